@@ -1,23 +1,39 @@
 extern crate protobuf_codegen_pure;
 
+use std::collections::HashMap;
+use std::path::Path;
+
 fn main() {
     // Define the base directory for .proto files
     let base_dir = "../../../../protobufs/schemas/";
 
-    // Define all your .proto files in an array
-    let proto_file_names = ["message.proto", "username_proof.proto"];
+    // Every schema that declares messages or enums is generated into a module named after the
+    // file; service-only files such as rpc.proto just feed the gRPC client below.
+    let mut proto_file_names: Vec<String> = std::fs::read_dir(base_dir)
+        .expect("protobuf schemas directory")
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|file_name| file_name.ends_with(".proto"))
+        .collect();
+    proto_file_names.sort();
+    println!("cargo:rerun-if-changed={}", base_dir);
+
+    let message_file_names: Vec<&str> = proto_file_names
+        .iter()
+        .map(String::as_str)
+        .filter(|file_name| {
+            let tokens = read_tokens(base_dir, file_name);
+            tokens
+                .iter()
+                .any(|token| token == "message" || token == "enum")
+        })
+        .collect();
 
     // Form full paths by appending the base directory
-    let proto_files: Vec<String> = proto_file_names
+    let proto_files: Vec<String> = message_file_names
         .iter()
         .map(|file_name| format!("{}{}", base_dir, file_name))
         .collect();
 
-    // Tell Cargo to re-run this build script if any of the .proto files change.
-    for proto_file in &proto_files {
-        println!("cargo:rerun-if-changed={}", proto_file);
-    }
-
     // Run the protobuf code generator
     protobuf_codegen_pure::Codegen::new()
         .out_dir("src/")
@@ -25,4 +41,181 @@ fn main() {
         .includes(&[base_dir])
         .run()
         .expect("protoc");
+
+    generate_grpc_clients(
+        base_dir,
+        &message_file_names,
+        &proto_file_names,
+        "src/rpc.rs",
+    );
+}
+
+/// Reads a .proto file and tells Cargo to re-run this build script when it changes.
+fn read_tokens(base_dir: &str, file_name: &str) -> Vec<String> {
+    let path = format!("{}{}", base_dir, file_name);
+    println!("cargo:rerun-if-changed={}", path);
+    tokenize(&std::fs::read_to_string(path).expect("proto file"))
+}
+
+/// Splits a .proto file into tokens, dropping comments.
+fn tokenize(source: &str) -> Vec<String> {
+    let mut text = String::new();
+    let mut rest = source;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("//") {
+            rest = after.find('\n').map_or("", |end| &after[end..]);
+        } else if let Some(after) = rest.strip_prefix("/*") {
+            rest = after.find("*/").map_or("", |end| &after[end + 2..]);
+        } else {
+            let c = rest.chars().next().unwrap();
+            if "(){};<>=,".contains(c) {
+                text.push_str(&format!(" {} ", c));
+            } else {
+                text.push(c);
+            }
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    text.split_whitespace().map(str::to_string).collect()
+}
+
+struct Rpc {
+    name: String,
+    input: String,
+    output: String,
+    client_streaming: bool,
+    server_streaming: bool,
+}
+
+/// Parses `service Name { rpc Method([stream] Input) returns ([stream] Output); ... }`.
+fn parse_services(tokens: &[String]) -> Vec<(String, Vec<Rpc>)> {
+    let mut services = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        if tokens[i] != "service" {
+            i += 1;
+            continue;
+        }
+        let service = tokens[i + 1].clone();
+        let mut rpcs = Vec::new();
+        i += 3;
+        let mut depth = 1;
+        while depth > 0 {
+            match tokens[i].as_str() {
+                "{" => depth += 1,
+                "}" => depth -= 1,
+                "rpc" if depth == 1 => {
+                    let arg = |i: &mut usize| {
+                        *i += 1; // "("
+                        let streaming = tokens[*i + 1] == "stream";
+                        if streaming {
+                            *i += 1;
+                        }
+                        *i += 2; // type, ")"
+                        (streaming, tokens[*i - 1].clone())
+                    };
+                    let name = tokens[i + 1].clone();
+                    i += 1;
+                    let (client_streaming, input) = arg(&mut i);
+                    i += 1; // "returns"
+                    let (server_streaming, output) = arg(&mut i);
+                    rpcs.push(Rpc {
+                        name,
+                        input,
+                        output,
+                        client_streaming,
+                        server_streaming,
+                    });
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        services.push((service, rpcs));
+    }
+    services
+}
+
+/// Returns the top level message names declared in a .proto file.
+fn message_names(tokens: &[String]) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        match token.as_str() {
+            "{" => depth += 1,
+            "}" => depth -= 1,
+            "message" if depth == 0 => names.push(tokens[i + 1].clone()),
+            _ => {}
+        }
+    }
+    names
+}
+
+/// Generates a tonic client for every service declared in `service_files`. Messages are encoded
+/// with rust-protobuf through `crate::grpc::ProtobufCodec`.
+fn generate_grpc_clients(
+    base_dir: &str,
+    message_files: &[&str],
+    service_files: &[String],
+    out_file: &str,
+) {
+    // Every message type is generated into the module named after its .proto file
+    let mut type_modules = HashMap::new();
+    for message_file in message_files {
+        let module = message_file.trim_end_matches(".proto");
+        for name in message_names(&read_tokens(base_dir, message_file)) {
+            type_modules.insert(name, module);
+        }
+    }
+    let rust_type = |name: &str| format!("crate::{}::{}", type_modules[name], name);
+
+    let services: Vec<_> = service_files
+        .iter()
+        .flat_map(|file_name| parse_services(&read_tokens(base_dir, file_name)))
+        .map(|(name, rpcs)| {
+            let mut builder = tonic_build::manual::Service::builder()
+                .name(&name)
+                .package("");
+            for rpc in rpcs {
+                let mut method = tonic_build::manual::Method::builder()
+                    .name(snake_case(&rpc.name))
+                    .route_name(&rpc.name)
+                    .input_type(rust_type(&rpc.input))
+                    .output_type(rust_type(&rpc.output))
+                    .codec_path("crate::grpc::ProtobufCodec");
+                if rpc.client_streaming {
+                    method = method.client_streaming();
+                }
+                if rpc.server_streaming {
+                    method = method.server_streaming();
+                }
+                builder = builder.method(method.build());
+            }
+            (name, builder.build())
+        })
+        .collect();
+
+    // tonic writes one `<package>.<service>.rs` file per service
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let mut generated = String::new();
+    for (name, service) in services {
+        tonic_build::manual::Builder::new()
+            .build_server(false)
+            .out_dir(&out_dir)
+            .compile(&[service]);
+        let path = Path::new(&out_dir).join(format!(".{}.rs", name));
+        generated.push_str(&std::fs::read_to_string(path).unwrap());
+    }
+    std::fs::write(out_file, generated).unwrap();
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in name.char_indices() {
+        if c.is_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
 }
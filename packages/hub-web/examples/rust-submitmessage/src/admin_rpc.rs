@@ -0,0 +1,1066 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `admin_rpc.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Empty {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Empty {
+    fn default() -> &'a Empty {
+        <Empty as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Empty {
+    pub fn new() -> Empty {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for Empty {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Empty {
+        Empty::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Empty>(
+                "Empty",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Empty {
+        static instance: ::protobuf::rt::LazyV2<Empty> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Empty::new)
+    }
+}
+
+impl ::protobuf::Clear for Empty {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Empty {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Empty {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RetryBlockNumberRange {
+    // message fields
+    pub start_block_number: u64,
+    pub stop_block_number: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RetryBlockNumberRange {
+    fn default() -> &'a RetryBlockNumberRange {
+        <RetryBlockNumberRange as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RetryBlockNumberRange {
+    pub fn new() -> RetryBlockNumberRange {
+        ::std::default::Default::default()
+    }
+
+    // uint64 start_block_number = 1;
+
+
+    pub fn get_start_block_number(&self) -> u64 {
+        self.start_block_number
+    }
+    pub fn clear_start_block_number(&mut self) {
+        self.start_block_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_block_number(&mut self, v: u64) {
+        self.start_block_number = v;
+    }
+
+    // uint64 stop_block_number = 2;
+
+
+    pub fn get_stop_block_number(&self) -> u64 {
+        self.stop_block_number
+    }
+    pub fn clear_stop_block_number(&mut self) {
+        self.stop_block_number = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop_block_number(&mut self, v: u64) {
+        self.stop_block_number = v;
+    }
+}
+
+impl ::protobuf::Message for RetryBlockNumberRange {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_block_number = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stop_block_number = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.start_block_number != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.start_block_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.stop_block_number != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.stop_block_number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.start_block_number != 0 {
+            os.write_uint64(1, self.start_block_number)?;
+        }
+        if self.stop_block_number != 0 {
+            os.write_uint64(2, self.stop_block_number)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RetryBlockNumberRange {
+        RetryBlockNumberRange::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "start_block_number",
+                |m: &RetryBlockNumberRange| { &m.start_block_number },
+                |m: &mut RetryBlockNumberRange| { &mut m.start_block_number },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "stop_block_number",
+                |m: &RetryBlockNumberRange| { &m.stop_block_number },
+                |m: &mut RetryBlockNumberRange| { &mut m.stop_block_number },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RetryBlockNumberRange>(
+                "RetryBlockNumberRange",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RetryBlockNumberRange {
+        static instance: ::protobuf::rt::LazyV2<RetryBlockNumberRange> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RetryBlockNumberRange::new)
+    }
+}
+
+impl ::protobuf::Clear for RetryBlockNumberRange {
+    fn clear(&mut self) {
+        self.start_block_number = 0;
+        self.stop_block_number = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RetryBlockNumberRange {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RetryBlockNumberRange {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RetryOnchainEventsRequest {
+    // message fields
+    pub fid: u64,
+    pub block_range: ::protobuf::SingularPtrField<RetryBlockNumberRange>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RetryOnchainEventsRequest {
+    fn default() -> &'a RetryOnchainEventsRequest {
+        <RetryOnchainEventsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RetryOnchainEventsRequest {
+    pub fn new() -> RetryOnchainEventsRequest {
+        ::std::default::Default::default()
+    }
+
+    // uint64 fid = 1;
+
+
+    pub fn get_fid(&self) -> u64 {
+        self.fid
+    }
+    pub fn clear_fid(&mut self) {
+        self.fid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fid(&mut self, v: u64) {
+        self.fid = v;
+    }
+
+    // .RetryBlockNumberRange block_range = 2;
+
+
+    pub fn get_block_range(&self) -> &RetryBlockNumberRange {
+        self.block_range.as_ref().unwrap_or_else(|| <RetryBlockNumberRange as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_block_range(&mut self) {
+        self.block_range.clear();
+    }
+
+    pub fn has_block_range(&self) -> bool {
+        self.block_range.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block_range(&mut self, v: RetryBlockNumberRange) {
+        self.block_range = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_block_range(&mut self) -> &mut RetryBlockNumberRange {
+        if self.block_range.is_none() {
+            self.block_range.set_default();
+        }
+        self.block_range.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_block_range(&mut self) -> RetryBlockNumberRange {
+        self.block_range.take().unwrap_or_else(|| RetryBlockNumberRange::new())
+    }
+}
+
+impl ::protobuf::Message for RetryOnchainEventsRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.block_range {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.fid = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.block_range)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.fid != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.fid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.block_range.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.fid != 0 {
+            os.write_uint64(1, self.fid)?;
+        }
+        if let Some(ref v) = self.block_range.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RetryOnchainEventsRequest {
+        RetryOnchainEventsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "fid",
+                |m: &RetryOnchainEventsRequest| { &m.fid },
+                |m: &mut RetryOnchainEventsRequest| { &mut m.fid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RetryBlockNumberRange>>(
+                "block_range",
+                |m: &RetryOnchainEventsRequest| { &m.block_range },
+                |m: &mut RetryOnchainEventsRequest| { &mut m.block_range },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RetryOnchainEventsRequest>(
+                "RetryOnchainEventsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RetryOnchainEventsRequest {
+        static instance: ::protobuf::rt::LazyV2<RetryOnchainEventsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RetryOnchainEventsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for RetryOnchainEventsRequest {
+    fn clear(&mut self) {
+        self.fid = 0;
+        self.block_range.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RetryOnchainEventsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RetryOnchainEventsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RetryFnameRequest {
+    // message fields
+    pub fid: u64,
+    pub fname: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RetryFnameRequest {
+    fn default() -> &'a RetryFnameRequest {
+        <RetryFnameRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RetryFnameRequest {
+    pub fn new() -> RetryFnameRequest {
+        ::std::default::Default::default()
+    }
+
+    // uint64 fid = 1;
+
+
+    pub fn get_fid(&self) -> u64 {
+        self.fid
+    }
+    pub fn clear_fid(&mut self) {
+        self.fid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fid(&mut self, v: u64) {
+        self.fid = v;
+    }
+
+    // string fname = 2;
+
+
+    pub fn get_fname(&self) -> &str {
+        &self.fname
+    }
+    pub fn clear_fname(&mut self) {
+        self.fname.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fname(&mut self, v: ::std::string::String) {
+        self.fname = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fname(&mut self) -> &mut ::std::string::String {
+        &mut self.fname
+    }
+
+    // Take field
+    pub fn take_fname(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.fname, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RetryFnameRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.fid = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.fname)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.fid != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.fid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.fname.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.fname);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.fid != 0 {
+            os.write_uint64(1, self.fid)?;
+        }
+        if !self.fname.is_empty() {
+            os.write_string(2, &self.fname)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RetryFnameRequest {
+        RetryFnameRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "fid",
+                |m: &RetryFnameRequest| { &m.fid },
+                |m: &mut RetryFnameRequest| { &mut m.fid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "fname",
+                |m: &RetryFnameRequest| { &m.fname },
+                |m: &mut RetryFnameRequest| { &mut m.fname },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RetryFnameRequest>(
+                "RetryFnameRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RetryFnameRequest {
+        static instance: ::protobuf::rt::LazyV2<RetryFnameRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RetryFnameRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for RetryFnameRequest {
+    fn clear(&mut self) {
+        self.fid = 0;
+        self.fname.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RetryFnameRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RetryFnameRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UploadSnapshotRequest {
+    // message fields
+    pub shard_indexes: ::std::vec::Vec<u32>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UploadSnapshotRequest {
+    fn default() -> &'a UploadSnapshotRequest {
+        <UploadSnapshotRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UploadSnapshotRequest {
+    pub fn new() -> UploadSnapshotRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated uint32 shard_indexes = 1;
+
+
+    pub fn get_shard_indexes(&self) -> &[u32] {
+        &self.shard_indexes
+    }
+    pub fn clear_shard_indexes(&mut self) {
+        self.shard_indexes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shard_indexes(&mut self, v: ::std::vec::Vec<u32>) {
+        self.shard_indexes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_shard_indexes(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.shard_indexes
+    }
+
+    // Take field
+    pub fn take_shard_indexes(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.shard_indexes, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for UploadSnapshotRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.shard_indexes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.shard_indexes {
+            my_size += ::protobuf::rt::value_size(1, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.shard_indexes {
+            os.write_uint32(1, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UploadSnapshotRequest {
+        UploadSnapshotRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "shard_indexes",
+                |m: &UploadSnapshotRequest| { &m.shard_indexes },
+                |m: &mut UploadSnapshotRequest| { &mut m.shard_indexes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UploadSnapshotRequest>(
+                "UploadSnapshotRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UploadSnapshotRequest {
+        static instance: ::protobuf::rt::LazyV2<UploadSnapshotRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UploadSnapshotRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for UploadSnapshotRequest {
+    fn clear(&mut self) {
+        self.shard_indexes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UploadSnapshotRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UploadSnapshotRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RunOnchainEventsMigrationRequest {
+    // message fields
+    pub shard_id: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RunOnchainEventsMigrationRequest {
+    fn default() -> &'a RunOnchainEventsMigrationRequest {
+        <RunOnchainEventsMigrationRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RunOnchainEventsMigrationRequest {
+    pub fn new() -> RunOnchainEventsMigrationRequest {
+        ::std::default::Default::default()
+    }
+
+    // uint64 shard_id = 1;
+
+
+    pub fn get_shard_id(&self) -> u64 {
+        self.shard_id
+    }
+    pub fn clear_shard_id(&mut self) {
+        self.shard_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shard_id(&mut self, v: u64) {
+        self.shard_id = v;
+    }
+}
+
+impl ::protobuf::Message for RunOnchainEventsMigrationRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.shard_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.shard_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.shard_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.shard_id != 0 {
+            os.write_uint64(1, self.shard_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RunOnchainEventsMigrationRequest {
+        RunOnchainEventsMigrationRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "shard_id",
+                |m: &RunOnchainEventsMigrationRequest| { &m.shard_id },
+                |m: &mut RunOnchainEventsMigrationRequest| { &mut m.shard_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RunOnchainEventsMigrationRequest>(
+                "RunOnchainEventsMigrationRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RunOnchainEventsMigrationRequest {
+        static instance: ::protobuf::rt::LazyV2<RunOnchainEventsMigrationRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RunOnchainEventsMigrationRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for RunOnchainEventsMigrationRequest {
+    fn clear(&mut self) {
+        self.shard_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RunOnchainEventsMigrationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RunOnchainEventsMigrationRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0fadmin_rpc.proto\x1a\x13onchain_event.proto\x1a\x14username_proof.p\
+    roto\"\t\n\x05Empty:\0\"w\n\x15RetryBlockNumberRange\x12.\n\x12start_blo\
+    ck_number\x18\x01\x20\x01(\x04R\x10startBlockNumberB\0\x12,\n\x11stop_bl\
+    ock_number\x18\x02\x20\x01(\x04R\x0fstopBlockNumberB\0:\0\"l\n\x19RetryO\
+    nchainEventsRequest\x12\x12\n\x03fid\x18\x01\x20\x01(\x04R\x03fidB\0\x12\
+    9\n\x0bblock_range\x18\x02\x20\x01(\x0b2\x16.RetryBlockNumberRangeR\nblo\
+    ckRangeB\0:\0\"A\n\x11RetryFnameRequest\x12\x12\n\x03fid\x18\x01\x20\x01\
+    (\x04R\x03fidB\0\x12\x16\n\x05fname\x18\x02\x20\x01(\tR\x05fnameB\0:\0\"\
+    @\n\x15UploadSnapshotRequest\x12%\n\rshard_indexes\x18\x01\x20\x03(\rR\
+    \x0cshardIndexesB\0:\0\"A\n\x20RunOnchainEventsMigrationRequest\x12\x1b\
+    \n\x08shard_id\x18\x01\x20\x01(\x04R\x07shardIdB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
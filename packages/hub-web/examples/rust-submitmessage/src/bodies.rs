@@ -0,0 +1,439 @@
+//! Typed builders for every `MessageData` body variant.
+//!
+//! Each builder knows which `MessageType` it belongs to, so a `MessageBuilder` created from one
+//! can never pair a body with the wrong `field_type`.
+
+use crate::message::{
+    CastAddBody, CastAddBody_oneof_parent, CastId, CastRemoveBody, CastType, Embed,
+    FrameActionBody, LinkBody, LinkBody_oneof_target, MessageData_oneof_body, MessageType,
+    Protocol, ReactionBody, ReactionBody_oneof_target, ReactionType, UserDataBody, UserDataType,
+    VerificationAddAddressBody, VerificationRemoveBody,
+};
+use crate::username_proof::UserNameProof;
+
+/// A message body together with the `MessageType` it must be sent as.
+pub trait MessageBody {
+    fn message_type(&self) -> MessageType;
+    fn into_body(self) -> MessageData_oneof_body;
+}
+
+/// Creates a `CastId` pointing at the cast with `hash` authored by `fid`.
+pub fn cast_id(fid: u64, hash: Vec<u8>) -> CastId {
+    let mut cast_id = CastId::new();
+    cast_id.set_fid(fid);
+    cast_id.set_hash(hash);
+    cast_id
+}
+
+/// Builds a `CastAddBody` for `MESSAGE_TYPE_CAST_ADD`.
+#[derive(Debug, Clone, Default)]
+pub struct CastAddBuilder {
+    body: CastAddBody,
+}
+
+impl CastAddBuilder {
+    pub fn new(text: impl Into<String>) -> Self {
+        let mut body = CastAddBody::new();
+        body.set_text(text.into());
+        Self { body }
+    }
+
+    /// Mentions `fid` at the UTF-8 byte offset `position` of the text.
+    pub fn mention(mut self, fid: u64, position: u32) -> Self {
+        self.body.mentions.push(fid);
+        self.body.mentions_positions.push(position);
+        self
+    }
+
+    pub fn embed_url(mut self, url: impl Into<String>) -> Self {
+        let mut embed = Embed::new();
+        embed.set_url(url.into());
+        self.body.embeds.push(embed);
+        self
+    }
+
+    pub fn embed_cast(mut self, cast_id: CastId) -> Self {
+        let mut embed = Embed::new();
+        embed.set_cast_id(cast_id);
+        self.body.embeds.push(embed);
+        self
+    }
+
+    /// Makes this cast a reply to another cast.
+    pub fn parent_cast(mut self, cast_id: CastId) -> Self {
+        self.body.parent = Some(CastAddBody_oneof_parent::parent_cast_id(cast_id));
+        self
+    }
+
+    /// Posts this cast in a channel identified by `url`.
+    pub fn parent_url(mut self, url: impl Into<String>) -> Self {
+        self.body.parent = Some(CastAddBody_oneof_parent::parent_url(url.into()));
+        self
+    }
+
+    /// Marks the cast as a long or 10k cast, which allow longer text. Defaults to
+    /// `CastType::CAST`.
+    pub fn cast_type(mut self, cast_type: CastType) -> Self {
+        self.body.set_field_type(cast_type);
+        self
+    }
+
+    pub fn into_inner(self) -> CastAddBody {
+        self.body
+    }
+}
+
+impl From<CastAddBody> for CastAddBuilder {
+    fn from(body: CastAddBody) -> Self {
+        Self { body }
+    }
+}
+
+impl MessageBody for CastAddBuilder {
+    fn message_type(&self) -> MessageType {
+        MessageType::MESSAGE_TYPE_CAST_ADD
+    }
+
+    fn into_body(self) -> MessageData_oneof_body {
+        MessageData_oneof_body::cast_add_body(self.body)
+    }
+}
+
+/// Builds a `CastRemoveBody` for `MESSAGE_TYPE_CAST_REMOVE`.
+#[derive(Debug, Clone)]
+pub struct CastRemoveBuilder {
+    body: CastRemoveBody,
+}
+
+impl CastRemoveBuilder {
+    pub fn new(target_hash: Vec<u8>) -> Self {
+        let mut body = CastRemoveBody::new();
+        body.set_target_hash(target_hash);
+        Self { body }
+    }
+}
+
+impl MessageBody for CastRemoveBuilder {
+    fn message_type(&self) -> MessageType {
+        MessageType::MESSAGE_TYPE_CAST_REMOVE
+    }
+
+    fn into_body(self) -> MessageData_oneof_body {
+        MessageData_oneof_body::cast_remove_body(self.body)
+    }
+}
+
+/// The cast or url a reaction points at.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReactionTarget {
+    Cast(CastId),
+    Url(String),
+}
+
+/// Builds a `ReactionBody` for `MESSAGE_TYPE_REACTION_ADD` or `MESSAGE_TYPE_REACTION_REMOVE`.
+#[derive(Debug, Clone)]
+pub struct ReactionBuilder {
+    message_type: MessageType,
+    body: ReactionBody,
+}
+
+impl ReactionBuilder {
+    pub fn add(reaction_type: ReactionType, target: ReactionTarget) -> Self {
+        Self::with_type(
+            MessageType::MESSAGE_TYPE_REACTION_ADD,
+            reaction_type,
+            target,
+        )
+    }
+
+    pub fn remove(reaction_type: ReactionType, target: ReactionTarget) -> Self {
+        Self::with_type(
+            MessageType::MESSAGE_TYPE_REACTION_REMOVE,
+            reaction_type,
+            target,
+        )
+    }
+
+    fn with_type(
+        message_type: MessageType,
+        reaction_type: ReactionType,
+        target: ReactionTarget,
+    ) -> Self {
+        let mut body = ReactionBody::new();
+        body.set_field_type(reaction_type);
+        body.target = Some(match target {
+            ReactionTarget::Cast(cast_id) => ReactionBody_oneof_target::target_cast_id(cast_id),
+            ReactionTarget::Url(url) => ReactionBody_oneof_target::target_url(url),
+        });
+        Self { message_type, body }
+    }
+}
+
+impl MessageBody for ReactionBuilder {
+    fn message_type(&self) -> MessageType {
+        self.message_type
+    }
+
+    fn into_body(self) -> MessageData_oneof_body {
+        MessageData_oneof_body::reaction_body(self.body)
+    }
+}
+
+/// Builds a `LinkBody` for `MESSAGE_TYPE_LINK_ADD` or `MESSAGE_TYPE_LINK_REMOVE`.
+#[derive(Debug, Clone)]
+pub struct LinkBuilder {
+    message_type: MessageType,
+    body: LinkBody,
+}
+
+impl LinkBuilder {
+    /// Links to `target_fid` with a link type such as `"follow"`.
+    pub fn add(link_type: impl Into<String>, target_fid: u64) -> Self {
+        Self::with_type(MessageType::MESSAGE_TYPE_LINK_ADD, link_type, target_fid)
+    }
+
+    pub fn remove(link_type: impl Into<String>, target_fid: u64) -> Self {
+        Self::with_type(MessageType::MESSAGE_TYPE_LINK_REMOVE, link_type, target_fid)
+    }
+
+    fn with_type(message_type: MessageType, link_type: impl Into<String>, target_fid: u64) -> Self {
+        let mut body = LinkBody::new();
+        body.set_field_type(link_type.into());
+        body.target = Some(LinkBody_oneof_target::target_fid(target_fid));
+        Self { message_type, body }
+    }
+
+    /// Overrides the timestamp shown to users for this link.
+    pub fn display_timestamp(mut self, timestamp: u32) -> Self {
+        self.body.set_displayTimestamp(timestamp);
+        self
+    }
+}
+
+impl MessageBody for LinkBuilder {
+    fn message_type(&self) -> MessageType {
+        self.message_type
+    }
+
+    fn into_body(self) -> MessageData_oneof_body {
+        MessageData_oneof_body::link_body(self.body)
+    }
+}
+
+/// Builds a `VerificationAddAddressBody` for `MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS`.
+#[derive(Debug, Clone)]
+pub struct VerificationAddAddressBuilder {
+    body: VerificationAddAddressBody,
+}
+
+impl VerificationAddAddressBuilder {
+    pub fn new(address: Vec<u8>, claim_signature: Vec<u8>, block_hash: Vec<u8>) -> Self {
+        let mut body = VerificationAddAddressBody::new();
+        body.set_address(address);
+        body.set_claim_signature(claim_signature);
+        body.set_block_hash(block_hash);
+        Self { body }
+    }
+
+    pub fn protocol(mut self, protocol: Protocol) -> Self {
+        self.body.set_protocol(protocol);
+        self
+    }
+
+    /// Sets the verification type, `0` for EOAs and `1` for contract wallets.
+    pub fn verification_type(mut self, verification_type: u32) -> Self {
+        self.body.set_verification_type(verification_type);
+        self
+    }
+
+    pub fn chain_id(mut self, chain_id: u32) -> Self {
+        self.body.set_chain_id(chain_id);
+        self
+    }
+}
+
+impl From<VerificationAddAddressBody> for VerificationAddAddressBuilder {
+    fn from(body: VerificationAddAddressBody) -> Self {
+        Self { body }
+    }
+}
+
+impl MessageBody for VerificationAddAddressBuilder {
+    fn message_type(&self) -> MessageType {
+        MessageType::MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS
+    }
+
+    fn into_body(self) -> MessageData_oneof_body {
+        MessageData_oneof_body::verification_add_address_body(self.body)
+    }
+}
+
+/// Builds a `VerificationRemoveBody` for `MESSAGE_TYPE_VERIFICATION_REMOVE`.
+#[derive(Debug, Clone)]
+pub struct VerificationRemoveBuilder {
+    body: VerificationRemoveBody,
+}
+
+impl VerificationRemoveBuilder {
+    pub fn new(address: Vec<u8>, protocol: Protocol) -> Self {
+        let mut body = VerificationRemoveBody::new();
+        body.set_address(address);
+        body.set_protocol(protocol);
+        Self { body }
+    }
+}
+
+impl MessageBody for VerificationRemoveBuilder {
+    fn message_type(&self) -> MessageType {
+        MessageType::MESSAGE_TYPE_VERIFICATION_REMOVE
+    }
+
+    fn into_body(self) -> MessageData_oneof_body {
+        MessageData_oneof_body::verification_remove_body(self.body)
+    }
+}
+
+/// Builds a `UserDataBody` for `MESSAGE_TYPE_USER_DATA_ADD`.
+#[derive(Debug, Clone)]
+pub struct UserDataBuilder {
+    body: UserDataBody,
+}
+
+impl UserDataBuilder {
+    pub fn new(user_data_type: UserDataType, value: impl Into<String>) -> Self {
+        let mut body = UserDataBody::new();
+        body.set_field_type(user_data_type);
+        body.set_value(value.into());
+        Self { body }
+    }
+}
+
+impl MessageBody for UserDataBuilder {
+    fn message_type(&self) -> MessageType {
+        MessageType::MESSAGE_TYPE_USER_DATA_ADD
+    }
+
+    fn into_body(self) -> MessageData_oneof_body {
+        MessageData_oneof_body::user_data_body(self.body)
+    }
+}
+
+/// Wraps a `UserNameProof` for `MESSAGE_TYPE_USERNAME_PROOF`.
+#[derive(Debug, Clone)]
+pub struct UsernameProofBuilder {
+    proof: UserNameProof,
+}
+
+impl UsernameProofBuilder {
+    pub fn new(proof: UserNameProof) -> Self {
+        Self { proof }
+    }
+}
+
+impl MessageBody for UsernameProofBuilder {
+    fn message_type(&self) -> MessageType {
+        MessageType::MESSAGE_TYPE_USERNAME_PROOF
+    }
+
+    fn into_body(self) -> MessageData_oneof_body {
+        MessageData_oneof_body::username_proof_body(self.proof)
+    }
+}
+
+/// Builds a `FrameActionBody` for `MESSAGE_TYPE_FRAME_ACTION`.
+#[derive(Debug, Clone)]
+pub struct FrameActionBuilder {
+    body: FrameActionBody,
+}
+
+impl FrameActionBuilder {
+    pub fn new(url: impl Into<Vec<u8>>, button_index: u32) -> Self {
+        let mut body = FrameActionBody::new();
+        body.set_url(url.into());
+        body.set_button_index(button_index);
+        Self { body }
+    }
+
+    /// Sets the cast the frame was embedded in.
+    pub fn cast_id(mut self, cast_id: CastId) -> Self {
+        self.body.set_cast_id(cast_id);
+        self
+    }
+
+    pub fn input_text(mut self, input_text: impl Into<Vec<u8>>) -> Self {
+        self.body.set_input_text(input_text.into());
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<Vec<u8>>) -> Self {
+        self.body.set_state(state.into());
+        self
+    }
+
+    /// Sets the id of the transaction a transaction button sent.
+    pub fn transaction_id(mut self, transaction_id: impl Into<Vec<u8>>) -> Self {
+        self.body.set_transaction_id(transaction_id.into());
+        self
+    }
+
+    /// Sets the address of the wallet connected to the frame.
+    pub fn address(mut self, address: impl Into<Vec<u8>>) -> Self {
+        self.body.set_address(address.into());
+        self
+    }
+}
+
+impl MessageBody for FrameActionBuilder {
+    fn message_type(&self) -> MessageType {
+        MessageType::MESSAGE_TYPE_FRAME_ACTION
+    }
+
+    fn into_body(self) -> MessageData_oneof_body {
+        MessageData_oneof_body::frame_action_body(self.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reaction_type_follows_constructor() {
+        let target = ReactionTarget::Url("https://farcaster.xyz".to_string());
+        let add = ReactionBuilder::add(ReactionType::REACTION_TYPE_LIKE, target.clone());
+        let remove = ReactionBuilder::remove(ReactionType::REACTION_TYPE_LIKE, target);
+        assert_eq!(add.message_type(), MessageType::MESSAGE_TYPE_REACTION_ADD);
+        assert_eq!(
+            remove.message_type(),
+            MessageType::MESSAGE_TYPE_REACTION_REMOVE
+        );
+    }
+
+    #[test]
+    fn link_type_follows_constructor() {
+        let add = LinkBuilder::add("follow", 2);
+        let remove = LinkBuilder::remove("follow", 2);
+        assert_eq!(add.message_type(), MessageType::MESSAGE_TYPE_LINK_ADD);
+        assert_eq!(remove.message_type(), MessageType::MESSAGE_TYPE_LINK_REMOVE);
+
+        match remove.into_body() {
+            MessageData_oneof_body::link_body(body) => {
+                assert_eq!(body.get_field_type(), "follow");
+                assert_eq!(body.get_target_fid(), 2);
+            }
+            other => panic!("unexpected body {:?}", other),
+        }
+    }
+
+    #[test]
+    fn cast_add_collects_mentions_in_order() {
+        let body = CastAddBuilder::new("hi  and ")
+            .mention(2, 3)
+            .mention(3, 8)
+            .parent_url("chain://eip155:1/erc721:0x1")
+            .into_inner();
+        assert_eq!(body.mentions, vec![2, 3]);
+        assert_eq!(body.mentions_positions, vec![3, 8]);
+        assert_eq!(body.get_parent_url(), "chain://eip155:1/erc721:0x1");
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use protobuf::Message as _;
+
+use crate::bodies::MessageBody;
+use crate::farcaster_time::{Clock, SystemClock, TimeError};
+use crate::message::{FarcasterNetwork, HashScheme, Message, MessageData, SignatureScheme};
+use crate::signers::{MessageSigner, SignerError};
+
+/// Length of the truncated blake3 digest used as the message hash.
+pub const HASH_LENGTH: usize = 20;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BuildError {
+    #[error("invalid timestamp: {0}")]
+    Time(#[from] TimeError),
+    #[error("failed to encode message data: {0}")]
+    Encode(#[from] protobuf::ProtobufError),
+    #[error("failed to sign message: {0}")]
+    Signer(#[from] SignerError),
+}
+
+/// Computes the blake3 hash of `bytes`, truncated to 20 bytes.
+pub fn hash_data(bytes: &[u8]) -> Vec<u8> {
+    blake3::hash(bytes).as_bytes()[..HASH_LENGTH].to_vec()
+}
+
+/// Builds a fully signed `Message` for any `MessageType`.
+///
+/// The `MessageType` is taken from the body, see [`crate::bodies`].
+///
+/// The `MessageData` is serialized once and carried in `data_bytes`, so the hash and signature
+/// always cover exactly the bytes that are submitted to the hub.
+#[derive(Debug, Clone)]
+pub struct MessageBuilder {
+    data: MessageData,
+    timestamp: Option<u32>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MessageBuilder {
+    pub fn new(fid: u64, network: FarcasterNetwork, body: impl MessageBody) -> Self {
+        let mut data = MessageData::new();
+        data.set_field_type(body.message_type());
+        data.set_fid(fid);
+        data.set_network(network);
+        data.body = Some(body.into_body());
+        Self {
+            data,
+            timestamp: None,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Sets the farcaster timestamp. Defaults to the current time when the message is built.
+    pub fn timestamp(mut self, timestamp: u32) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the clock used for the default timestamp. Defaults to the system clock.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the `MessageData` that will be hashed and signed.
+    pub fn data(&self) -> &MessageData {
+        &self.data
+    }
+
+    /// Serializes the message data, hashes it and signs the hash with `signer`.
+    pub async fn build(self, signer: &impl MessageSigner) -> Result<Message, BuildError> {
+        let mut data = self.data;
+        let timestamp = match self.timestamp {
+            Some(timestamp) => timestamp,
+            None => self.clock.farcaster_time()?,
+        };
+        data.set_timestamp(timestamp);
+
+        let data_bytes = data.write_to_bytes()?;
+        let hash = hash_data(&data_bytes);
+        let signature = signer.sign(&hash).await?.to_bytes();
+
+        let mut msg = Message::new();
+        msg.set_hash_scheme(HashScheme::HASH_SCHEME_BLAKE3);
+        msg.set_hash(hash);
+        msg.set_signature_scheme(SignatureScheme::SIGNATURE_SCHEME_ED25519);
+        msg.set_signature(signature.to_vec());
+        msg.set_signer(signer.public_key().to_bytes().to_vec());
+        msg.set_data_bytes(data_bytes);
+        Ok(msg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bodies::{CastAddBuilder, ReactionBuilder, ReactionTarget};
+    use crate::farcaster_time::FixedClock;
+    use crate::message::{MessageType, ReactionType};
+    use crate::signers::InMemorySigner;
+    use crate::test_utils::{test_signer, test_signing_key};
+    use ed25519_dalek::{Signature, Verifier};
+
+    #[tokio::test]
+    async fn builds_signed_cast_add() {
+        let signing_key = test_signing_key();
+        let msg = MessageBuilder::new(
+            6833,
+            FarcasterNetwork::FARCASTER_NETWORK_MAINNET,
+            CastAddBuilder::new("hello"),
+        )
+        .timestamp(100)
+        .build(&InMemorySigner::new(signing_key.clone()))
+        .await
+        .unwrap();
+
+        assert_eq!(msg.get_hash(), hash_data(msg.get_data_bytes()).as_slice());
+        let signature = Signature::from_slice(msg.get_signature()).unwrap();
+        assert!(signing_key
+            .verifying_key()
+            .verify(msg.get_hash(), &signature)
+            .is_ok());
+
+        let data = MessageData::parse_from_bytes(msg.get_data_bytes()).unwrap();
+        assert_eq!(data.get_field_type(), MessageType::MESSAGE_TYPE_CAST_ADD);
+        assert_eq!(data.get_fid(), 6833);
+        assert_eq!(data.get_timestamp(), 100);
+        assert_eq!(data.get_cast_add_body().get_text(), "hello");
+    }
+
+    #[tokio::test]
+    async fn timestamps_with_injected_clock() {
+        let clock = Arc::new(FixedClock::at_farcaster_time(1_000));
+        let signer = test_signer();
+        let builder = MessageBuilder::new(
+            1,
+            FarcasterNetwork::FARCASTER_NETWORK_MAINNET,
+            CastAddBuilder::new("hello"),
+        )
+        .clock(clock.clone());
+
+        let msg = builder.clone().build(&signer).await.unwrap();
+        let data = MessageData::parse_from_bytes(msg.get_data_bytes()).unwrap();
+        assert_eq!(data.get_timestamp(), 1_000);
+
+        clock.set(0);
+        assert!(matches!(
+            builder.build(&signer).await,
+            Err(BuildError::Time(TimeError::BeforeEpoch))
+        ));
+    }
+
+    #[test]
+    fn sets_message_type_from_body() {
+        let builder = MessageBuilder::new(
+            1,
+            FarcasterNetwork::FARCASTER_NETWORK_MAINNET,
+            ReactionBuilder::remove(
+                ReactionType::REACTION_TYPE_RECAST,
+                ReactionTarget::Url("https://farcaster.xyz".to_string()),
+            ),
+        );
+        assert_eq!(
+            builder.data().get_field_type(),
+            MessageType::MESSAGE_TYPE_REACTION_REMOVE
+        );
+        assert!(builder.data().has_reaction_body());
+    }
+}
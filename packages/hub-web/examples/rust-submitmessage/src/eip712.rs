@@ -0,0 +1,189 @@
+//! EIP-712 typed data hashing for the claims Farcaster signs, mirroring
+//! packages/core/src/crypto/eip712.ts.
+
+use crate::eth::{keccak256, Address};
+
+/// Fixed salt of the verification domain, to minimize collisions.
+pub const EIP_712_FARCASTER_DOMAIN_SALT: [u8; 32] = [
+    0xf2, 0xd8, 0x57, 0xf4, 0xa3, 0xed, 0xcb, 0x9b, 0x78, 0xb4, 0xd5, 0x03, 0xbf, 0xe7, 0x33, 0xdb,
+    0x1e, 0x3f, 0x6c, 0xdc, 0x2b, 0x79, 0x71, 0xee, 0x73, 0x96, 0x26, 0xc9, 0x7e, 0x86, 0xa5, 0x58,
+];
+
+/// The name registry contract that fname proofs are bound to.
+pub const EIP_712_USERNAME_VERIFYING_CONTRACT: [u8; 20] = [
+    0xe3, 0xbe, 0x01, 0xd9, 0x9b, 0xaa, 0x8d, 0xb9, 0x90, 0x5b, 0x33, 0xa3, 0xca, 0x39, 0x12, 0x38,
+    0x23, 0x4b, 0x79, 0xd1,
+];
+
+/// The SignedKeyRequestValidator contract on OP mainnet.
+pub const SIGNED_KEY_REQUEST_VALIDATOR_ADDRESS: [u8; 20] = [
+    0x00, 0x00, 0x00, 0x00, 0xfc, 0x70, 0x04, 0x72, 0x60, 0x6e, 0xd4, 0xfa, 0x22, 0x62, 0x3a, 0xcf,
+    0x62, 0xc6, 0x05, 0x53,
+];
+
+/// The `EIP712Domain` a signature is bound to. Fields left as `None` are not part of the domain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip712Domain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: Option<u64>,
+    pub verifying_contract: Option<Address>,
+    pub salt: Option<[u8; 32]>,
+}
+
+impl Eip712Domain {
+    /// "Farcaster Verify Ethereum Address", which address verifications are signed in. Only
+    /// contract wallet claims carry a chain id.
+    pub fn farcaster_verify(chain_id: Option<u64>) -> Self {
+        Self {
+            name: "Farcaster Verify Ethereum Address".to_string(),
+            version: "2.0.0".to_string(),
+            chain_id,
+            verifying_contract: None,
+            salt: Some(EIP_712_FARCASTER_DOMAIN_SALT),
+        }
+    }
+
+    /// "Farcaster name verification", which username proofs are signed in.
+    pub fn username() -> Self {
+        Self {
+            name: "Farcaster name verification".to_string(),
+            version: "1".to_string(),
+            chain_id: Some(1),
+            verifying_contract: Some(EIP_712_USERNAME_VERIFYING_CONTRACT),
+            salt: None,
+        }
+    }
+
+    /// "Farcaster SignedKeyRequestValidator", which key requests are signed in.
+    pub fn signed_key_request_validator() -> Self {
+        Self {
+            name: "Farcaster SignedKeyRequestValidator".to_string(),
+            version: "1".to_string(),
+            chain_id: Some(10),
+            verifying_contract: Some(SIGNED_KEY_REQUEST_VALIDATOR_ADDRESS),
+            salt: None,
+        }
+    }
+
+    pub fn separator(&self) -> [u8; 32] {
+        let mut fields = vec!["string name", "string version"];
+        let mut values = vec![encode_string(&self.name), encode_string(&self.version)];
+        if let Some(chain_id) = self.chain_id {
+            fields.push("uint256 chainId");
+            values.push(encode_uint(chain_id));
+        }
+        if let Some(verifying_contract) = &self.verifying_contract {
+            fields.push("address verifyingContract");
+            values.push(encode_address(verifying_contract));
+        }
+        if let Some(salt) = self.salt {
+            fields.push("bytes32 salt");
+            values.push(salt);
+        }
+        hash_struct(&format!("EIP712Domain({})", fields.join(",")), &values)
+    }
+}
+
+/// `hashStruct`: the hash of a struct's type string followed by its encoded fields.
+pub fn hash_struct(type_string: &str, fields: &[[u8; 32]]) -> [u8; 32] {
+    let mut encoded = keccak256(type_string.as_bytes()).to_vec();
+    for field in fields {
+        encoded.extend_from_slice(field);
+    }
+    keccak256(&encoded)
+}
+
+/// The digest that is signed for a struct with `struct_hash` in `domain`.
+pub fn hash_typed_data(domain: &Eip712Domain, struct_hash: &[u8; 32]) -> [u8; 32] {
+    let mut encoded = vec![0x19, 0x01];
+    encoded.extend_from_slice(&domain.separator());
+    encoded.extend_from_slice(struct_hash);
+    keccak256(&encoded)
+}
+
+pub fn encode_uint(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+pub fn encode_address(address: &Address) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address);
+    word
+}
+
+/// Dynamic `string` values are encoded as their hash.
+pub fn encode_string(value: &str) -> [u8; 32] {
+    keccak256(value.as_bytes())
+}
+
+/// Dynamic `bytes` values are encoded as their hash.
+pub fn encode_bytes(value: &[u8]) -> [u8; 32] {
+    keccak256(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::EthWallet;
+
+    const PERSON_TYPE: &str = "Person(string name,address wallet)";
+
+    fn address(hex: &str) -> Address {
+        hex::decode(hex).unwrap().try_into().unwrap()
+    }
+
+    fn person(name: &str, wallet: &str) -> [u8; 32] {
+        hash_struct(
+            PERSON_TYPE,
+            &[encode_string(name), encode_address(&address(wallet))],
+        )
+    }
+
+    // The example from the EIP-712 specification.
+    #[test]
+    fn matches_the_eip712_mail_example() {
+        let domain = Eip712Domain {
+            name: "Ether Mail".to_string(),
+            version: "1".to_string(),
+            chain_id: Some(1),
+            verifying_contract: Some(address("cccccccccccccccccccccccccccccccccccccccc")),
+            salt: None,
+        };
+        assert_eq!(
+            hex::encode(domain.separator()),
+            "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f"
+        );
+
+        let mail = hash_struct(
+            &format!("Mail(Person from,Person to,string contents){}", PERSON_TYPE),
+            &[
+                person("Cow", "cd2a3d9f938e13cd947ec05abc7fe734df8dd826"),
+                person("Bob", "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb"),
+                encode_string("Hello, Bob!"),
+            ],
+        );
+        assert_eq!(
+            hex::encode(mail),
+            "c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e"
+        );
+        let digest = hash_typed_data(&domain, &mail);
+        assert_eq!(
+            hex::encode(digest),
+            "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"
+        );
+
+        let cow = EthWallet::from_bytes(&keccak256(b"cow")).unwrap();
+        assert_eq!(
+            cow.address(),
+            address("cd2a3d9f938e13cd947ec05abc7fe734df8dd826")
+        );
+        assert_eq!(
+            hex::encode(cow.sign_hash(&digest).unwrap()),
+            "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d\
+             07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b915621c"
+        );
+    }
+}
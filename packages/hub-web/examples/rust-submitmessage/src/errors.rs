@@ -0,0 +1,221 @@
+//! Errors returned by hubs, mirroring `HubError` in packages/core/src/errors.ts.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde_json::Value;
+
+/// Classifies every type of error a hub can return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HubErrorCode {
+    /// The request did not have valid authentication credentials, retry with credentials
+    Unauthenticated,
+    /// The authenticated request did not have the authority to perform this action
+    Unauthorized,
+    /// The request cannot be completed as constructed, do not retry
+    BadRequest,
+    BadRequestParseFailure,
+    BadRequestInvalidParam,
+    BadRequestValidationFailure,
+    BadRequestUnknownSigner,
+    BadRequestDuplicate,
+    BadRequestConflict,
+    BadRequestPrunable,
+    BadRequestNoStorage,
+    BadRequestUnknownFid,
+    /// The requested resource could not be found
+    NotFound,
+    /// The request could not be completed because the operation is not executable
+    NotImplemented,
+    NotImplementedDeprecated,
+    /// The request could not be completed, it may or may not be safe to retry
+    Unavailable,
+    UnavailableNetworkFailure,
+    UnavailableStorageFailure,
+    /// An unknown error was encountered
+    Unknown,
+}
+
+impl HubErrorCode {
+    pub const ALL: [HubErrorCode; 19] = [
+        HubErrorCode::Unauthenticated,
+        HubErrorCode::Unauthorized,
+        HubErrorCode::BadRequest,
+        HubErrorCode::BadRequestParseFailure,
+        HubErrorCode::BadRequestInvalidParam,
+        HubErrorCode::BadRequestValidationFailure,
+        HubErrorCode::BadRequestUnknownSigner,
+        HubErrorCode::BadRequestDuplicate,
+        HubErrorCode::BadRequestConflict,
+        HubErrorCode::BadRequestPrunable,
+        HubErrorCode::BadRequestNoStorage,
+        HubErrorCode::BadRequestUnknownFid,
+        HubErrorCode::NotFound,
+        HubErrorCode::NotImplemented,
+        HubErrorCode::NotImplementedDeprecated,
+        HubErrorCode::Unavailable,
+        HubErrorCode::UnavailableNetworkFailure,
+        HubErrorCode::UnavailableStorageFailure,
+        HubErrorCode::Unknown,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HubErrorCode::Unauthenticated => "unauthenticated",
+            HubErrorCode::Unauthorized => "unauthorized",
+            HubErrorCode::BadRequest => "bad_request",
+            HubErrorCode::BadRequestParseFailure => "bad_request.parse_failure",
+            HubErrorCode::BadRequestInvalidParam => "bad_request.invalid_param",
+            HubErrorCode::BadRequestValidationFailure => "bad_request.validation_failure",
+            HubErrorCode::BadRequestUnknownSigner => "bad_request.unknown_signer",
+            HubErrorCode::BadRequestDuplicate => "bad_request.duplicate",
+            HubErrorCode::BadRequestConflict => "bad_request.conflict",
+            HubErrorCode::BadRequestPrunable => "bad_request.prunable",
+            HubErrorCode::BadRequestNoStorage => "bad_request.no_storage",
+            HubErrorCode::BadRequestUnknownFid => "bad_request.unknown_fid",
+            HubErrorCode::NotFound => "not_found",
+            HubErrorCode::NotImplemented => "not_implemented",
+            HubErrorCode::NotImplementedDeprecated => "not_implemented.deprecated",
+            HubErrorCode::Unavailable => "unavailable",
+            HubErrorCode::UnavailableNetworkFailure => "unavailable.network_failure",
+            HubErrorCode::UnavailableStorageFailure => "unavailable.storage_failure",
+            HubErrorCode::Unknown => "unknown",
+        }
+    }
+
+    /// The top level code, e.g. `BadRequest` for `BadRequestDuplicate`.
+    pub fn category(&self) -> HubErrorCode {
+        let category = self.as_str().split('.').next().unwrap_or_default();
+        category.parse().unwrap_or(HubErrorCode::Unknown)
+    }
+
+    pub fn is_bad_request(&self) -> bool {
+        self.category() == HubErrorCode::BadRequest
+    }
+
+    pub fn is_unavailable(&self) -> bool {
+        self.category() == HubErrorCode::Unavailable
+    }
+}
+
+impl fmt::Display for HubErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Codes not known to this crate parse as an error so callers can fall back to `Unknown`.
+impl FromStr for HubErrorCode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HubErrorCode::ALL
+            .into_iter()
+            .find(|code| code.as_str() == s)
+            .ok_or(())
+    }
+}
+
+/// An error returned by a hub, classified by its `errCode`.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("{code}: {message}")]
+pub struct HubError {
+    pub code: HubErrorCode,
+    pub message: String,
+}
+
+impl HubError {
+    pub fn new(code: HubErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// Parses the JSON error body returned by the HTTP API, e.g.
+    /// `{"errCode": "bad_request.duplicate", "details": "message has already been merged"}`.
+    ///
+    /// The code is read from `errCode`, falling back to the gRPC style `metadata.errcode`.
+    /// Returns `None` if the body carries no error code.
+    pub fn from_json(body: &Value) -> Option<Self> {
+        let code = body["errCode"]
+            .as_str()
+            .or_else(|| body["metadata"]["errcode"][0].as_str())?;
+        let message = ["details", "message", "error"]
+            .iter()
+            .find_map(|key| body[key].as_str())
+            .unwrap_or_default();
+        Some(Self::new(
+            code.parse().unwrap_or(HubErrorCode::Unknown),
+            message,
+        ))
+    }
+
+    pub fn is_duplicate(&self) -> bool {
+        self.code == HubErrorCode::BadRequestDuplicate
+    }
+
+    pub fn is_validation_failure(&self) -> bool {
+        self.code == HubErrorCode::BadRequestValidationFailure
+    }
+
+    /// Hubs reject submissions over the per-fid rate limit as `unavailable` with a
+    /// "too many requests" message.
+    pub fn is_rate_limited(&self) -> bool {
+        let message = self.message.to_lowercase();
+        self.code.is_unavailable()
+            && (message.contains("too many") || message.contains("rate limit"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn round_trips_every_code() {
+        for code in HubErrorCode::ALL {
+            assert_eq!(code.as_str().parse(), Ok(code));
+        }
+        assert_eq!("bad_request.nope".parse::<HubErrorCode>(), Err(()));
+    }
+
+    #[test]
+    fn classifies_codes() {
+        assert_eq!(
+            HubErrorCode::BadRequestDuplicate.category(),
+            HubErrorCode::BadRequest
+        );
+        assert!(HubErrorCode::BadRequestNoStorage.is_bad_request());
+        assert!(HubErrorCode::UnavailableStorageFailure.is_unavailable());
+        assert_eq!(HubErrorCode::NotFound.category(), HubErrorCode::NotFound);
+    }
+
+    #[test]
+    fn parses_http_error_bodies() {
+        let error = HubError::from_json(&json!({
+            "errCode": "bad_request.duplicate",
+            "presentable": false,
+            "name": "HubError",
+            "code": 3,
+            "details": "message has already been merged",
+            "metadata": { "errcode": ["bad_request.duplicate"] }
+        }))
+        .unwrap();
+        assert!(error.is_duplicate());
+        assert_eq!(error.message, "message has already been merged");
+
+        let error = HubError::from_json(&json!({
+            "metadata": { "errcode": ["unavailable"] },
+            "details": "Too many requests"
+        }))
+        .unwrap();
+        assert!(error.is_rate_limited());
+
+        let error = HubError::from_json(&json!({ "errCode": "teapot" })).unwrap();
+        assert_eq!(error.code, HubErrorCode::Unknown);
+
+        assert!(HubError::from_json(&json!({ "message": "oops" })).is_none());
+    }
+}
@@ -0,0 +1,37 @@
+//! The Ethereum primitives needed to sign and check Farcaster's EIP-712 claims.
+
+use sha3::{Digest, Keccak256};
+
+pub mod wallet;
+
+pub use wallet::{recover_address, EthError, EthResult, EthWallet};
+
+/// A 20-byte Ethereum address.
+pub type Address = [u8; 20];
+
+/// Keccak-256, the pre-standard SHA-3 variant Ethereum hashes with.
+pub fn keccak256(bytes: &[u8]) -> [u8; 32] {
+    Keccak256::digest(bytes).into()
+}
+
+/// Formats bytes as lowercase `0x`-prefixed hex, as viem's `bytesToHex` does.
+pub fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_known_keccak_digests() {
+        assert_eq!(
+            hex::encode(keccak256(b"")),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex::encode(keccak256(b"hello")),
+            "1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8"
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! secp256k1 keys and Ethereum-style recoverable signatures.
+
+use std::fmt;
+
+use k256::ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey};
+use rand::rngs::OsRng;
+
+use super::{keccak256, Address};
+
+#[derive(Debug, thiserror::Error)]
+pub enum EthError {
+    #[error("secp256k1 operation failed: {0}")]
+    Ecdsa(#[from] k256::ecdsa::Error),
+    #[error("invalid secp256k1 private key")]
+    InvalidPrivateKey,
+    #[error("signature must be 65 bytes, got {0}")]
+    InvalidSignatureLength(usize),
+    #[error("invalid signature")]
+    InvalidSignature,
+}
+
+pub type EthResult<T> = Result<T, EthError>;
+
+/// The first account of the hardhat / anvil test mnemonic, for fixed test vectors.
+#[cfg(test)]
+pub(crate) const TEST_KEY: &str =
+    "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+fn public_key_address(public_key: &VerifyingKey) -> Address {
+    let public_key = public_key.to_encoded_point(false);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&keccak256(&public_key.as_bytes()[1..])[12..]);
+    address
+}
+
+/// A local secp256k1 key, like an Ethereum EOA.
+pub struct EthWallet {
+    key: SigningKey,
+    address: Address,
+}
+
+impl fmt::Debug for EthWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EthWallet")
+            .field("address", &super::to_hex(&self.address))
+            .finish()
+    }
+}
+
+impl EthWallet {
+    pub fn from_bytes(secret: &[u8; 32]) -> EthResult<Self> {
+        let key = SigningKey::from_slice(secret).map_err(|_| EthError::InvalidPrivateKey)?;
+        Ok(Self::from_key(key))
+    }
+
+    /// Parses a hex private key, with or without a `0x` prefix.
+    pub fn from_hex(secret: &str) -> EthResult<Self> {
+        let bytes = hex::decode(secret.trim_start_matches("0x"))
+            .map_err(|_| EthError::InvalidPrivateKey)?;
+        let secret: [u8; 32] = bytes.try_into().map_err(|_| EthError::InvalidPrivateKey)?;
+        Self::from_bytes(&secret)
+    }
+
+    pub fn random() -> Self {
+        Self::from_key(SigningKey::random(&mut OsRng))
+    }
+
+    fn from_key(key: SigningKey) -> Self {
+        let address = public_key_address(key.verifying_key());
+        Self { key, address }
+    }
+
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Signs a 32-byte digest with a deterministic (RFC 6979) nonce, returning `r || s || v` with
+    /// a low `s` and `v` of 27 or 28.
+    pub fn sign_hash(&self, hash: &[u8; 32]) -> EthResult<[u8; 65]> {
+        let (signature, recovery_id) = self.key.sign_prehash_recoverable(hash)?;
+        let mut bytes = [0u8; 65];
+        bytes[..64].copy_from_slice(&signature.to_bytes());
+        bytes[64] = 27 + recovery_id.to_byte();
+        Ok(bytes)
+    }
+}
+
+/// Recovers the address that produced `signature` (`r || s || v`) over a 32-byte digest, like
+/// Ethereum's `ecrecover`. `v` may be 0, 1, 27 or 28.
+pub fn recover_address(hash: &[u8; 32], signature: &[u8]) -> EthResult<Address> {
+    if signature.len() != 65 {
+        return Err(EthError::InvalidSignatureLength(signature.len()));
+    }
+    let recovery_id = match signature[64] {
+        0 | 27 => RecoveryId::new(false, false),
+        1 | 28 => RecoveryId::new(true, false),
+        _ => return Err(EthError::InvalidSignature),
+    };
+    let signature =
+        Signature::from_slice(&signature[..64]).map_err(|_| EthError::InvalidSignature)?;
+    let public_key = VerifyingKey::recover_from_prehash(hash, &signature, recovery_id)
+        .map_err(|_| EthError::InvalidSignature)?;
+    Ok(public_key_address(&public_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_ADDRESS: &str = "f39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+
+    #[test]
+    fn derives_addresses_and_recovers_signers() {
+        let wallet = EthWallet::from_hex(TEST_KEY).unwrap();
+        assert_eq!(hex::encode(wallet.address()), TEST_ADDRESS);
+
+        let hash = keccak256(b"farcaster");
+        let signature = wallet.sign_hash(&hash).unwrap();
+        assert_eq!(
+            recover_address(&hash, &signature).unwrap(),
+            wallet.address()
+        );
+
+        let other = keccak256(b"other");
+        assert_ne!(
+            recover_address(&other, &signature).ok(),
+            Some(wallet.address())
+        );
+        assert!(matches!(
+            recover_address(&hash, &signature[..64]),
+            Err(EthError::InvalidSignatureLength(64))
+        ));
+        assert!(EthWallet::from_bytes(&[0u8; 32]).is_err());
+        assert!(EthWallet::from_bytes(&[0xff; 32]).is_err());
+    }
+
+    #[test]
+    fn signs_deterministically_with_low_s() {
+        let wallet = EthWallet::random();
+        let hash = keccak256(b"farcaster");
+        let signature = wallet.sign_hash(&hash).unwrap();
+        assert_eq!(wallet.sign_hash(&hash).unwrap(), signature);
+        assert!(signature[32] < 0x80);
+        assert!(matches!(signature[64], 27 | 28));
+
+        let mut high_v = signature;
+        high_v[64] = 29;
+        assert!(matches!(
+            recover_address(&hash, &high_v),
+            Err(EthError::InvalidSignature)
+        ));
+    }
+}
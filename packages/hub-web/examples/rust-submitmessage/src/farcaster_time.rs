@@ -0,0 +1,143 @@
+//! Conversions between Unix time and Farcaster time, mirroring packages/core/src/time.ts.
+//!
+//! Farcaster timestamps are seconds since the Farcaster epoch stored as a `u32`.
+
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const FARCASTER_EPOCH: u64 = 1609459200; // January 1, 2021 UTC
+pub const FARCASTER_EPOCH_MS: u64 = FARCASTER_EPOCH * 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum TimeError {
+    #[error("time must be after Farcaster epoch (01/01/2021)")]
+    BeforeEpoch,
+    #[error("time too far in future")]
+    TooFarInFuture,
+}
+
+/// Converts unix milliseconds to seconds since the Farcaster epoch, rounding to the nearest second.
+pub fn to_farcaster_time(unix_ms: u64) -> Result<u32, TimeError> {
+    let ms_since_epoch = unix_ms
+        .checked_sub(FARCASTER_EPOCH_MS)
+        .ok_or(TimeError::BeforeEpoch)?;
+    let seconds = (ms_since_epoch + 500) / 1000;
+    u32::try_from(seconds).map_err(|_| TimeError::TooFarInFuture)
+}
+
+/// Converts seconds since the Farcaster epoch to a `SystemTime`.
+pub fn from_farcaster_time(time: u32) -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(FARCASTER_EPOCH + u64::from(time))
+}
+
+/// Converts a `SystemTime` to seconds since the Farcaster epoch.
+pub fn system_time_to_farcaster_time(time: SystemTime) -> Result<u32, TimeError> {
+    let since_unix_epoch = time
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| TimeError::BeforeEpoch)?;
+    let unix_ms = u64::try_from(since_unix_epoch.as_millis()).unwrap_or(u64::MAX);
+    to_farcaster_time(unix_ms)
+}
+
+/// The current Farcaster time according to the system clock.
+pub fn get_farcaster_time() -> Result<u32, TimeError> {
+    SystemClock.farcaster_time()
+}
+
+/// A source of the current time, so builders and tests can control timestamps.
+pub trait Clock: Debug + Send + Sync {
+    fn now(&self) -> SystemTime;
+
+    fn farcaster_time(&self) -> Result<u32, TimeError> {
+        system_time_to_farcaster_time(self.now())
+    }
+}
+
+/// Reads the system clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock that only moves when told to.
+#[derive(Debug, Default)]
+pub struct FixedClock {
+    unix_ms: AtomicU64,
+}
+
+impl FixedClock {
+    pub fn new(unix_ms: u64) -> Self {
+        Self {
+            unix_ms: AtomicU64::new(unix_ms),
+        }
+    }
+
+    /// A clock set to `time` seconds since the Farcaster epoch.
+    pub fn at_farcaster_time(time: u32) -> Self {
+        Self::new(FARCASTER_EPOCH_MS + u64::from(time) * 1000)
+    }
+
+    pub fn set(&self, unix_ms: u64) {
+        self.unix_ms.store(unix_ms, Ordering::SeqCst);
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        let ms = u64::try_from(duration.as_millis()).unwrap_or(u64::MAX);
+        self.unix_ms.fetch_add(ms, Ordering::SeqCst);
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_millis(self.unix_ms.load(Ordering::SeqCst))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_unix_ms_to_farcaster_time() {
+        assert_eq!(to_farcaster_time(FARCASTER_EPOCH_MS), Ok(0));
+        assert_eq!(to_farcaster_time(FARCASTER_EPOCH_MS + 1_499), Ok(1));
+        assert_eq!(to_farcaster_time(FARCASTER_EPOCH_MS + 1_500), Ok(2));
+        assert_eq!(
+            from_farcaster_time(100),
+            UNIX_EPOCH + Duration::from_secs(FARCASTER_EPOCH + 100)
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_times() {
+        assert_eq!(
+            to_farcaster_time(FARCASTER_EPOCH_MS - 1),
+            Err(TimeError::BeforeEpoch)
+        );
+        let max = FARCASTER_EPOCH_MS + u64::from(u32::MAX) * 1000;
+        assert_eq!(to_farcaster_time(max), Ok(u32::MAX));
+        assert_eq!(
+            to_farcaster_time(max + 1000),
+            Err(TimeError::TooFarInFuture)
+        );
+        assert_eq!(
+            system_time_to_farcaster_time(UNIX_EPOCH),
+            Err(TimeError::BeforeEpoch)
+        );
+    }
+
+    #[test]
+    fn fixed_clock_only_moves_when_advanced() {
+        let clock = FixedClock::at_farcaster_time(10);
+        assert_eq!(clock.farcaster_time(), Ok(10));
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(clock.farcaster_time(), Ok(15));
+        clock.set(0);
+        assert_eq!(clock.farcaster_time(), Err(TimeError::BeforeEpoch));
+    }
+}
@@ -0,0 +1,244 @@
+//! An axum extractor for frame action POSTs.
+//!
+//! ```ignore
+//! let config = FrameConfig::new("https://frames.example.com")
+//!     .signer_lookup(Arc::new(HubHttpClient::new("https://hub.example.com")?));
+//! let app = Router::new().route("/poll", post(vote)).with_state(config);
+//!
+//! async fn vote(VerifiedFrameAction(action): VerifiedFrameAction) -> Html<String> { ... }
+//! ```
+
+use std::sync::Arc;
+
+use axum::async_trait;
+use axum::body::HttpBody;
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{FromRef, FromRequest, OriginalUri};
+use axum::http::{Request, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::{BoxError, Json};
+use serde::Deserialize;
+
+use super::{
+    validate_frame_action, validate_frame_action_with_signer, FrameAction, FrameError, SignerLookup,
+};
+
+/// The JSON body clients POST when a frame button is pressed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FramePacket {
+    /// What the client says the action is. Nothing in it is checked, so read the action from
+    /// `trusted_data` instead.
+    #[serde(default)]
+    pub untrusted_data: serde_json::Value,
+    pub trusted_data: TrustedData,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustedData {
+    /// The hex encoded frame action `Message`.
+    pub message_bytes: String,
+}
+
+/// Where frame actions are expected and how their signers are checked, taken from the router
+/// state.
+#[derive(Clone)]
+pub struct FrameConfig {
+    origin: String,
+    signers: Option<Arc<dyn SignerLookup>>,
+}
+
+impl FrameConfig {
+    /// Frames are served from `origin`, so an action POSTed to `/poll` must be signed for
+    /// `{origin}/poll`. The path is the one the client requested, before any nested router
+    /// strips its prefix.
+    pub fn new(origin: impl Into<String>) -> Self {
+        Self {
+            origin: origin.into().trim_end_matches('/').to_string(),
+            signers: None,
+        }
+    }
+
+    /// Rejects actions that were not signed by an active signer of their fid.
+    pub fn signer_lookup(mut self, signers: Arc<dyn SignerLookup>) -> Self {
+        self.signers = Some(signers);
+        self
+    }
+}
+
+/// A frame action from the request's `trustedData`, validated against [`FrameConfig`].
+#[derive(Debug, Clone)]
+pub struct VerifiedFrameAction(pub FrameAction);
+
+#[derive(Debug, thiserror::Error)]
+pub enum FrameRejection {
+    #[error(transparent)]
+    Json(#[from] JsonRejection),
+    #[error("messageBytes is not hex: {0}")]
+    InvalidHex(#[from] hex::FromHexError),
+    #[error(transparent)]
+    Frame(#[from] FrameError),
+}
+
+impl IntoResponse for FrameRejection {
+    fn into_response(self) -> Response {
+        let status = match self {
+            Self::Json(rejection) => return rejection.into_response(),
+            Self::Frame(FrameError::InactiveSigner(_)) => StatusCode::UNAUTHORIZED,
+            Self::Frame(FrameError::SignerLookup(_)) => StatusCode::BAD_GATEWAY,
+            _ => StatusCode::BAD_REQUEST,
+        };
+        (status, self.to_string()).into_response()
+    }
+}
+
+#[async_trait]
+impl<S, B> FromRequest<S, B> for VerifiedFrameAction
+where
+    FrameConfig: FromRef<S>,
+    S: Send + Sync,
+    B: HttpBody + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<BoxError>,
+{
+    type Rejection = FrameRejection;
+
+    async fn from_request(request: Request<B>, state: &S) -> Result<Self, Self::Rejection> {
+        let config = FrameConfig::from_ref(state);
+        // A nested router strips its prefix from the request URI but keeps the original in
+        // `OriginalUri`, which is the URL the frame action was signed for
+        let uri = match request.extensions().get::<OriginalUri>() {
+            Some(OriginalUri(uri)) => uri,
+            None => request.uri(),
+        };
+        let path = uri.path_and_query().map_or("/", |path| path.as_str());
+        let expected_url = format!("{}{}", config.origin, path);
+
+        let Json(packet) = Json::<FramePacket>::from_request(request, state).await?;
+        let trusted_bytes =
+            hex::decode(packet.trusted_data.message_bytes.trim_start_matches("0x"))?;
+        let action = match &config.signers {
+            Some(signers) => {
+                validate_frame_action_with_signer(&trusted_bytes, &expected_url, signers.as_ref())
+                    .await?
+            }
+            None => validate_frame_action(&trusted_bytes, &expected_url)?,
+        };
+        Ok(Self(action))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bodies::FrameActionBuilder;
+    use crate::message::FarcasterNetwork;
+    use crate::signers::InMemorySigner;
+    use crate::test_utils::test_signing_key;
+    use crate::MessageBuilder;
+    use axum::body::Body;
+    use protobuf::Message as _;
+    use std::collections::HashMap;
+
+    #[async_trait]
+    impl SignerLookup for HashMap<u64, Vec<u8>> {
+        async fn is_active_signer(&self, fid: u64, signer: &[u8]) -> Result<bool, FrameError> {
+            Ok(self.get(&fid).map(Vec::as_slice) == Some(signer))
+        }
+    }
+
+    async fn post(
+        path: &str,
+        url: &str,
+        config: &FrameConfig,
+    ) -> Result<FrameAction, FrameRejection> {
+        let key = test_signing_key();
+        let message = MessageBuilder::new(
+            2,
+            FarcasterNetwork::FARCASTER_NETWORK_MAINNET,
+            FrameActionBuilder::new(url, 1),
+        )
+        .build(&InMemorySigner::new(key))
+        .await
+        .unwrap();
+        let body = serde_json::json!({
+            "untrustedData": { "fid": 2, "url": url, "buttonIndex": 1 },
+            "trustedData": { "messageBytes": hex::encode(message.write_to_bytes().unwrap()) },
+        });
+        let mut request = Request::post(path)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        // What a router nested at `/frames` passes on
+        if let Some(nested) = path.strip_prefix("/frames") {
+            let original = request.uri().clone();
+            request.extensions_mut().insert(OriginalUri(original));
+            *request.uri_mut() = nested.parse().unwrap();
+        }
+        VerifiedFrameAction::from_request(request, config)
+            .await
+            .map(|VerifiedFrameAction(action)| action)
+    }
+
+    #[tokio::test]
+    async fn extracts_verified_frame_actions() {
+        let config = FrameConfig::new("https://frames.example.com/");
+        let action = post("/poll", "https://frames.example.com/poll", &config)
+            .await
+            .unwrap();
+        assert_eq!(action.fid, 2);
+        assert_eq!(action.body.get_button_index(), 1);
+
+        assert!(matches!(
+            post("/poll", "https://evil.example.com/poll", &config).await,
+            Err(FrameRejection::Frame(FrameError::UrlMismatch { .. }))
+        ));
+
+        post(
+            "/frames/poll",
+            "https://frames.example.com/frames/poll",
+            &config,
+        )
+        .await
+        .unwrap();
+
+        let active = test_signing_key().verifying_key().to_bytes();
+        let signers = HashMap::from([(2, active.to_vec())]);
+        let config = config.signer_lookup(Arc::new(signers));
+        post("/poll", "https://frames.example.com/poll", &config)
+            .await
+            .unwrap();
+        let config = config.signer_lookup(Arc::new(HashMap::<u64, Vec<u8>>::new()));
+        let rejection = post("/poll", "https://frames.example.com/poll", &config)
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.into_response().status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_json_with_its_status() {
+        let config = FrameConfig::new("https://frames.example.com");
+        let request = Request::post("/poll")
+            .header("content-type", "application/json")
+            .body(Body::from(r#"{"trustedData":{}}"#))
+            .unwrap();
+        let rejection = VerifiedFrameAction::from_request(request, &config)
+            .await
+            .unwrap_err();
+        assert!(matches!(rejection, FrameRejection::Json(_)));
+        assert_eq!(
+            rejection.into_response().status(),
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+
+        let request = Request::post("/poll").body(Body::from("{}")).unwrap();
+        let rejection = VerifiedFrameAction::from_request(request, &config)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            rejection.into_response().status(),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+    }
+}
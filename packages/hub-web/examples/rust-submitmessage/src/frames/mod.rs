@@ -0,0 +1,243 @@
+//! Server-side validation of frame actions.
+//!
+//! When a user presses a frame button, the client POSTs the signed `MESSAGE_TYPE_FRAME_ACTION`
+//! message as `trustedData.messageBytes`. Frame servers should only trust what is in that message,
+//! after checking it was signed and is for their frame.
+
+#[cfg(feature = "axum")]
+pub mod extract;
+
+use async_trait::async_trait;
+use protobuf::Message as _;
+
+use crate::http::HubHttpClient;
+use crate::message::{
+    CastId, FarcasterNetwork, FrameActionBody, Message, MessageData_oneof_body, MessageType,
+};
+use crate::onchain_event::SignerEventType;
+use crate::validations::{message_data, validate_message, ValidationError, MAX_FRAME_BUTTON_INDEX};
+
+#[derive(Debug, thiserror::Error)]
+pub enum FrameError {
+    #[error("failed to decode frame message: {0}")]
+    Decode(#[from] protobuf::ProtobufError),
+    #[error(transparent)]
+    Invalid(#[from] ValidationError),
+    #[error("expected a frame action, got {0:?}")]
+    InvalidMessageType(MessageType),
+    #[error("frame action is for {actual}, expected {expected}")]
+    UrlMismatch { expected: String, actual: String },
+    #[error("button index must be between 1-{MAX_FRAME_BUTTON_INDEX}, got {0}")]
+    InvalidButtonIndex(u32),
+    #[error("url and input text must be UTF-8")]
+    InvalidUtf8,
+    #[error("signer is not active for fid {0}")]
+    InactiveSigner(u64),
+    #[error("failed to look up signer: {0}")]
+    SignerLookup(String),
+}
+
+pub type FrameResult<T = ()> = Result<T, FrameError>;
+
+/// Looks up whether an ed25519 key is an active signer of an fid.
+#[async_trait]
+pub trait SignerLookup: Send + Sync {
+    async fn is_active_signer(&self, fid: u64, signer: &[u8]) -> FrameResult<bool>;
+}
+
+/// Looks signers up through `onChainSignersByFid`, which only returns active signers.
+#[async_trait]
+impl SignerLookup for HubHttpClient {
+    async fn is_active_signer(&self, fid: u64, signer: &[u8]) -> FrameResult<bool> {
+        let events = self
+            .on_chain_signers_by_fid(fid)
+            .await
+            .map_err(|err| FrameError::SignerLookup(err.to_string()))?;
+        Ok(events.iter().any(|event| {
+            let body = event.get_signer_event_body();
+            body.get_event_type() == SignerEventType::SIGNER_EVENT_TYPE_ADD
+                && body.get_key() == signer
+        }))
+    }
+}
+
+/// A frame action whose signature and contents have been validated.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameAction {
+    pub fid: u64,
+    pub timestamp: u32,
+    pub network: FarcasterNetwork,
+    /// The ed25519 key that signed the action. [`validate_frame_action`] does not check that it
+    /// is an active signer of `fid`; [`validate_frame_action_with_signer`] does.
+    pub signer: Vec<u8>,
+    pub hash: Vec<u8>,
+    pub body: FrameActionBody,
+}
+
+impl FrameAction {
+    pub fn url(&self) -> &str {
+        // Checked to be UTF-8 by `validate_frame_action`.
+        std::str::from_utf8(self.body.get_url()).unwrap_or_default()
+    }
+
+    /// The pressed button, starting at 1.
+    pub fn button_index(&self) -> u32 {
+        self.body.get_button_index()
+    }
+
+    /// The cast the frame was embedded in, if any.
+    pub fn cast_id(&self) -> Option<&CastId> {
+        self.body.cast_id.as_ref()
+    }
+
+    pub fn input_text(&self) -> &str {
+        std::str::from_utf8(self.body.get_input_text()).unwrap_or_default()
+    }
+
+    pub fn state(&self) -> &[u8] {
+        self.body.get_state()
+    }
+
+    pub fn transaction_id(&self) -> &[u8] {
+        self.body.get_transaction_id()
+    }
+
+    pub fn address(&self) -> &[u8] {
+        self.body.get_address()
+    }
+}
+
+/// Decodes `trusted_bytes` as a frame action message and validates it like a hub would, checking
+/// the hash and signature and the url, button index, input text, state, transaction id and address
+/// limits. The signed url must equal `expected_url` exactly.
+pub fn validate_frame_action(trusted_bytes: &[u8], expected_url: &str) -> FrameResult<FrameAction> {
+    let message = Message::parse_from_bytes(trusted_bytes)?;
+    let data = message_data(&message)?;
+    if data.get_field_type() != MessageType::MESSAGE_TYPE_FRAME_ACTION {
+        return Err(FrameError::InvalidMessageType(data.get_field_type()));
+    }
+    validate_message(&message)?;
+
+    let body = match data.body {
+        Some(MessageData_oneof_body::frame_action_body(body)) => body,
+        _ => return Err(ValidationError::InvalidBodyType(data.field_type).into()),
+    };
+    let url = std::str::from_utf8(body.get_url()).map_err(|_| FrameError::InvalidUtf8)?;
+    if url != expected_url {
+        return Err(FrameError::UrlMismatch {
+            expected: expected_url.to_string(),
+            actual: url.to_string(),
+        });
+    }
+    if !(1..=MAX_FRAME_BUTTON_INDEX).contains(&body.get_button_index()) {
+        return Err(FrameError::InvalidButtonIndex(body.get_button_index()));
+    }
+    std::str::from_utf8(body.get_input_text()).map_err(|_| FrameError::InvalidUtf8)?;
+
+    Ok(FrameAction {
+        fid: data.fid,
+        timestamp: data.timestamp,
+        network: data.network,
+        signer: message.signer,
+        hash: message.hash,
+        body,
+    })
+}
+
+/// Like [`validate_frame_action`], then checks with `signers` that the action was signed by an
+/// active signer of its fid.
+pub async fn validate_frame_action_with_signer(
+    trusted_bytes: &[u8],
+    expected_url: &str,
+    signers: &dyn SignerLookup,
+) -> FrameResult<FrameAction> {
+    let action = validate_frame_action(trusted_bytes, expected_url)?;
+    if !signers.is_active_signer(action.fid, &action.signer).await? {
+        return Err(FrameError::InactiveSigner(action.fid));
+    }
+    Ok(action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bodies::{cast_id, CastAddBuilder, FrameActionBuilder, MessageBody};
+    use crate::test_utils::test_signer;
+    use crate::MessageBuilder;
+
+    const URL: &str = "https://frames.example.com/poll";
+
+    async fn bytes(body: impl MessageBody) -> Vec<u8> {
+        MessageBuilder::new(2, FarcasterNetwork::FARCASTER_NETWORK_MAINNET, body)
+            .build(&test_signer())
+            .await
+            .unwrap()
+            .write_to_bytes()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn validates_frame_actions() {
+        let action = FrameActionBuilder::new(URL, 2)
+            .cast_id(cast_id(3, vec![1; 20]))
+            .input_text("yes")
+            .state("{\"page\":1}");
+        let trusted_bytes = bytes(action).await;
+
+        let action = validate_frame_action(&trusted_bytes, URL).unwrap();
+        assert_eq!(action.fid, 2);
+        assert_eq!(action.url(), URL);
+        assert_eq!(action.button_index(), 2);
+        assert_eq!(action.cast_id().unwrap().get_fid(), 3);
+        assert_eq!(action.input_text(), "yes");
+        assert_eq!(action.state(), b"{\"page\":1}");
+
+        assert!(matches!(
+            validate_frame_action(&trusted_bytes, "https://frames.example.com/other"),
+            Err(FrameError::UrlMismatch { .. })
+        ));
+        let mut tampered = trusted_bytes.clone();
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        assert!(validate_frame_action(&tampered, URL).is_err());
+    }
+
+    #[tokio::test]
+    async fn rejects_out_of_range_actions() {
+        let no_button = bytes(FrameActionBuilder::new(URL, 0)).await;
+        assert!(matches!(
+            validate_frame_action(&no_button, URL),
+            Err(FrameError::InvalidButtonIndex(0))
+        ));
+        let long_text = bytes(FrameActionBuilder::new(URL, 1).input_text(vec![b'a'; 257])).await;
+        assert!(matches!(
+            validate_frame_action(&long_text, URL),
+            Err(FrameError::Invalid(ValidationError::InvalidInputText))
+        ));
+        let max_transaction_id =
+            bytes(FrameActionBuilder::new(URL, 1).transaction_id(vec![1; 256])).await;
+        let action = validate_frame_action(&max_transaction_id, URL).unwrap();
+        assert_eq!(action.transaction_id().len(), 256);
+        let long_transaction_id =
+            bytes(FrameActionBuilder::new(URL, 1).transaction_id(vec![1; 257])).await;
+        assert!(matches!(
+            validate_frame_action(&long_transaction_id, URL),
+            Err(FrameError::Invalid(ValidationError::InvalidTransactionId))
+        ));
+        let max_address = bytes(FrameActionBuilder::new(URL, 1).address(vec![1; 64])).await;
+        let action = validate_frame_action(&max_address, URL).unwrap();
+        assert_eq!(action.address().len(), 64);
+        let long_address = bytes(FrameActionBuilder::new(URL, 1).address(vec![1; 65])).await;
+        assert!(matches!(
+            validate_frame_action(&long_address, URL),
+            Err(FrameError::Invalid(ValidationError::InvalidFrameAddress))
+        ));
+        let cast = bytes(CastAddBuilder::new("hi")).await;
+        assert!(matches!(
+            validate_frame_action(&cast, URL),
+            Err(FrameError::InvalidMessageType(
+                MessageType::MESSAGE_TYPE_CAST_ADD
+            ))
+        ));
+    }
+}
@@ -0,0 +1,1167 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `gossip.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ContactInfoBody {
+    // message fields
+    pub gossip_address: ::std::string::String,
+    pub peer_id: ::std::vec::Vec<u8>,
+    pub snapchain_version: ::std::string::String,
+    pub network: super::message::FarcasterNetwork,
+    pub timestamp: u64,
+    pub announce_rpc_address: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ContactInfoBody {
+    fn default() -> &'a ContactInfoBody {
+        <ContactInfoBody as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ContactInfoBody {
+    pub fn new() -> ContactInfoBody {
+        ::std::default::Default::default()
+    }
+
+    // string gossip_address = 1;
+
+
+    pub fn get_gossip_address(&self) -> &str {
+        &self.gossip_address
+    }
+    pub fn clear_gossip_address(&mut self) {
+        self.gossip_address.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gossip_address(&mut self, v: ::std::string::String) {
+        self.gossip_address = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_gossip_address(&mut self) -> &mut ::std::string::String {
+        &mut self.gossip_address
+    }
+
+    // Take field
+    pub fn take_gossip_address(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.gossip_address, ::std::string::String::new())
+    }
+
+    // bytes peer_id = 2;
+
+
+    pub fn get_peer_id(&self) -> &[u8] {
+        &self.peer_id
+    }
+    pub fn clear_peer_id(&mut self) {
+        self.peer_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_peer_id(&mut self, v: ::std::vec::Vec<u8>) {
+        self.peer_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_peer_id(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.peer_id
+    }
+
+    // Take field
+    pub fn take_peer_id(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.peer_id, ::std::vec::Vec::new())
+    }
+
+    // string snapchain_version = 3;
+
+
+    pub fn get_snapchain_version(&self) -> &str {
+        &self.snapchain_version
+    }
+    pub fn clear_snapchain_version(&mut self) {
+        self.snapchain_version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snapchain_version(&mut self, v: ::std::string::String) {
+        self.snapchain_version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_snapchain_version(&mut self) -> &mut ::std::string::String {
+        &mut self.snapchain_version
+    }
+
+    // Take field
+    pub fn take_snapchain_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.snapchain_version, ::std::string::String::new())
+    }
+
+    // .FarcasterNetwork network = 4;
+
+
+    pub fn get_network(&self) -> super::message::FarcasterNetwork {
+        self.network
+    }
+    pub fn clear_network(&mut self) {
+        self.network = super::message::FarcasterNetwork::FARCASTER_NETWORK_NONE;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_network(&mut self, v: super::message::FarcasterNetwork) {
+        self.network = v;
+    }
+
+    // uint64 timestamp = 5;
+
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: u64) {
+        self.timestamp = v;
+    }
+
+    // string announce_rpc_address = 6;
+
+
+    pub fn get_announce_rpc_address(&self) -> &str {
+        &self.announce_rpc_address
+    }
+    pub fn clear_announce_rpc_address(&mut self) {
+        self.announce_rpc_address.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_announce_rpc_address(&mut self, v: ::std::string::String) {
+        self.announce_rpc_address = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_announce_rpc_address(&mut self) -> &mut ::std::string::String {
+        &mut self.announce_rpc_address
+    }
+
+    // Take field
+    pub fn take_announce_rpc_address(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.announce_rpc_address, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for ContactInfoBody {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.gossip_address)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.peer_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.snapchain_version)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.network, 4, &mut self.unknown_fields)?
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.timestamp = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.announce_rpc_address)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.gossip_address.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.gossip_address);
+        }
+        if !self.peer_id.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.peer_id);
+        }
+        if !self.snapchain_version.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.snapchain_version);
+        }
+        if self.network != super::message::FarcasterNetwork::FARCASTER_NETWORK_NONE {
+            my_size += ::protobuf::rt::enum_size(4, self.network);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.announce_rpc_address.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.announce_rpc_address);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.gossip_address.is_empty() {
+            os.write_string(1, &self.gossip_address)?;
+        }
+        if !self.peer_id.is_empty() {
+            os.write_bytes(2, &self.peer_id)?;
+        }
+        if !self.snapchain_version.is_empty() {
+            os.write_string(3, &self.snapchain_version)?;
+        }
+        if self.network != super::message::FarcasterNetwork::FARCASTER_NETWORK_NONE {
+            os.write_enum(4, ::protobuf::ProtobufEnum::value(&self.network))?;
+        }
+        if self.timestamp != 0 {
+            os.write_uint64(5, self.timestamp)?;
+        }
+        if !self.announce_rpc_address.is_empty() {
+            os.write_string(6, &self.announce_rpc_address)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ContactInfoBody {
+        ContactInfoBody::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "gossip_address",
+                |m: &ContactInfoBody| { &m.gossip_address },
+                |m: &mut ContactInfoBody| { &mut m.gossip_address },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "peer_id",
+                |m: &ContactInfoBody| { &m.peer_id },
+                |m: &mut ContactInfoBody| { &mut m.peer_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "snapchain_version",
+                |m: &ContactInfoBody| { &m.snapchain_version },
+                |m: &mut ContactInfoBody| { &mut m.snapchain_version },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::message::FarcasterNetwork>>(
+                "network",
+                |m: &ContactInfoBody| { &m.network },
+                |m: &mut ContactInfoBody| { &mut m.network },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "timestamp",
+                |m: &ContactInfoBody| { &m.timestamp },
+                |m: &mut ContactInfoBody| { &mut m.timestamp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "announce_rpc_address",
+                |m: &ContactInfoBody| { &m.announce_rpc_address },
+                |m: &mut ContactInfoBody| { &mut m.announce_rpc_address },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ContactInfoBody>(
+                "ContactInfoBody",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ContactInfoBody {
+        static instance: ::protobuf::rt::LazyV2<ContactInfoBody> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ContactInfoBody::new)
+    }
+}
+
+impl ::protobuf::Clear for ContactInfoBody {
+    fn clear(&mut self) {
+        self.gossip_address.clear();
+        self.peer_id.clear();
+        self.snapchain_version.clear();
+        self.network = super::message::FarcasterNetwork::FARCASTER_NETWORK_NONE;
+        self.timestamp = 0;
+        self.announce_rpc_address.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ContactInfoBody {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ContactInfoBody {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ContactInfo {
+    // message fields
+    pub body: ::protobuf::SingularPtrField<ContactInfoBody>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ContactInfo {
+    fn default() -> &'a ContactInfo {
+        <ContactInfo as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ContactInfo {
+    pub fn new() -> ContactInfo {
+        ::std::default::Default::default()
+    }
+
+    // .ContactInfoBody body = 1;
+
+
+    pub fn get_body(&self) -> &ContactInfoBody {
+        self.body.as_ref().unwrap_or_else(|| <ContactInfoBody as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_body(&mut self) {
+        self.body.clear();
+    }
+
+    pub fn has_body(&self) -> bool {
+        self.body.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_body(&mut self, v: ContactInfoBody) {
+        self.body = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_body(&mut self) -> &mut ContactInfoBody {
+        if self.body.is_none() {
+            self.body.set_default();
+        }
+        self.body.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_body(&mut self) -> ContactInfoBody {
+        self.body.take().unwrap_or_else(|| ContactInfoBody::new())
+    }
+}
+
+impl ::protobuf::Message for ContactInfo {
+    fn is_initialized(&self) -> bool {
+        for v in &self.body {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.body)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.body.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.body.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ContactInfo {
+        ContactInfo::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ContactInfoBody>>(
+                "body",
+                |m: &ContactInfo| { &m.body },
+                |m: &mut ContactInfo| { &mut m.body },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ContactInfo>(
+                "ContactInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ContactInfo {
+        static instance: ::protobuf::rt::LazyV2<ContactInfo> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ContactInfo::new)
+    }
+}
+
+impl ::protobuf::Clear for ContactInfo {
+    fn clear(&mut self) {
+        self.body.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ContactInfo {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ContactInfo {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GossipMessage {
+    // message oneof groups
+    pub gossip_message: ::std::option::Option<GossipMessage_oneof_gossip_message>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GossipMessage {
+    fn default() -> &'a GossipMessage {
+        <GossipMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum GossipMessage_oneof_gossip_message {
+    consensus(super::blocks::ConsensusMessage),
+    full_proposal(super::blocks::FullProposal),
+    mempool_message(super::blocks::MempoolMessage),
+    status(super::blocks::StatusMessage),
+    read_node_message(super::blocks::ReadNodeMessage),
+    contact_info_message(ContactInfo),
+}
+
+impl GossipMessage {
+    pub fn new() -> GossipMessage {
+        ::std::default::Default::default()
+    }
+
+    // .ConsensusMessage consensus = 1;
+
+
+    pub fn get_consensus(&self) -> &super::blocks::ConsensusMessage {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::consensus(ref v)) => v,
+            _ => <super::blocks::ConsensusMessage as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_consensus(&mut self) {
+        self.gossip_message = ::std::option::Option::None;
+    }
+
+    pub fn has_consensus(&self) -> bool {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::consensus(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_consensus(&mut self, v: super::blocks::ConsensusMessage) {
+        self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::consensus(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_consensus(&mut self) -> &mut super::blocks::ConsensusMessage {
+        if let ::std::option::Option::Some(GossipMessage_oneof_gossip_message::consensus(_)) = self.gossip_message {
+        } else {
+            self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::consensus(super::blocks::ConsensusMessage::new()));
+        }
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::consensus(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_consensus(&mut self) -> super::blocks::ConsensusMessage {
+        if self.has_consensus() {
+            match self.gossip_message.take() {
+                ::std::option::Option::Some(GossipMessage_oneof_gossip_message::consensus(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::blocks::ConsensusMessage::new()
+        }
+    }
+
+    // .FullProposal full_proposal = 2;
+
+
+    pub fn get_full_proposal(&self) -> &super::blocks::FullProposal {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::full_proposal(ref v)) => v,
+            _ => <super::blocks::FullProposal as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_full_proposal(&mut self) {
+        self.gossip_message = ::std::option::Option::None;
+    }
+
+    pub fn has_full_proposal(&self) -> bool {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::full_proposal(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_full_proposal(&mut self, v: super::blocks::FullProposal) {
+        self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::full_proposal(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_full_proposal(&mut self) -> &mut super::blocks::FullProposal {
+        if let ::std::option::Option::Some(GossipMessage_oneof_gossip_message::full_proposal(_)) = self.gossip_message {
+        } else {
+            self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::full_proposal(super::blocks::FullProposal::new()));
+        }
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::full_proposal(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_full_proposal(&mut self) -> super::blocks::FullProposal {
+        if self.has_full_proposal() {
+            match self.gossip_message.take() {
+                ::std::option::Option::Some(GossipMessage_oneof_gossip_message::full_proposal(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::blocks::FullProposal::new()
+        }
+    }
+
+    // .MempoolMessage mempool_message = 3;
+
+
+    pub fn get_mempool_message(&self) -> &super::blocks::MempoolMessage {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::mempool_message(ref v)) => v,
+            _ => <super::blocks::MempoolMessage as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_mempool_message(&mut self) {
+        self.gossip_message = ::std::option::Option::None;
+    }
+
+    pub fn has_mempool_message(&self) -> bool {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::mempool_message(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mempool_message(&mut self, v: super::blocks::MempoolMessage) {
+        self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::mempool_message(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mempool_message(&mut self) -> &mut super::blocks::MempoolMessage {
+        if let ::std::option::Option::Some(GossipMessage_oneof_gossip_message::mempool_message(_)) = self.gossip_message {
+        } else {
+            self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::mempool_message(super::blocks::MempoolMessage::new()));
+        }
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::mempool_message(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_mempool_message(&mut self) -> super::blocks::MempoolMessage {
+        if self.has_mempool_message() {
+            match self.gossip_message.take() {
+                ::std::option::Option::Some(GossipMessage_oneof_gossip_message::mempool_message(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::blocks::MempoolMessage::new()
+        }
+    }
+
+    // .StatusMessage status = 4;
+
+
+    pub fn get_status(&self) -> &super::blocks::StatusMessage {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::status(ref v)) => v,
+            _ => <super::blocks::StatusMessage as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_status(&mut self) {
+        self.gossip_message = ::std::option::Option::None;
+    }
+
+    pub fn has_status(&self) -> bool {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::status(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_status(&mut self, v: super::blocks::StatusMessage) {
+        self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::status(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_status(&mut self) -> &mut super::blocks::StatusMessage {
+        if let ::std::option::Option::Some(GossipMessage_oneof_gossip_message::status(_)) = self.gossip_message {
+        } else {
+            self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::status(super::blocks::StatusMessage::new()));
+        }
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::status(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_status(&mut self) -> super::blocks::StatusMessage {
+        if self.has_status() {
+            match self.gossip_message.take() {
+                ::std::option::Option::Some(GossipMessage_oneof_gossip_message::status(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::blocks::StatusMessage::new()
+        }
+    }
+
+    // .ReadNodeMessage read_node_message = 5;
+
+
+    pub fn get_read_node_message(&self) -> &super::blocks::ReadNodeMessage {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::read_node_message(ref v)) => v,
+            _ => <super::blocks::ReadNodeMessage as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_read_node_message(&mut self) {
+        self.gossip_message = ::std::option::Option::None;
+    }
+
+    pub fn has_read_node_message(&self) -> bool {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::read_node_message(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_read_node_message(&mut self, v: super::blocks::ReadNodeMessage) {
+        self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::read_node_message(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_read_node_message(&mut self) -> &mut super::blocks::ReadNodeMessage {
+        if let ::std::option::Option::Some(GossipMessage_oneof_gossip_message::read_node_message(_)) = self.gossip_message {
+        } else {
+            self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::read_node_message(super::blocks::ReadNodeMessage::new()));
+        }
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::read_node_message(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_read_node_message(&mut self) -> super::blocks::ReadNodeMessage {
+        if self.has_read_node_message() {
+            match self.gossip_message.take() {
+                ::std::option::Option::Some(GossipMessage_oneof_gossip_message::read_node_message(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            super::blocks::ReadNodeMessage::new()
+        }
+    }
+
+    // .ContactInfo contact_info_message = 6;
+
+
+    pub fn get_contact_info_message(&self) -> &ContactInfo {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::contact_info_message(ref v)) => v,
+            _ => <ContactInfo as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_contact_info_message(&mut self) {
+        self.gossip_message = ::std::option::Option::None;
+    }
+
+    pub fn has_contact_info_message(&self) -> bool {
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::contact_info_message(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_contact_info_message(&mut self, v: ContactInfo) {
+        self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::contact_info_message(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_contact_info_message(&mut self) -> &mut ContactInfo {
+        if let ::std::option::Option::Some(GossipMessage_oneof_gossip_message::contact_info_message(_)) = self.gossip_message {
+        } else {
+            self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::contact_info_message(ContactInfo::new()));
+        }
+        match self.gossip_message {
+            ::std::option::Option::Some(GossipMessage_oneof_gossip_message::contact_info_message(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_contact_info_message(&mut self) -> ContactInfo {
+        if self.has_contact_info_message() {
+            match self.gossip_message.take() {
+                ::std::option::Option::Some(GossipMessage_oneof_gossip_message::contact_info_message(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ContactInfo::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for GossipMessage {
+    fn is_initialized(&self) -> bool {
+        if let Some(GossipMessage_oneof_gossip_message::consensus(ref v)) = self.gossip_message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GossipMessage_oneof_gossip_message::full_proposal(ref v)) = self.gossip_message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GossipMessage_oneof_gossip_message::mempool_message(ref v)) = self.gossip_message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GossipMessage_oneof_gossip_message::status(ref v)) = self.gossip_message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GossipMessage_oneof_gossip_message::read_node_message(ref v)) = self.gossip_message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GossipMessage_oneof_gossip_message::contact_info_message(ref v)) = self.gossip_message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::consensus(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::full_proposal(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::mempool_message(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::status(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::read_node_message(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.gossip_message = ::std::option::Option::Some(GossipMessage_oneof_gossip_message::contact_info_message(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.gossip_message {
+            match v {
+                &GossipMessage_oneof_gossip_message::consensus(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GossipMessage_oneof_gossip_message::full_proposal(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GossipMessage_oneof_gossip_message::mempool_message(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GossipMessage_oneof_gossip_message::status(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GossipMessage_oneof_gossip_message::read_node_message(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GossipMessage_oneof_gossip_message::contact_info_message(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.gossip_message {
+            match v {
+                &GossipMessage_oneof_gossip_message::consensus(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GossipMessage_oneof_gossip_message::full_proposal(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GossipMessage_oneof_gossip_message::mempool_message(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GossipMessage_oneof_gossip_message::status(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GossipMessage_oneof_gossip_message::read_node_message(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GossipMessage_oneof_gossip_message::contact_info_message(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GossipMessage {
+        GossipMessage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::blocks::ConsensusMessage>(
+                "consensus",
+                GossipMessage::has_consensus,
+                GossipMessage::get_consensus,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::blocks::FullProposal>(
+                "full_proposal",
+                GossipMessage::has_full_proposal,
+                GossipMessage::get_full_proposal,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::blocks::MempoolMessage>(
+                "mempool_message",
+                GossipMessage::has_mempool_message,
+                GossipMessage::get_mempool_message,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::blocks::StatusMessage>(
+                "status",
+                GossipMessage::has_status,
+                GossipMessage::get_status,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, super::blocks::ReadNodeMessage>(
+                "read_node_message",
+                GossipMessage::has_read_node_message,
+                GossipMessage::get_read_node_message,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ContactInfo>(
+                "contact_info_message",
+                GossipMessage::has_contact_info_message,
+                GossipMessage::get_contact_info_message,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GossipMessage>(
+                "GossipMessage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GossipMessage {
+        static instance: ::protobuf::rt::LazyV2<GossipMessage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GossipMessage::new)
+    }
+}
+
+impl ::protobuf::Clear for GossipMessage {
+    fn clear(&mut self) {
+        self.gossip_message = ::std::option::Option::None;
+        self.gossip_message = ::std::option::Option::None;
+        self.gossip_message = ::std::option::Option::None;
+        self.gossip_message = ::std::option::Option::None;
+        self.gossip_message = ::std::option::Option::None;
+        self.gossip_message = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GossipMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GossipMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0cgossip.proto\x1a\x0cblocks.proto\x1a\rmessage.proto\"\x89\x02\n\
+    \x0fContactInfoBody\x12'\n\x0egossip_address\x18\x01\x20\x01(\tR\rgossip\
+    AddressB\0\x12\x19\n\x07peer_id\x18\x02\x20\x01(\x0cR\x06peerIdB\0\x12-\
+    \n\x11snapchain_version\x18\x03\x20\x01(\tR\x10snapchainVersionB\0\x12-\
+    \n\x07network\x18\x04\x20\x01(\x0e2\x11.FarcasterNetworkR\x07networkB\0\
+    \x12\x1e\n\ttimestamp\x18\x05\x20\x01(\x04R\ttimestampB\0\x122\n\x14anno\
+    unce_rpc_address\x18\x06\x20\x01(\tR\x12announceRpcAddressB\0:\0\"7\n\
+    \x0bContactInfo\x12&\n\x04body\x18\x01\x20\x01(\x0b2\x10.ContactInfoBody\
+    R\x04bodyB\0:\0\"\x80\x03\n\rGossipMessage\x123\n\tconsensus\x18\x01\x20\
+    \x01(\x0b2\x11.ConsensusMessageH\0R\tconsensusB\0\x126\n\rfull_proposal\
+    \x18\x02\x20\x01(\x0b2\r.FullProposalH\0R\x0cfullProposalB\0\x12<\n\x0fm\
+    empool_message\x18\x03\x20\x01(\x0b2\x0f.MempoolMessageH\0R\x0emempoolMe\
+    ssageB\0\x12*\n\x06status\x18\x04\x20\x01(\x0b2\x0e.StatusMessageH\0R\
+    \x06statusB\0\x12@\n\x11read_node_message\x18\x05\x20\x01(\x0b2\x10.Read\
+    NodeMessageH\0R\x0freadNodeMessageB\0\x12B\n\x14contact_info_message\x18\
+    \x06\x20\x01(\x0b2\x0c.ContactInfoH\0R\x12contactInfoMessageB\0B\x10\n\
+    \x0egossip_message:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,168 @@
+//! A minimal in-process `HubService` standing in for a hub's gRPC API in tests.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::body::BoxBody;
+use tonic::codegen::{http, BoxFuture, Service};
+use tonic::server::{Grpc, NamedService, ServerStreamingService, UnaryService};
+use tonic::transport::{Body, Channel, Server};
+use tonic::Status;
+
+use super::ProtobufCodec;
+
+type Route =
+    dyn Fn(http::Request<Body>) -> BoxFuture<http::Response<BoxBody>, Infallible> + Send + Sync;
+
+/// Routes `/HubService/<Method>` calls to handlers; every other method is unimplemented.
+#[derive(Clone, Default)]
+pub(crate) struct MockHubService {
+    routes: HashMap<String, Arc<Route>>,
+}
+
+impl MockHubService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn unary<Req, Resp>(
+        mut self,
+        method: &str,
+        handler: impl Fn(Req) -> Result<Resp, Status> + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: protobuf::Message,
+        Resp: protobuf::Message,
+    {
+        let handler = Arc::new(handler);
+        self.routes.insert(
+            format!("/HubService/{}", method),
+            Arc::new(move |request| {
+                let handler = Handler(handler.clone());
+                Box::pin(async move {
+                    let mut grpc = Grpc::new(ProtobufCodec::<Resp, Req>::default());
+                    Ok(grpc.unary(handler, request).await)
+                })
+            }),
+        );
+        self
+    }
+
+    /// Hands `handler` the encoded request and replies with the bytes it returns.
+    #[cfg(feature = "legacy-sync")]
+    pub fn raw_unary(
+        mut self,
+        method: &str,
+        handler: impl Fn(Vec<u8>) -> Result<Vec<u8>, Status> + Send + Sync + 'static,
+    ) -> Self {
+        let handler = Arc::new(handler);
+        self.routes.insert(
+            format!("/HubService/{}", method),
+            Arc::new(move |request| {
+                let handler = Handler(handler.clone());
+                Box::pin(
+                    async move { Ok(Grpc::new(super::BytesCodec).unary(handler, request).await) },
+                )
+            }),
+        );
+        self
+    }
+
+    /// Streams every event returned by `handler`, then ends the stream.
+    pub fn server_streaming<Req, Resp>(
+        mut self,
+        method: &str,
+        handler: impl Fn(Req) -> Result<Vec<Resp>, Status> + Send + Sync + 'static,
+    ) -> Self
+    where
+        Req: protobuf::Message,
+        Resp: protobuf::Message,
+    {
+        let handler = Arc::new(handler);
+        self.routes.insert(
+            format!("/HubService/{}", method),
+            Arc::new(move |request| {
+                let handler = Handler(handler.clone());
+                Box::pin(async move {
+                    let mut grpc = Grpc::new(ProtobufCodec::<Resp, Req>::default());
+                    Ok(grpc.server_streaming(handler, request).await)
+                })
+            }),
+        );
+        self
+    }
+
+    /// Serves on a random local port and returns a channel connected to it.
+    pub async fn serve(self) -> Channel {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(
+            Server::builder()
+                .add_service(self)
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+        Channel::from_shared(format!("http://{}", addr))
+            .unwrap()
+            .connect()
+            .await
+            .unwrap()
+    }
+}
+
+impl Service<http::Request<Body>> for MockHubService {
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Infallible>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+        match self.routes.get(request.uri().path()) {
+            Some(route) => route(request),
+            None => {
+                let status = Status::unimplemented(request.uri().path().to_string());
+                Box::pin(async move { Ok(status.to_http()) })
+            }
+        }
+    }
+}
+
+impl NamedService for MockHubService {
+    const NAME: &'static str = "HubService";
+}
+
+struct Handler<F>(Arc<F>);
+
+impl<F, Req, Resp> UnaryService<Req> for Handler<F>
+where
+    F: Fn(Req) -> Result<Resp, Status>,
+{
+    type Response = Resp;
+    type Future = std::future::Ready<Result<tonic::Response<Resp>, Status>>;
+
+    fn call(&mut self, request: tonic::Request<Req>) -> Self::Future {
+        std::future::ready((self.0)(request.into_inner()).map(tonic::Response::new))
+    }
+}
+
+impl<F, Req, Resp> ServerStreamingService<Req> for Handler<F>
+where
+    F: Fn(Req) -> Result<Vec<Resp>, Status>,
+{
+    type Response = Resp;
+    type ResponseStream = tokio_stream::Iter<std::vec::IntoIter<Result<Resp, Status>>>;
+    type Future = std::future::Ready<Result<tonic::Response<Self::ResponseStream>, Status>>;
+
+    fn call(&mut self, request: tonic::Request<Req>) -> Self::Future {
+        std::future::ready((self.0)(request.into_inner()).map(|events| {
+            let events: Vec<_> = events.into_iter().map(Ok).collect();
+            tonic::Response::new(tokio_stream::iter(events))
+        }))
+    }
+}
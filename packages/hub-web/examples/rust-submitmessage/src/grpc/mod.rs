@@ -0,0 +1,156 @@
+//! Client for the Hub gRPC API (port 2283 by default).
+//!
+//! The `HubService` client in [`crate::rpc`] is generated from rpc.proto by build.rs and exchanges
+//! the rust-protobuf message types through [`ProtobufCodec`].
+
+use tonic::transport::Channel;
+use tonic::Status;
+
+use crate::errors::{HubError, HubErrorCode};
+
+pub mod codec;
+#[cfg(test)]
+pub(crate) mod mock;
+
+pub use crate::rpc::hub_service_client::HubServiceClient;
+pub use codec::{BytesCodec, ProtobufCodec};
+
+pub const DEFAULT_GRPC_URL: &str = "http://127.0.0.1:2283";
+
+/// Connects to the hub's gRPC API at `url`, e.g. `http://127.0.0.1:2283`.
+pub async fn connect(
+    url: impl Into<String>,
+) -> Result<HubServiceClient<Channel>, tonic::transport::Error> {
+    HubServiceClient::connect(url.into()).await
+}
+
+/// Classifies a failed call by the `errcode` (or `x-err-code`) metadata hubs attach to errors.
+pub fn hub_error(status: &Status) -> HubError {
+    let metadata = status.metadata();
+    let code = ["errcode", "x-err-code"]
+        .iter()
+        .find_map(|key| metadata.get(*key)?.to_str().ok())
+        .and_then(|code| code.parse().ok())
+        .unwrap_or_else(|| match status.code() {
+            tonic::Code::NotFound => HubErrorCode::NotFound,
+            tonic::Code::InvalidArgument => HubErrorCode::BadRequest,
+            tonic::Code::Unauthenticated => HubErrorCode::Unauthenticated,
+            tonic::Code::PermissionDenied => HubErrorCode::Unauthorized,
+            tonic::Code::Unimplemented => HubErrorCode::NotImplemented,
+            tonic::Code::Unavailable | tonic::Code::ResourceExhausted => HubErrorCode::Unavailable,
+            _ => HubErrorCode::Unknown,
+        });
+    HubError::new(code, status.message())
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)] // handlers return tonic::Status like generated servers
+mod tests {
+    use super::mock::MockHubService;
+    use super::*;
+    use crate::bodies::{cast_id, CastAddBuilder};
+    use crate::hub_event::{HubEvent, HubEventType};
+    use crate::message::{CastId, FarcasterNetwork, Message};
+    use crate::request_response::{FidRequest, GetInfoRequest, MessagesResponse, SubscribeRequest};
+    use crate::test_utils::test_signer;
+    use crate::MessageBuilder;
+    use tonic::metadata::MetadataValue;
+
+    async fn cast(text: &str) -> Message {
+        let signer = test_signer();
+        MessageBuilder::new(
+            2,
+            FarcasterNetwork::FARCASTER_NETWORK_MAINNET,
+            CastAddBuilder::new(text),
+        )
+        .timestamp(100)
+        .build(&signer)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn submits_and_reads_messages() {
+        let message = cast("hello").await;
+        let stored = message.clone();
+        let mock = MockHubService::new()
+            .unary("SubmitMessage", |message: Message| Ok(message))
+            .unary("GetCast", move |id: CastId| {
+                assert_eq!(id.get_fid(), 2);
+                Ok(stored.clone())
+            })
+            .unary("GetCastsByFid", {
+                let message = message.clone();
+                move |request: FidRequest| {
+                    assert_eq!(request.get_page_size(), 10);
+                    let mut response = MessagesResponse::new();
+                    response.mut_messages().push(message.clone());
+                    Ok(response)
+                }
+            });
+        let mut client = HubServiceClient::new(mock.serve().await);
+
+        let merged = client.submit_message(message.clone()).await.unwrap();
+        assert_eq!(merged.into_inner(), message);
+
+        let fetched = client
+            .get_cast(cast_id(2, message.get_hash().to_vec()))
+            .await
+            .unwrap();
+        assert_eq!(fetched.into_inner(), message);
+
+        let mut request = FidRequest::new();
+        request.set_fid(2);
+        request.set_page_size(10);
+        let casts = client.get_casts_by_fid(request).await.unwrap().into_inner();
+        assert_eq!(casts.get_messages(), [message]);
+    }
+
+    #[tokio::test]
+    async fn streams_subscribed_events() {
+        let mock =
+            MockHubService::new().server_streaming("Subscribe", |request: SubscribeRequest| {
+                let from = request.get_from_id();
+                Ok((from..from + 3)
+                    .map(|id| {
+                        let mut event = HubEvent::new();
+                        event.set_id(id);
+                        event.set_field_type(HubEventType::HUB_EVENT_TYPE_MERGE_MESSAGE);
+                        event
+                    })
+                    .collect())
+            });
+        let mut client = HubServiceClient::new(mock.serve().await);
+
+        let mut request = SubscribeRequest::new();
+        request.set_from_id(5);
+        let mut stream = client.subscribe(request).await.unwrap().into_inner();
+        let mut ids = Vec::new();
+        while let Some(event) = stream.message().await.unwrap() {
+            ids.push(event.get_id());
+        }
+        assert_eq!(ids, [5, 6, 7]);
+    }
+
+    #[tokio::test]
+    async fn maps_status_metadata_to_hub_errors() {
+        let mock =
+            MockHubService::new().unary("SubmitMessage", |_: Message| -> Result<Message, _> {
+                let mut status = Status::invalid_argument("message has already been merged");
+                status.metadata_mut().insert(
+                    "errcode",
+                    MetadataValue::from_static("bad_request.duplicate"),
+                );
+                Err(status)
+            });
+        let mut client = HubServiceClient::new(mock.serve().await);
+
+        let status = client.submit_message(cast("dup").await).await.unwrap_err();
+        let error = hub_error(&status);
+        assert!(error.is_duplicate());
+        assert_eq!(error.message, "message has already been merged");
+
+        let status = client.get_info(GetInfoRequest::new()).await.unwrap_err();
+        assert_eq!(hub_error(&status).code, HubErrorCode::NotImplemented);
+    }
+}
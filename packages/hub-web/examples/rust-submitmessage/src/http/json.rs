@@ -0,0 +1,490 @@
+//! Conversion between the generated protobuf types and the JSON returned by the Hub HTTP API.
+//!
+//! The hub serializes messages as proto3 JSON, except that hashes, keys and addresses are `0x`
+//! prefixed hex strings (Solana addresses and block hashes are base58) and username proof names are
+//! plain strings. rust-protobuf 2 has no JSON
+//! support, so decoding walks the schema descriptors and re-encodes the JSON into the protobuf wire
+//! format before parsing it.
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use protobuf::descriptor::{
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, FieldDescriptorProto_Label,
+    FieldDescriptorProto_Type, FileDescriptorProto,
+};
+use protobuf::reflect::{ReflectFieldRef, ReflectValueRef};
+use serde_json::{Map, Value};
+
+use crate::{
+    admin_rpc, blocks, gossip, hub_event, hub_state, message, node_state, onchain_event,
+    replication, request_response, sync_trie, username_proof,
+};
+
+/// Bytes fields the hub renders as `0x` prefixed hex rather than base64.
+const HEX_FIELDS: &[&str] = &[
+    "hash",
+    "signer",
+    "transaction_hash",
+    "key",
+    "owner",
+    "to",
+    "from",
+    "recovery_address",
+    "address",
+    "block_hash",
+    "target_hash",
+];
+
+/// Bytes fields that may be base58 encoded when they hold a Solana value.
+const BASE58_FIELDS: &[&str] = &["address", "block_hash"];
+
+/// Bytes fields the hub renders as UTF-8 strings.
+const UTF8_FIELDS: &[&str] = &["name"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum JsonError {
+    #[error("unknown protobuf type {0}")]
+    UnknownType(String),
+    #[error("invalid value for field {field}: {value}")]
+    InvalidValue { field: String, value: Value },
+    #[error("unsupported field type for {0}")]
+    UnsupportedField(String),
+    #[error("failed to parse protobuf: {0}")]
+    Protobuf(#[from] protobuf::ProtobufError),
+}
+
+fn files() -> [&'static FileDescriptorProto; 12] {
+    [
+        message::file_descriptor_proto(),
+        username_proof::file_descriptor_proto(),
+        onchain_event::file_descriptor_proto(),
+        hub_event::file_descriptor_proto(),
+        blocks::file_descriptor_proto(),
+        request_response::file_descriptor_proto(),
+        gossip::file_descriptor_proto(),
+        sync_trie::file_descriptor_proto(),
+        hub_state::file_descriptor_proto(),
+        node_state::file_descriptor_proto(),
+        replication::file_descriptor_proto(),
+        admin_rpc::file_descriptor_proto(),
+    ]
+}
+
+fn find_message(name: &str) -> Option<&'static DescriptorProto> {
+    let name = name.trim_start_matches('.');
+    files()
+        .into_iter()
+        .flat_map(|file| file.get_message_type())
+        .find(|descriptor| descriptor.get_name() == name)
+}
+
+fn find_enum(name: &str) -> Option<&'static EnumDescriptorProto> {
+    let name = name.trim_start_matches('.');
+    files()
+        .into_iter()
+        .flat_map(|file| file.get_enum_type())
+        .find(|descriptor| descriptor.get_name() == name)
+}
+
+/// Converts a `snake_case` proto field name to its `lowerCamelCase` JSON name.
+fn json_name(name: &str) -> String {
+    let mut out = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Decodes hub JSON into a generated protobuf type. Unknown fields are ignored.
+pub fn from_json<M: protobuf::Message>(value: &Value) -> Result<M, JsonError> {
+    let mut buf = Vec::new();
+    encode_message(M::descriptor_static().name(), value, &mut buf)?;
+    Ok(M::parse_from_bytes(&buf)?)
+}
+
+fn encode_message(type_name: &str, value: &Value, buf: &mut Vec<u8>) -> Result<(), JsonError> {
+    let descriptor =
+        find_message(type_name).ok_or_else(|| JsonError::UnknownType(type_name.into()))?;
+    let object = value.as_object().ok_or_else(|| JsonError::InvalidValue {
+        field: type_name.into(),
+        value: value.clone(),
+    })?;
+
+    for (key, value) in object {
+        let field = descriptor
+            .get_field()
+            .iter()
+            .find(|field| field.get_name() == key || json_name(field.get_name()) == *key);
+        let field = match field {
+            Some(field) if !value.is_null() => field,
+            _ => continue,
+        };
+
+        if field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED {
+            let values = value.as_array().ok_or_else(|| invalid(field, value))?;
+            for value in values {
+                encode_field(field, value, buf)?;
+            }
+        } else {
+            encode_field(field, value, buf)?;
+        }
+    }
+    Ok(())
+}
+
+fn invalid(field: &FieldDescriptorProto, value: &Value) -> JsonError {
+    JsonError::InvalidValue {
+        field: field.get_name().into(),
+        value: value.clone(),
+    }
+}
+
+fn write_varint(mut value: u64, buf: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn write_tag(number: i32, wire_type: u32, buf: &mut Vec<u8>) {
+    write_varint(((number as u64) << 3) | wire_type as u64, buf);
+}
+
+fn write_length_delimited(number: i32, bytes: &[u8], buf: &mut Vec<u8>) {
+    write_tag(number, 2, buf);
+    write_varint(bytes.len() as u64, buf);
+    buf.extend_from_slice(bytes);
+}
+
+fn as_i64(field: &FieldDescriptorProto, value: &Value) -> Result<i64, JsonError> {
+    match value {
+        Value::Number(n) => n
+            .as_i64()
+            .or_else(|| n.as_u64().map(|n| n as i64))
+            .ok_or_else(|| invalid(field, value)),
+        Value::String(s) => s
+            .parse::<i64>()
+            .or_else(|_| s.parse::<u64>().map(|n| n as i64))
+            .map_err(|_| invalid(field, value)),
+        Value::Bool(b) => Ok(*b as i64),
+        _ => Err(invalid(field, value)),
+    }
+}
+
+fn as_f64(field: &FieldDescriptorProto, value: &Value) -> Result<f64, JsonError> {
+    match value {
+        Value::Number(n) => n.as_f64().ok_or_else(|| invalid(field, value)),
+        Value::String(s) => s.parse().map_err(|_| invalid(field, value)),
+        _ => Err(invalid(field, value)),
+    }
+}
+
+fn decode_bytes(field: &FieldDescriptorProto, value: &Value) -> Result<Vec<u8>, JsonError> {
+    let s = value.as_str().ok_or_else(|| invalid(field, value))?;
+    let name = field.get_name();
+    if UTF8_FIELDS.contains(&name) {
+        return Ok(s.as_bytes().to_vec());
+    }
+    if let Some(hex) = s.strip_prefix("0x").filter(|_| HEX_FIELDS.contains(&name)) {
+        return hex::decode(hex).map_err(|_| invalid(field, value));
+    }
+    if BASE58_FIELDS.contains(&name) {
+        return bs58::decode(s)
+            .into_vec()
+            .map_err(|_| invalid(field, value));
+    }
+    BASE64.decode(s).map_err(|_| invalid(field, value))
+}
+
+fn encode_field(
+    field: &FieldDescriptorProto,
+    value: &Value,
+    buf: &mut Vec<u8>,
+) -> Result<(), JsonError> {
+    use FieldDescriptorProto_Type::*;
+
+    let number = field.get_number();
+    match field.get_field_type() {
+        TYPE_MESSAGE => {
+            let mut nested = Vec::new();
+            encode_message(field.get_type_name(), value, &mut nested)?;
+            write_length_delimited(number, &nested, buf);
+        }
+        TYPE_STRING => {
+            let s = value.as_str().ok_or_else(|| invalid(field, value))?;
+            write_length_delimited(number, s.as_bytes(), buf);
+        }
+        TYPE_BYTES => {
+            let bytes = decode_bytes(field, value)?;
+            write_length_delimited(number, &bytes, buf);
+        }
+        TYPE_ENUM => {
+            let number_value = match value {
+                Value::String(name) => find_enum(field.get_type_name())
+                    .and_then(|descriptor| {
+                        descriptor.get_value().iter().find(|v| v.get_name() == name)
+                    })
+                    .map(|v| v.get_number() as i64)
+                    .ok_or_else(|| invalid(field, value))?,
+                _ => as_i64(field, value)?,
+            };
+            write_tag(number, 0, buf);
+            write_varint(number_value as u64, buf);
+        }
+        TYPE_BOOL | TYPE_INT32 | TYPE_INT64 | TYPE_UINT32 | TYPE_UINT64 => {
+            write_tag(number, 0, buf);
+            write_varint(as_i64(field, value)? as u64, buf);
+        }
+        TYPE_SINT32 | TYPE_SINT64 => {
+            let n = as_i64(field, value)?;
+            write_tag(number, 0, buf);
+            write_varint(((n << 1) ^ (n >> 63)) as u64, buf);
+        }
+        TYPE_FIXED32 | TYPE_SFIXED32 => {
+            write_tag(number, 5, buf);
+            buf.extend_from_slice(&(as_i64(field, value)? as u32).to_le_bytes());
+        }
+        TYPE_FIXED64 | TYPE_SFIXED64 => {
+            write_tag(number, 1, buf);
+            buf.extend_from_slice(&(as_i64(field, value)? as u64).to_le_bytes());
+        }
+        TYPE_FLOAT => {
+            write_tag(number, 5, buf);
+            buf.extend_from_slice(&(as_f64(field, value)? as f32).to_le_bytes());
+        }
+        TYPE_DOUBLE => {
+            write_tag(number, 1, buf);
+            buf.extend_from_slice(&as_f64(field, value)?.to_le_bytes());
+        }
+        TYPE_GROUP => return Err(JsonError::UnsupportedField(field.get_name().into())),
+    }
+    Ok(())
+}
+
+/// Encodes a generated protobuf type the way the hub does. Fields with default values are omitted.
+pub fn to_json(message: &dyn protobuf::Message) -> Value {
+    let fields = message.descriptor().fields();
+    let solana = fields.iter().any(|field| {
+        field.name() == "protocol"
+            && matches!(
+                field.get_reflect(message),
+                ReflectFieldRef::Optional(Some(ReflectValueRef::Enum(protocol)))
+                    if protocol.name() == "PROTOCOL_SOLANA"
+            )
+    });
+    let mut object = Map::new();
+    for field in fields {
+        let name = field.name();
+        let value = match field.get_reflect(message) {
+            ReflectFieldRef::Optional(Some(value)) => value_to_json(name, value, solana),
+            ReflectFieldRef::Optional(None) => continue,
+            ReflectFieldRef::Repeated(values) => Value::Array(
+                values
+                    .reflect_iter()
+                    .map(|value| value_to_json(name, value.as_ref(), solana))
+                    .collect(),
+            ),
+            ReflectFieldRef::Map(_) => continue,
+        };
+        object.insert(json_name(name), value);
+    }
+    Value::Object(object)
+}
+
+/// `solana` is set for fields of a message whose `protocol` is `PROTOCOL_SOLANA`.
+fn value_to_json(field: &str, value: ReflectValueRef, solana: bool) -> Value {
+    match value {
+        ReflectValueRef::U32(n) => n.into(),
+        ReflectValueRef::U64(n) => n.into(),
+        ReflectValueRef::I32(n) => n.into(),
+        ReflectValueRef::I64(n) => n.into(),
+        ReflectValueRef::F32(n) => n.into(),
+        ReflectValueRef::F64(n) => n.into(),
+        ReflectValueRef::Bool(b) => b.into(),
+        ReflectValueRef::String(s) => s.into(),
+        ReflectValueRef::Bytes(bytes) if UTF8_FIELDS.contains(&field) => {
+            String::from_utf8_lossy(bytes).into()
+        }
+        ReflectValueRef::Bytes(bytes) if solana && BASE58_FIELDS.contains(&field) => {
+            bs58::encode(bytes).into_string().into()
+        }
+        ReflectValueRef::Bytes(bytes) if HEX_FIELDS.contains(&field) => {
+            format!("0x{}", hex::encode(bytes)).into()
+        }
+        ReflectValueRef::Bytes(bytes) => BASE64.encode(bytes).into(),
+        ReflectValueRef::Enum(value) => value.name().into(),
+        ReflectValueRef::Message(message) => to_json(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hub_event::{HubEvent, HubEventType};
+    use crate::message::{
+        Message, MessageType, Protocol, ReactionType, VerificationAddAddressBody,
+    };
+    use crate::onchain_event::{OnChainEventType, SignerEventType};
+    use crate::username_proof::{UserNameProof, UserNameType};
+    use serde_json::json;
+
+    #[test]
+    fn decodes_hub_message_json() {
+        let value = json!({
+            "data": {
+                "type": "MESSAGE_TYPE_REACTION_ADD",
+                "fid": 2,
+                "timestamp": 48994466,
+                "network": "FARCASTER_NETWORK_MAINNET",
+                "reactionBody": {
+                    "type": "REACTION_TYPE_LIKE",
+                    "targetCastId": {
+                        "fid": "3",
+                        "hash": "0xa48dd46161d8e57725f5e26e34ec19c13ff7f3b9"
+                    }
+                }
+            },
+            "hash": "0x2e9c7c4e1e6c3f5e8a1b7b0e1c0f3a4d5e6f7a8b",
+            "hashScheme": "HASH_SCHEME_BLAKE3",
+            "signature": "AQID",
+            "signatureScheme": "SIGNATURE_SCHEME_ED25519",
+            "signer": "0x0852c07f7d8d2ae7d0bb2c2f1e8a2c5b3d8b2a1b3c4d5e6f7a8b9c0d1e2f3a4b",
+            "unknownField": true
+        });
+
+        let message: Message = from_json(&value).unwrap();
+        let data = message.get_data();
+        assert_eq!(
+            data.get_field_type(),
+            MessageType::MESSAGE_TYPE_REACTION_ADD
+        );
+        assert_eq!(data.get_fid(), 2);
+        let reaction = data.get_reaction_body();
+        assert_eq!(reaction.get_field_type(), ReactionType::REACTION_TYPE_LIKE);
+        assert_eq!(reaction.get_target_cast_id().get_fid(), 3);
+        assert_eq!(
+            hex::encode(reaction.get_target_cast_id().get_hash()),
+            "a48dd46161d8e57725f5e26e34ec19c13ff7f3b9"
+        );
+        assert_eq!(message.get_signature(), &[1, 2, 3]);
+        assert_eq!(message.get_signer().len(), 32);
+    }
+
+    #[test]
+    fn decodes_hub_event_json() {
+        let value = json!({
+            "type": "HUB_EVENT_TYPE_MERGE_ON_CHAIN_EVENT",
+            "id": 358231459614720u64,
+            "mergeOnChainEventBody": {
+                "onChainEvent": {
+                    "type": "EVENT_TYPE_SIGNER",
+                    "chainId": 10,
+                    "blockNumber": 108875854,
+                    "blockHash": "0xe5d7d5e1ad4b4e4d5c1f8a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d",
+                    "blockTimestamp": 1693350485,
+                    "transactionHash": "0xd8d3b1f5a2c4e6f8091a2b3c4d5e6f7a8b9c0d1e2f3a4b5c6d7e8f9a0b1c2d3e",
+                    "logIndex": 4,
+                    "fid": 2,
+                    "signerEventBody": {
+                        "key": format!("0x{}", "11".repeat(32)),
+                        "keyType": 1,
+                        "eventType": "SIGNER_EVENT_TYPE_ADD",
+                        "metadata": "AQID",
+                        "metadataType": 1
+                    },
+                    "txIndex": 0
+                }
+            },
+            "blockNumber": 108875854
+        });
+
+        let event: HubEvent = from_json(&value).unwrap();
+        assert_eq!(
+            event.get_field_type(),
+            HubEventType::HUB_EVENT_TYPE_MERGE_ON_CHAIN_EVENT
+        );
+        let on_chain_event = event.get_merge_on_chain_event_body().get_on_chain_event();
+        assert_eq!(
+            on_chain_event.get_field_type(),
+            OnChainEventType::EVENT_TYPE_SIGNER
+        );
+        assert_eq!(on_chain_event.get_fid(), 2);
+        let signer = on_chain_event.get_signer_event_body();
+        assert_eq!(signer.get_key(), [0x11; 32]);
+        assert_eq!(
+            signer.get_event_type(),
+            SignerEventType::SIGNER_EVENT_TYPE_ADD
+        );
+        assert_eq!(from_json::<HubEvent>(&to_json(&event)).unwrap(), event);
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut proof = UserNameProof::new();
+        proof.set_timestamp(1_700_000_000);
+        proof.set_name(b"alice.eth".to_vec());
+        proof.set_owner(vec![0xab; 20]);
+        proof.set_signature(vec![1; 65]);
+        proof.set_fid(2);
+        proof.set_field_type(UserNameType::USERNAME_TYPE_ENS_L1);
+
+        let value = to_json(&proof);
+        assert_eq!(value["name"], "alice.eth");
+        assert_eq!(value["owner"], format!("0x{}", "ab".repeat(20)));
+        assert_eq!(value["type"], "USERNAME_TYPE_ENS_L1");
+        assert_eq!(from_json::<UserNameProof>(&value).unwrap(), proof);
+    }
+
+    #[test]
+    fn decodes_hub_username_proofs() {
+        let value = json!({
+            "timestamp": 1_700_000_000,
+            "name": "0xalice",
+            "owner": format!("0x{}", "ab".repeat(20)),
+            "signature": "0xAQEBAQ",
+            "fid": 2,
+            "type": "USERNAME_TYPE_FNAME"
+        });
+        let proof = from_json::<UserNameProof>(&value).unwrap();
+        assert_eq!(proof.get_name(), b"0xalice");
+        assert_eq!(proof.get_owner(), [0xab; 20]);
+        // Not a hex field, so base64 even though it starts with "0x".
+        assert_eq!(proof.get_signature(), BASE64.decode("0xAQEBAQ").unwrap());
+    }
+
+    #[test]
+    fn encodes_solana_verifications_as_base58() {
+        let mut body = VerificationAddAddressBody::new();
+        body.set_address(vec![7; 32]);
+        body.set_block_hash(vec![9; 32]);
+        body.set_protocol(Protocol::PROTOCOL_SOLANA);
+        let value = to_json(&body);
+        assert_eq!(value["address"], bs58::encode([7; 32]).into_string());
+        assert_eq!(value["blockHash"], bs58::encode([9; 32]).into_string());
+        assert_eq!(
+            from_json::<VerificationAddAddressBody>(&value).unwrap(),
+            body
+        );
+
+        body.set_protocol(Protocol::PROTOCOL_ETHEREUM);
+        assert_eq!(to_json(&body)["address"], format!("0x{}", "07".repeat(32)));
+    }
+
+    #[test]
+    fn rejects_unknown_enum_names() {
+        let value = json!({ "type": "USERNAME_TYPE_UNKNOWN" });
+        assert!(matches!(
+            from_json::<UserNameProof>(&value),
+            Err(JsonError::InvalidValue { .. })
+        ));
+    }
+}
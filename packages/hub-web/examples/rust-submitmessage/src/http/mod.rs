@@ -0,0 +1,531 @@
+//! Typed client for the Hub HTTP API (port 2281 by default).
+//!
+//! See <https://www.thehubble.xyz/docs/httpapi/httpapi.html> for the endpoints.
+
+use protobuf::{Message as _, ProtobufEnum};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::errors::HubError;
+use crate::hub_event::HubEvent;
+use crate::message::{CastId, Message, ReactionType, UserDataType};
+use crate::onchain_event::OnChainEvent;
+use crate::username_proof::UserNameProof;
+
+pub mod json;
+#[cfg(test)]
+pub(crate) mod mock;
+
+pub use json::{from_json, to_json, JsonError};
+
+pub const DEFAULT_HTTP_URL: &str = "http://127.0.0.1:2281";
+
+#[derive(Debug, thiserror::Error)]
+pub enum HttpError {
+    #[error("request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("hub returned {status}: {error}")]
+    Hub {
+        status: reqwest::StatusCode,
+        error: HubError,
+    },
+    #[error("hub returned {status}: {body}")]
+    Status {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+    #[error("invalid json response: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("failed to decode response: {0}")]
+    Decode(#[from] JsonError),
+    #[error("failed to encode message: {0}")]
+    Encode(#[from] protobuf::ProtobufError),
+}
+
+impl HttpError {
+    /// The structured hub error, if the hub returned one.
+    pub fn hub_error(&self) -> Option<&HubError> {
+        match self {
+            HttpError::Hub { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Whether the request was rejected for exceeding a rate limit.
+    pub fn is_rate_limited(&self) -> bool {
+        match self {
+            HttpError::Hub { status, error } => {
+                *status == reqwest::StatusCode::TOO_MANY_REQUESTS || error.is_rate_limited()
+            }
+            HttpError::Status { status, .. } => *status == reqwest::StatusCode::TOO_MANY_REQUESTS,
+            _ => false,
+        }
+    }
+}
+
+pub type HttpResult<T> = Result<T, HttpError>;
+
+/// Pagination options accepted by the list endpoints.
+#[derive(Debug, Clone, Default)]
+pub struct PageOptions {
+    pub page_size: Option<u32>,
+    /// Opaque token from a previous page's `next_page_token`.
+    pub page_token: Option<String>,
+    pub reverse: bool,
+}
+
+impl PageOptions {
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(page_size) = self.page_size {
+            query.push(("pageSize", page_size.to_string()));
+        }
+        if let Some(page_token) = &self.page_token {
+            query.push(("pageToken", page_token.clone()));
+        }
+        if self.reverse {
+            query.push(("reverse", "true".to_string()));
+        }
+        query
+    }
+}
+
+/// A page of messages and the token to fetch the next one, if any.
+#[derive(Debug, Clone, Default)]
+pub struct MessagesPage {
+    pub messages: Vec<Message>,
+    pub next_page_token: Option<String>,
+}
+
+/// The parent of a cast: another cast or a url such as a channel.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CastParent {
+    Cast(CastId),
+    Url(String),
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HubInfo {
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub is_syncing: bool,
+    #[serde(default)]
+    pub nickname: String,
+    #[serde(default)]
+    pub root_hash: String,
+    #[serde(default)]
+    pub peer_id: String,
+    #[serde(default)]
+    pub hub_operator_fid: u64,
+}
+
+/// A page of hub events and the id to resume from.
+#[derive(Debug, Clone, Default)]
+pub struct EventsPage {
+    pub events: Vec<HubEvent>,
+    pub next_page_event_id: u64,
+}
+
+fn hex_bytes(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+/// Decodes the array at `value[key]`, treating a missing array as empty.
+fn decode_list<M: protobuf::Message>(value: &Value, key: &str) -> HttpResult<Vec<M>> {
+    Ok(value[key]
+        .as_array()
+        .map(|items| items.iter().map(from_json).collect())
+        .transpose()?
+        .unwrap_or_default())
+}
+
+#[derive(Debug, Clone)]
+pub struct HubHttpClient {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl Default for HubHttpClient {
+    fn default() -> Self {
+        Self::new(DEFAULT_HTTP_URL)
+    }
+}
+
+impl HubHttpClient {
+    /// Creates a client for the hub at `base_url`, e.g. `http://127.0.0.1:2281`.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_client(reqwest::Client::new(), base_url)
+    }
+
+    pub fn with_client(client: reqwest::Client, base_url: impl Into<String>) -> Self {
+        Self {
+            client,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn url(&self, endpoint: &str) -> String {
+        format!("{}/v1/{}", self.base_url, endpoint)
+    }
+
+    async fn read_json(response: reqwest::Response) -> HttpResult<Value> {
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            let error = serde_json::from_str(&body)
+                .ok()
+                .and_then(|value| HubError::from_json(&value));
+            return Err(match error {
+                Some(error) => HttpError::Hub { status, error },
+                None => HttpError::Status { status, body },
+            });
+        }
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    async fn get(&self, endpoint: &str, query: &[(&str, String)]) -> HttpResult<Value> {
+        let response = self
+            .client
+            .get(self.url(endpoint))
+            .query(query)
+            .send()
+            .await?;
+        Self::read_json(response).await
+    }
+
+    async fn get_messages(
+        &self,
+        endpoint: &str,
+        mut query: Vec<(&str, String)>,
+        page: &PageOptions,
+    ) -> HttpResult<MessagesPage> {
+        query.extend(page.query());
+        let value = self.get(endpoint, &query).await?;
+        let messages = decode_list(&value, "messages")?;
+        let next_page_token = value["nextPageToken"]
+            .as_str()
+            .filter(|token| !token.is_empty())
+            .map(str::to_string);
+        Ok(MessagesPage {
+            messages,
+            next_page_token,
+        })
+    }
+
+    /// Submits a signed message and returns the message as merged by the hub.
+    pub async fn submit_message(&self, message: &Message) -> HttpResult<Message> {
+        let response = self
+            .client
+            .post(self.url("submitMessage"))
+            .header("Content-Type", "application/octet-stream")
+            .body(message.write_to_bytes()?)
+            .send()
+            .await?;
+        Ok(from_json(&Self::read_json(response).await?)?)
+    }
+
+    pub async fn info(&self) -> HttpResult<HubInfo> {
+        let value = self.get("info", &[]).await?;
+        Ok(serde_json::from_value(value)?)
+    }
+
+    pub async fn cast_by_id(&self, cast_id: &CastId) -> HttpResult<Message> {
+        let query = [
+            ("fid", cast_id.get_fid().to_string()),
+            ("hash", hex_bytes(cast_id.get_hash())),
+        ];
+        Ok(from_json(&self.get("castById", &query).await?)?)
+    }
+
+    pub async fn casts_by_fid(&self, fid: u64, page: &PageOptions) -> HttpResult<MessagesPage> {
+        self.get_messages("castsByFid", vec![("fid", fid.to_string())], page)
+            .await
+    }
+
+    pub async fn casts_by_parent(
+        &self,
+        parent: &CastParent,
+        page: &PageOptions,
+    ) -> HttpResult<MessagesPage> {
+        let query = match parent {
+            CastParent::Cast(cast_id) => vec![
+                ("fid", cast_id.get_fid().to_string()),
+                ("hash", hex_bytes(cast_id.get_hash())),
+            ],
+            CastParent::Url(url) => vec![("url", url.clone())],
+        };
+        self.get_messages("castsByParent", query, page).await
+    }
+
+    pub async fn casts_by_mention(&self, fid: u64, page: &PageOptions) -> HttpResult<MessagesPage> {
+        self.get_messages("castsByMention", vec![("fid", fid.to_string())], page)
+            .await
+    }
+
+    pub async fn reactions_by_fid(
+        &self,
+        fid: u64,
+        reaction_type: Option<ReactionType>,
+        page: &PageOptions,
+    ) -> HttpResult<MessagesPage> {
+        let mut query = vec![("fid", fid.to_string())];
+        if let Some(reaction_type) = reaction_type {
+            query.push(("reaction_type", reaction_type.value().to_string()));
+        }
+        self.get_messages("reactionsByFid", query, page).await
+    }
+
+    pub async fn reactions_by_cast(
+        &self,
+        cast_id: &CastId,
+        reaction_type: Option<ReactionType>,
+        page: &PageOptions,
+    ) -> HttpResult<MessagesPage> {
+        let mut query = vec![
+            ("target_fid", cast_id.get_fid().to_string()),
+            ("target_hash", hex_bytes(cast_id.get_hash())),
+        ];
+        if let Some(reaction_type) = reaction_type {
+            query.push(("reaction_type", reaction_type.value().to_string()));
+        }
+        self.get_messages("reactionsByCast", query, page).await
+    }
+
+    pub async fn links_by_fid(
+        &self,
+        fid: u64,
+        link_type: Option<&str>,
+        page: &PageOptions,
+    ) -> HttpResult<MessagesPage> {
+        let mut query = vec![("fid", fid.to_string())];
+        if let Some(link_type) = link_type {
+            query.push(("link_type", link_type.to_string()));
+        }
+        self.get_messages("linksByFid", query, page).await
+    }
+
+    pub async fn links_by_target_fid(
+        &self,
+        target_fid: u64,
+        link_type: Option<&str>,
+        page: &PageOptions,
+    ) -> HttpResult<MessagesPage> {
+        let mut query = vec![("target_fid", target_fid.to_string())];
+        if let Some(link_type) = link_type {
+            query.push(("link_type", link_type.to_string()));
+        }
+        self.get_messages("linksByTargetFid", query, page).await
+    }
+
+    pub async fn user_data_by_fid(&self, fid: u64, page: &PageOptions) -> HttpResult<MessagesPage> {
+        self.get_messages("userDataByFid", vec![("fid", fid.to_string())], page)
+            .await
+    }
+
+    /// Fetches a single `UserDataType` for `fid`.
+    pub async fn user_data(&self, fid: u64, user_data_type: UserDataType) -> HttpResult<Message> {
+        let query = [
+            ("fid", fid.to_string()),
+            ("user_data_type", user_data_type.value().to_string()),
+        ];
+        Ok(from_json(&self.get("userDataByFid", &query).await?)?)
+    }
+
+    pub async fn verifications_by_fid(
+        &self,
+        fid: u64,
+        page: &PageOptions,
+    ) -> HttpResult<MessagesPage> {
+        self.get_messages("verificationsByFid", vec![("fid", fid.to_string())], page)
+            .await
+    }
+
+    pub async fn username_proof_by_name(&self, name: &str) -> HttpResult<UserNameProof> {
+        let value = self
+            .get("userNameProofByName", &[("name", name.to_string())])
+            .await?;
+        Ok(from_json(&value)?)
+    }
+
+    /// Returns the raw signer `OnChainEvent`s for `fid`.
+    pub async fn on_chain_signers_by_fid(&self, fid: u64) -> HttpResult<Vec<OnChainEvent>> {
+        let value = self
+            .get("onChainSignersByFid", &[("fid", fid.to_string())])
+            .await?;
+        decode_list(&value, "events")
+    }
+
+    /// Returns the hub events starting at `from_event_id`.
+    pub async fn events(&self, from_event_id: u64) -> HttpResult<EventsPage> {
+        let value = self
+            .get("events", &[("from_event_id", from_event_id.to_string())])
+            .await?;
+        Ok(EventsPage {
+            events: decode_list(&value, "events")?,
+            next_page_event_id: value["nextPageEventId"].as_u64().unwrap_or(from_event_id),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::MockHub;
+    use super::*;
+    use crate::bodies::{cast_id, CastAddBuilder};
+    use crate::errors::HubErrorCode;
+    use crate::message::FarcasterNetwork;
+    use crate::test_utils::test_signer;
+    use crate::MessageBuilder;
+    use serde_json::json;
+
+    async fn cast(text: &str) -> Message {
+        let signer = test_signer();
+        let mut message = MessageBuilder::new(
+            2,
+            FarcasterNetwork::FARCASTER_NETWORK_MAINNET,
+            CastAddBuilder::new(text),
+        )
+        .timestamp(100)
+        .build(&signer)
+        .await
+        .unwrap();
+        // Hubs return the decoded data rather than data_bytes
+        let data = protobuf::Message::parse_from_bytes(&message.take_data_bytes()).unwrap();
+        message.set_data(data);
+        message
+    }
+
+    #[tokio::test]
+    async fn pages_through_casts_by_fid() {
+        let first = to_json(&cast("first").await);
+        let second = to_json(&cast("second").await);
+        let hub = MockHub::start(move |request| {
+            assert_eq!(request.path, "/v1/castsByFid");
+            assert_eq!(request.query["fid"], "2");
+            let body = match request.query.get("pageToken").map(String::as_str) {
+                None => json!({ "messages": [first], "nextPageToken": "AQ==" }),
+                Some("AQ==") => json!({ "messages": [second], "nextPageToken": "" }),
+                Some(other) => panic!("unexpected page token {}", other),
+            };
+            (200, body.to_string())
+        })
+        .await;
+
+        let client = HubHttpClient::new(hub.url());
+        let mut page = PageOptions {
+            page_size: Some(1),
+            ..Default::default()
+        };
+        let first_page = client.casts_by_fid(2, &page).await.unwrap();
+        assert_eq!(
+            first_page.messages[0]
+                .get_data()
+                .get_cast_add_body()
+                .get_text(),
+            "first"
+        );
+        page.page_token = first_page.next_page_token;
+
+        let second_page = client.casts_by_fid(2, &page).await.unwrap();
+        assert_eq!(
+            second_page.messages[0]
+                .get_data()
+                .get_cast_add_body()
+                .get_text(),
+            "second"
+        );
+        assert!(second_page.next_page_token.is_none());
+        assert_eq!(hub.requests()[0].query["pageSize"], "1");
+    }
+
+    #[tokio::test]
+    async fn fetches_cast_by_id_with_hex_hash() {
+        let message = cast("hello").await;
+        let body = to_json(&message).to_string();
+        let hub = MockHub::start(move |request| {
+            assert_eq!(request.path, "/v1/castById");
+            assert_eq!(request.query["hash"], format!("0x{}", "ab".repeat(20)));
+            (200, body.clone())
+        })
+        .await;
+
+        let client = HubHttpClient::new(hub.url());
+        let fetched = client
+            .cast_by_id(&cast_id(2, vec![0xab; 20]))
+            .await
+            .unwrap();
+        assert_eq!(fetched, message);
+    }
+
+    #[tokio::test]
+    async fn submits_message_bytes() {
+        let message = cast("submitted").await;
+        let body = to_json(&message).to_string();
+        let hub = MockHub::start(move |_| (200, body.clone())).await;
+
+        let client = HubHttpClient::new(hub.url());
+        let merged = client.submit_message(&message).await.unwrap();
+        assert_eq!(merged, message);
+
+        let request = &hub.requests()[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/v1/submitMessage");
+        assert_eq!(request.body, message.write_to_bytes().unwrap());
+    }
+
+    #[tokio::test]
+    async fn reads_info_and_errors() {
+        let hub = MockHub::start(|request| match request.path.as_str() {
+            "/v1/info" => (
+                200,
+                json!({ "version": "1.2.3", "isSyncing": false, "nickname": "mock" }).to_string(),
+            ),
+            _ => (404, json!({ "errCode": "not_found" }).to_string()),
+        })
+        .await;
+
+        let client = HubHttpClient::new(hub.url());
+        let info = client.info().await.unwrap();
+        assert_eq!(info.version, "1.2.3");
+        assert_eq!(info.nickname, "mock");
+
+        let err = client
+            .username_proof_by_name("missing.eth")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, HttpError::Hub { status, .. } if status == 404));
+        assert_eq!(err.hub_error().unwrap().code, HubErrorCode::NotFound);
+    }
+
+    #[tokio::test]
+    async fn decodes_submit_message_errors() {
+        let responses = std::sync::Mutex::new(vec![
+            (500, "internal error".to_string()),
+            (
+                429,
+                json!({ "errCode": "unavailable", "details": "Too many requests" }).to_string(),
+            ),
+            (
+                400,
+                json!({ "errCode": "bad_request.duplicate", "details": "message has already been merged" })
+                    .to_string(),
+            ),
+        ]);
+        let hub = MockHub::start(move |_| responses.lock().unwrap().pop().unwrap()).await;
+        let client = HubHttpClient::new(hub.url());
+        let message = cast("again").await;
+
+        let err = client.submit_message(&message).await.unwrap_err();
+        assert!(err.hub_error().unwrap().is_duplicate());
+        assert!(!err.is_rate_limited());
+
+        let err = client.submit_message(&message).await.unwrap_err();
+        assert!(err.is_rate_limited());
+
+        let err = client.submit_message(&message).await.unwrap_err();
+        assert!(matches!(err, HttpError::Status { .. }));
+        assert!(err.hub_error().is_none());
+    }
+}
@@ -0,0 +1,64 @@
+//! Build, hash and sign Farcaster messages that can be submitted to a Hub.
+
+#[allow(warnings)]
+pub mod admin_rpc; // Generated protobuf code
+#[allow(warnings)]
+pub mod blocks; // Generated protobuf code
+#[allow(warnings)]
+pub mod gossip; // Generated protobuf code
+#[allow(warnings)]
+pub mod hub_event; // Generated protobuf code
+#[allow(warnings)]
+pub mod hub_state; // Generated protobuf code
+#[allow(warnings)]
+pub mod message; // Generated protobuf code
+#[allow(warnings)]
+pub mod node_state; // Generated protobuf code
+#[allow(warnings)]
+pub mod onchain_event; // Generated protobuf code
+#[allow(warnings)]
+pub mod replication; // Generated protobuf code
+#[allow(warnings)]
+pub mod request_response; // Generated protobuf code
+#[allow(warnings)]
+pub mod rpc; // Generated tonic clients
+#[allow(warnings)]
+pub mod sync_trie; // Generated protobuf code
+#[allow(warnings)]
+pub mod username_proof; // Generated protobuf code
+
+pub mod bodies;
+pub mod builder;
+pub mod eip712;
+pub mod errors;
+pub mod eth;
+pub mod farcaster_time;
+pub mod frames;
+pub mod grpc;
+pub mod http;
+pub mod limits;
+pub mod mentions;
+pub mod name_proofs;
+pub mod replicator;
+pub mod signed_key_request;
+pub mod signer_registry;
+pub mod signers;
+pub mod store;
+pub mod subscriber;
+pub mod sync;
+#[cfg(test)]
+pub(crate) mod test_utils;
+pub mod validations;
+pub mod verifications;
+pub mod verify;
+
+pub use builder::{BuildError, MessageBuilder};
+pub use errors::{HubError, HubErrorCode};
+pub use farcaster_time::{to_farcaster_time, FARCASTER_EPOCH};
+pub use http::HubHttpClient;
+pub use replicator::Replicator;
+pub use signers::MessageSigner;
+pub use store::{MergeResult, Store};
+pub use subscriber::HubEventSubscriber;
+pub use validations::{validate_message, validate_message_data, ValidationError};
+pub use verify::{verify_message, VerifyError};
@@ -0,0 +1,112 @@
+//! Storage limits per unit, mirroring packages/core/src/limits.ts.
+
+use crate::message::StorageUnitType;
+use crate::request_response::{StorageUnitDetails, StoreType};
+
+pub const LEGACY_STORAGE_UNIT_CUTOFF_TIMESTAMP: u64 = 1724889600; // 2024-08-29 00:00:00 UTC
+pub const UNIT_TYPE_2024_CUTOFF_TIMESTAMP: u64 = 1752685200; // 2025-07-16 17:00:00 UTC
+const ONE_YEAR_IN_SECONDS: u64 = 365 * 24 * 60 * 60;
+
+/// The store types that hold messages and have a limit.
+pub const LIMITED_STORE_TYPES: [StoreType; 6] = [
+    StoreType::STORE_TYPE_CASTS,
+    StoreType::STORE_TYPE_LINKS,
+    StoreType::STORE_TYPE_REACTIONS,
+    StoreType::STORE_TYPE_USER_DATA,
+    StoreType::STORE_TYPE_USERNAME_PROOFS,
+    StoreType::STORE_TYPE_VERIFICATIONS,
+];
+
+/// How many messages one unit of `unit_type` allows in a store.
+pub fn default_store_limit(store_type: StoreType, unit_type: StorageUnitType) -> u64 {
+    use StorageUnitType::*;
+    use StoreType::*;
+    match (store_type, unit_type) {
+        (STORE_TYPE_CASTS, UNIT_TYPE_LEGACY) => 5000,
+        (STORE_TYPE_CASTS, UNIT_TYPE_2024) => 2000,
+        (STORE_TYPE_CASTS, UNIT_TYPE_2025) => 100,
+        (STORE_TYPE_LINKS | STORE_TYPE_REACTIONS, UNIT_TYPE_LEGACY) => 2500,
+        (STORE_TYPE_LINKS | STORE_TYPE_REACTIONS, UNIT_TYPE_2024) => 1000,
+        (STORE_TYPE_LINKS | STORE_TYPE_REACTIONS, UNIT_TYPE_2025) => 200,
+        (STORE_TYPE_USER_DATA, UNIT_TYPE_LEGACY | UNIT_TYPE_2024) => 50,
+        (STORE_TYPE_USER_DATA, UNIT_TYPE_2025) => 25,
+        (STORE_TYPE_USERNAME_PROOFS, UNIT_TYPE_LEGACY | UNIT_TYPE_2024) => 5,
+        (STORE_TYPE_USERNAME_PROOFS, UNIT_TYPE_2025) => 2,
+        (STORE_TYPE_VERIFICATIONS, UNIT_TYPE_LEGACY | UNIT_TYPE_2024) => 25,
+        (STORE_TYPE_VERIFICATIONS, UNIT_TYPE_2025) => 5,
+        (STORE_TYPE_STORAGE_LENDS, _) => 1,
+        (STORE_TYPE_NONE, _) => 0,
+    }
+}
+
+/// The limit of a store for an fid holding `units`.
+pub fn store_limit(store_type: StoreType, units: &[StorageUnitDetails]) -> u64 {
+    units
+        .iter()
+        .map(|unit| {
+            default_store_limit(store_type, unit.get_unit_type()) * unit.get_unit_size() as u64
+        })
+        .sum()
+}
+
+/// The limit of every message store for an fid holding `units`.
+pub fn store_limits(units: &[StorageUnitDetails]) -> Vec<(StoreType, u64)> {
+    LIMITED_STORE_TYPES
+        .iter()
+        .map(|store_type| (*store_type, store_limit(*store_type, units)))
+        .collect()
+}
+
+/// The type of units rented in a block with the given unix timestamp.
+pub fn storage_unit_type(block_timestamp: u64) -> StorageUnitType {
+    if block_timestamp < LEGACY_STORAGE_UNIT_CUTOFF_TIMESTAMP {
+        StorageUnitType::UNIT_TYPE_LEGACY
+    } else if block_timestamp < UNIT_TYPE_2024_CUTOFF_TIMESTAMP {
+        StorageUnitType::UNIT_TYPE_2024
+    } else {
+        StorageUnitType::UNIT_TYPE_2025
+    }
+}
+
+/// The unix timestamp at which units rented in a block with the given timestamp expire.
+pub fn storage_expiry_timestamp(block_timestamp: u64) -> u64 {
+    if block_timestamp < LEGACY_STORAGE_UNIT_CUTOFF_TIMESTAMP {
+        // Legacy storage units expire after 3 years
+        block_timestamp + ONE_YEAR_IN_SECONDS * 3
+    } else if block_timestamp < UNIT_TYPE_2024_CUTOFF_TIMESTAMP {
+        block_timestamp + ONE_YEAR_IN_SECONDS * 2
+    } else {
+        block_timestamp + ONE_YEAR_IN_SECONDS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit(unit_type: StorageUnitType, unit_size: u32) -> StorageUnitDetails {
+        let mut unit = StorageUnitDetails::new();
+        unit.set_unit_type(unit_type);
+        unit.set_unit_size(unit_size);
+        unit
+    }
+
+    #[test]
+    fn sums_limits_across_unit_types() {
+        let units = [
+            unit(StorageUnitType::UNIT_TYPE_LEGACY, 1),
+            unit(StorageUnitType::UNIT_TYPE_2025, 2),
+        ];
+        assert_eq!(store_limit(StoreType::STORE_TYPE_CASTS, &units), 5200);
+        assert_eq!(store_limit(StoreType::STORE_TYPE_USER_DATA, &units), 100);
+        assert_eq!(store_limits(&[])[0], (StoreType::STORE_TYPE_CASTS, 0));
+        assert_eq!(
+            storage_unit_type(LEGACY_STORAGE_UNIT_CUTOFF_TIMESTAMP),
+            StorageUnitType::UNIT_TYPE_2024
+        );
+        assert_eq!(
+            storage_expiry_timestamp(UNIT_TYPE_2024_CUTOFF_TIMESTAMP),
+            UNIT_TYPE_2024_CUTOFF_TIMESTAMP + ONE_YEAR_IN_SECONDS
+        );
+    }
+}
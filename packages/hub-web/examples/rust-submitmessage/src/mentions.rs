@@ -0,0 +1,284 @@
+//! Converts between cast text with `@username` handles and the `text`, `mentions` and
+//! `mentions_positions` fields of a `CastAddBody`.
+//!
+//! Mentions are stored out of band: the handle is removed from `text` and its fid is recorded with
+//! the UTF-8 byte offset where it was removed.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+
+use crate::bodies::CastAddBuilder;
+use crate::errors::HubErrorCode;
+use crate::http::{HttpError, HubHttpClient};
+use crate::message::{CastAddBody, UserDataType};
+use crate::validations::USERNAME_MAX_LENGTH;
+
+#[derive(Debug, thiserror::Error)]
+pub enum MentionError {
+    #[error("unknown username @{0}")]
+    UnknownUsername(String),
+    #[error("no username for fid {0}")]
+    UnknownFid(u64),
+    #[error("mentions and mentionsPositions must match")]
+    MentionsMismatch,
+    #[error("mention position {0} is not a character boundary in text")]
+    InvalidPosition(u32),
+    #[error("failed to resolve mention: {0}")]
+    Resolve(String),
+}
+
+/// Looks up the fid that owns a username.
+#[async_trait]
+pub trait UsernameResolver: Send + Sync {
+    async fn resolve_fid(&self, username: &str) -> Result<Option<u64>, MentionError>;
+}
+
+/// Looks up the username of an fid.
+#[async_trait]
+pub trait FidResolver: Send + Sync {
+    async fn resolve_username(&self, fid: u64) -> Result<Option<String>, MentionError>;
+}
+
+#[async_trait]
+impl UsernameResolver for HashMap<String, u64> {
+    async fn resolve_fid(&self, username: &str) -> Result<Option<u64>, MentionError> {
+        Ok(self.get(username).copied())
+    }
+}
+
+#[async_trait]
+impl FidResolver for HashMap<u64, String> {
+    async fn resolve_username(&self, fid: u64) -> Result<Option<String>, MentionError> {
+        Ok(self.get(&fid).cloned())
+    }
+}
+
+fn not_found<T>(err: HttpError) -> Result<Option<T>, MentionError> {
+    match err.hub_error() {
+        Some(error) if error.code == HubErrorCode::NotFound => Ok(None),
+        _ => Err(MentionError::Resolve(err.to_string())),
+    }
+}
+
+/// Resolves usernames through their username proofs.
+#[async_trait]
+impl UsernameResolver for HubHttpClient {
+    async fn resolve_fid(&self, username: &str) -> Result<Option<u64>, MentionError> {
+        match self.username_proof_by_name(username).await {
+            Ok(proof) => Ok(Some(proof.get_fid())),
+            Err(err) => not_found(err),
+        }
+    }
+}
+
+/// Resolves fids through their `USER_DATA_TYPE_USERNAME` user data.
+#[async_trait]
+impl FidResolver for HubHttpClient {
+    async fn resolve_username(&self, fid: u64) -> Result<Option<String>, MentionError> {
+        match self
+            .user_data(fid, UserDataType::USER_DATA_TYPE_USERNAME)
+            .await
+        {
+            Ok(message) => Ok(Some(
+                message
+                    .get_data()
+                    .get_user_data_body()
+                    .get_value()
+                    .to_string(),
+            )),
+            Err(err) => not_found(err),
+        }
+    }
+}
+
+/// A `@username` handle found in text, as byte offsets of the handle including the `@`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Handle<'a> {
+    pub start: usize,
+    pub end: usize,
+    pub username: &'a str,
+}
+
+fn is_username_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Finds every `@username` handle in `text`.
+///
+/// A handle must not directly follow a word character, so email addresses are left alone. Dots are
+/// only part of a handle when followed by another username character, so `@alice.eth` is one
+/// handle but the trailing dot in `hi @bob.` is not.
+pub fn find_handles(text: &str) -> Vec<Handle<'_>> {
+    let mut handles = Vec::new();
+    let mut prev: Option<char> = None;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let follows_word = prev.is_some_and(|p| p.is_alphanumeric() || p == '_');
+        prev = Some(c);
+        if c != '@' || follows_word {
+            continue;
+        }
+
+        let rest = &text[start + 1..];
+        let mut len = 0;
+        let mut rest_chars = rest.char_indices().peekable();
+        while let Some((i, c)) = rest_chars.next() {
+            let next_is_username = rest_chars.peek().is_some_and(|&(_, n)| is_username_char(n));
+            if is_username_char(c) || (c == '.' && len > 0 && next_is_username) {
+                len = i + 1;
+            } else {
+                break;
+            }
+        }
+        if len == 0 || len > USERNAME_MAX_LENGTH {
+            continue;
+        }
+
+        let end = start + 1 + len;
+        handles.push(Handle {
+            start,
+            end,
+            username: &text[start + 1..end],
+        });
+        while chars.peek().is_some_and(|&(i, _)| i < end) {
+            prev = chars.next().map(|(_, c)| c);
+        }
+    }
+    handles
+}
+
+/// Strips the `@username` handles from `text` and records them as mentions.
+///
+/// Fails with `UnknownUsername` if `resolver` doesn't know a handle.
+pub async fn compose_cast(
+    text: &str,
+    resolver: &(impl UsernameResolver + ?Sized),
+) -> Result<CastAddBuilder, MentionError> {
+    let mut stripped = String::with_capacity(text.len());
+    let mut mentions = Vec::new();
+    let mut last = 0;
+
+    for handle in find_handles(text) {
+        let fid = resolver
+            .resolve_fid(handle.username)
+            .await?
+            .ok_or_else(|| MentionError::UnknownUsername(handle.username.to_string()))?;
+        stripped.push_str(&text[last..handle.start]);
+        mentions.push((fid, stripped.len() as u32));
+        last = handle.end;
+    }
+    stripped.push_str(&text[last..]);
+
+    Ok(mentions
+        .into_iter()
+        .fold(CastAddBuilder::new(stripped), |builder, (fid, position)| {
+            builder.mention(fid, position)
+        }))
+}
+
+/// Renders `body.text` with an `@username` handle reinserted at every mention position.
+pub async fn render_cast_text(
+    body: &CastAddBody,
+    resolver: &(impl FidResolver + ?Sized),
+) -> Result<String, MentionError> {
+    let text = body.get_text();
+    let mentions = body.get_mentions();
+    let positions = body.get_mentions_positions();
+    if mentions.len() != positions.len() {
+        return Err(MentionError::MentionsMismatch);
+    }
+
+    let mut rendered = String::with_capacity(text.len());
+    let mut last = 0;
+    for (&fid, &position) in mentions.iter().zip(positions) {
+        let index = position as usize;
+        if index < last || !text.is_char_boundary(index) {
+            return Err(MentionError::InvalidPosition(position));
+        }
+        let username = resolver
+            .resolve_username(fid)
+            .await?
+            .ok_or(MentionError::UnknownFid(fid))?;
+        rendered.push_str(&text[last..index]);
+        rendered.push('@');
+        rendered.push_str(&username);
+        last = index;
+    }
+    rendered.push_str(&text[last..]);
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validations::validate_cast_add_body;
+
+    fn usernames() -> HashMap<String, u64> {
+        HashMap::from([
+            ("alice".to_string(), 1),
+            ("bob".to_string(), 2),
+            ("carol.eth".to_string(), 3),
+        ])
+    }
+
+    fn fids() -> HashMap<u64, String> {
+        usernames()
+            .into_iter()
+            .map(|(name, fid)| (fid, name))
+            .collect()
+    }
+
+    #[test]
+    fn finds_handles() {
+        let names: Vec<_> = find_handles("hi @alice, @carol.eth. mail bob@x.com @ @@bob")
+            .into_iter()
+            .map(|handle| handle.username)
+            .collect();
+        assert_eq!(names, ["alice", "carol.eth", "bob"]);
+    }
+
+    #[tokio::test]
+    async fn composes_and_renders_mentions() {
+        let text = "hi @alice and @bob 👋 @carol.eth";
+        let body = compose_cast(text, &usernames()).await.unwrap().into_inner();
+
+        assert_eq!(body.get_text(), "hi  and  👋 ");
+        assert_eq!(body.get_mentions(), [1, 2, 3]);
+        assert_eq!(body.get_mentions_positions(), [3, 8, 14]);
+        assert!(validate_cast_add_body(&body, false).is_ok());
+
+        assert_eq!(render_cast_text(&body, &fids()).await.unwrap(), text);
+    }
+
+    #[tokio::test]
+    async fn composes_adjacent_mentions() {
+        let body = compose_cast("@alice @bob", &usernames())
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(body.get_text(), " ");
+        assert_eq!(body.get_mentions_positions(), [0, 1]);
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_names_and_bad_positions() {
+        assert!(matches!(
+            compose_cast("hi @dave", &usernames()).await,
+            Err(MentionError::UnknownUsername(name)) if name == "dave"
+        ));
+
+        let body = CastAddBuilder::new("👋").mention(1, 1).into_inner();
+        assert!(matches!(
+            render_cast_text(&body, &fids()).await,
+            Err(MentionError::InvalidPosition(1))
+        ));
+
+        let body = CastAddBuilder::new("hi").mention(9, 0).into_inner();
+        assert!(matches!(
+            render_cast_text(&body, &fids()).await,
+            Err(MentionError::UnknownFid(9))
+        ));
+    }
+}
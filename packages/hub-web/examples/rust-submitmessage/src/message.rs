@@ -458,6 +458,10 @@ pub enum MessageData_oneof_body {
     link_body(LinkBody),
     username_proof_body(super::username_proof::UserNameProof),
     frame_action_body(FrameActionBody),
+    link_compact_state_body(LinkCompactStateBody),
+    lend_storage_body(LendStorageBody),
+    key_add_body(KeyAddBody),
+    key_remove_body(KeyRemoveBody),
 }
 
 impl MessageData {
@@ -965,6 +969,202 @@ impl MessageData {
             FrameActionBody::new()
         }
     }
+
+    // .LinkCompactStateBody link_compact_state_body = 17;
+
+
+    pub fn get_link_compact_state_body(&self) -> &LinkCompactStateBody {
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::link_compact_state_body(ref v)) => v,
+            _ => <LinkCompactStateBody as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_link_compact_state_body(&mut self) {
+        self.body = ::std::option::Option::None;
+    }
+
+    pub fn has_link_compact_state_body(&self) -> bool {
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::link_compact_state_body(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_link_compact_state_body(&mut self, v: LinkCompactStateBody) {
+        self.body = ::std::option::Option::Some(MessageData_oneof_body::link_compact_state_body(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_link_compact_state_body(&mut self) -> &mut LinkCompactStateBody {
+        if let ::std::option::Option::Some(MessageData_oneof_body::link_compact_state_body(_)) = self.body {
+        } else {
+            self.body = ::std::option::Option::Some(MessageData_oneof_body::link_compact_state_body(LinkCompactStateBody::new()));
+        }
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::link_compact_state_body(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_link_compact_state_body(&mut self) -> LinkCompactStateBody {
+        if self.has_link_compact_state_body() {
+            match self.body.take() {
+                ::std::option::Option::Some(MessageData_oneof_body::link_compact_state_body(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            LinkCompactStateBody::new()
+        }
+    }
+
+    // .LendStorageBody lend_storage_body = 18;
+
+
+    pub fn get_lend_storage_body(&self) -> &LendStorageBody {
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::lend_storage_body(ref v)) => v,
+            _ => <LendStorageBody as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_lend_storage_body(&mut self) {
+        self.body = ::std::option::Option::None;
+    }
+
+    pub fn has_lend_storage_body(&self) -> bool {
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::lend_storage_body(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lend_storage_body(&mut self, v: LendStorageBody) {
+        self.body = ::std::option::Option::Some(MessageData_oneof_body::lend_storage_body(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_lend_storage_body(&mut self) -> &mut LendStorageBody {
+        if let ::std::option::Option::Some(MessageData_oneof_body::lend_storage_body(_)) = self.body {
+        } else {
+            self.body = ::std::option::Option::Some(MessageData_oneof_body::lend_storage_body(LendStorageBody::new()));
+        }
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::lend_storage_body(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_lend_storage_body(&mut self) -> LendStorageBody {
+        if self.has_lend_storage_body() {
+            match self.body.take() {
+                ::std::option::Option::Some(MessageData_oneof_body::lend_storage_body(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            LendStorageBody::new()
+        }
+    }
+
+    // .KeyAddBody key_add_body = 19;
+
+
+    pub fn get_key_add_body(&self) -> &KeyAddBody {
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::key_add_body(ref v)) => v,
+            _ => <KeyAddBody as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_key_add_body(&mut self) {
+        self.body = ::std::option::Option::None;
+    }
+
+    pub fn has_key_add_body(&self) -> bool {
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::key_add_body(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_add_body(&mut self, v: KeyAddBody) {
+        self.body = ::std::option::Option::Some(MessageData_oneof_body::key_add_body(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_key_add_body(&mut self) -> &mut KeyAddBody {
+        if let ::std::option::Option::Some(MessageData_oneof_body::key_add_body(_)) = self.body {
+        } else {
+            self.body = ::std::option::Option::Some(MessageData_oneof_body::key_add_body(KeyAddBody::new()));
+        }
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::key_add_body(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_key_add_body(&mut self) -> KeyAddBody {
+        if self.has_key_add_body() {
+            match self.body.take() {
+                ::std::option::Option::Some(MessageData_oneof_body::key_add_body(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            KeyAddBody::new()
+        }
+    }
+
+    // .KeyRemoveBody key_remove_body = 20;
+
+
+    pub fn get_key_remove_body(&self) -> &KeyRemoveBody {
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::key_remove_body(ref v)) => v,
+            _ => <KeyRemoveBody as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_key_remove_body(&mut self) {
+        self.body = ::std::option::Option::None;
+    }
+
+    pub fn has_key_remove_body(&self) -> bool {
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::key_remove_body(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_remove_body(&mut self, v: KeyRemoveBody) {
+        self.body = ::std::option::Option::Some(MessageData_oneof_body::key_remove_body(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_key_remove_body(&mut self) -> &mut KeyRemoveBody {
+        if let ::std::option::Option::Some(MessageData_oneof_body::key_remove_body(_)) = self.body {
+        } else {
+            self.body = ::std::option::Option::Some(MessageData_oneof_body::key_remove_body(KeyRemoveBody::new()));
+        }
+        match self.body {
+            ::std::option::Option::Some(MessageData_oneof_body::key_remove_body(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_key_remove_body(&mut self) -> KeyRemoveBody {
+        if self.has_key_remove_body() {
+            match self.body.take() {
+                ::std::option::Option::Some(MessageData_oneof_body::key_remove_body(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            KeyRemoveBody::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for MessageData {
@@ -1014,6 +1214,26 @@ impl ::protobuf::Message for MessageData {
                 return false;
             }
         }
+        if let Some(MessageData_oneof_body::link_compact_state_body(ref v)) = self.body {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(MessageData_oneof_body::lend_storage_body(ref v)) = self.body {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(MessageData_oneof_body::key_add_body(ref v)) = self.body {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(MessageData_oneof_body::key_remove_body(ref v)) = self.body {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -1095,6 +1315,30 @@ impl ::protobuf::Message for MessageData {
                     }
                     self.body = ::std::option::Option::Some(MessageData_oneof_body::frame_action_body(is.read_message()?));
                 },
+                17 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.body = ::std::option::Option::Some(MessageData_oneof_body::link_compact_state_body(is.read_message()?));
+                },
+                18 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.body = ::std::option::Option::Some(MessageData_oneof_body::lend_storage_body(is.read_message()?));
+                },
+                19 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.body = ::std::option::Option::Some(MessageData_oneof_body::key_add_body(is.read_message()?));
+                },
+                20 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.body = ::std::option::Option::Some(MessageData_oneof_body::key_remove_body(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1157,6 +1401,22 @@ impl ::protobuf::Message for MessageData {
                     let len = v.compute_size();
                     my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &MessageData_oneof_body::link_compact_state_body(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &MessageData_oneof_body::lend_storage_body(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &MessageData_oneof_body::key_add_body(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &MessageData_oneof_body::key_remove_body(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -1224,6 +1484,26 @@ impl ::protobuf::Message for MessageData {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
+                &MessageData_oneof_body::link_compact_state_body(ref v) => {
+                    os.write_tag(17, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &MessageData_oneof_body::lend_storage_body(ref v) => {
+                    os.write_tag(18, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &MessageData_oneof_body::key_add_body(ref v) => {
+                    os.write_tag(19, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &MessageData_oneof_body::key_remove_body(ref v) => {
+                    os.write_tag(20, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
             };
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -1329,6 +1609,26 @@ impl ::protobuf::Message for MessageData {
                 MessageData::has_frame_action_body,
                 MessageData::get_frame_action_body,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, LinkCompactStateBody>(
+                "link_compact_state_body",
+                MessageData::has_link_compact_state_body,
+                MessageData::get_link_compact_state_body,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, LendStorageBody>(
+                "lend_storage_body",
+                MessageData::has_lend_storage_body,
+                MessageData::get_lend_storage_body,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, KeyAddBody>(
+                "key_add_body",
+                MessageData::has_key_add_body,
+                MessageData::get_key_add_body,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, KeyRemoveBody>(
+                "key_remove_body",
+                MessageData::has_key_remove_body,
+                MessageData::get_key_remove_body,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<MessageData>(
                 "MessageData",
                 fields,
@@ -1358,6 +1658,10 @@ impl ::protobuf::Clear for MessageData {
         self.body = ::std::option::Option::None;
         self.body = ::std::option::Option::None;
         self.body = ::std::option::Option::None;
+        self.body = ::std::option::Option::None;
+        self.body = ::std::option::Option::None;
+        self.body = ::std::option::Option::None;
+        self.body = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -1846,6 +2150,7 @@ pub struct CastAddBody {
     pub text: ::std::string::String,
     pub mentions_positions: ::std::vec::Vec<u32>,
     pub embeds: ::protobuf::RepeatedField<Embed>,
+    pub field_type: CastType,
     // message oneof groups
     pub parent: ::std::option::Option<CastAddBody_oneof_parent>,
     // special fields
@@ -2093,6 +2398,21 @@ impl CastAddBody {
     pub fn take_embeds(&mut self) -> ::protobuf::RepeatedField<Embed> {
         ::std::mem::replace(&mut self.embeds, ::protobuf::RepeatedField::new())
     }
+
+    // .CastType type = 8;
+
+
+    pub fn get_field_type(&self) -> CastType {
+        self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type = CastType::CAST;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: CastType) {
+        self.field_type = v;
+    }
 }
 
 impl ::protobuf::Message for CastAddBody {
@@ -2141,6 +2461,9 @@ impl ::protobuf::Message for CastAddBody {
                 6 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.embeds)?;
                 },
+                8 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.field_type, 8, &mut self.unknown_fields)?
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2169,6 +2492,9 @@ impl ::protobuf::Message for CastAddBody {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if self.field_type != CastType::CAST {
+            my_size += ::protobuf::rt::enum_size(8, self.field_type);
+        }
         if let ::std::option::Option::Some(ref v) = self.parent {
             match v {
                 &CastAddBody_oneof_parent::parent_cast_id(ref v) => {
@@ -2203,6 +2529,9 @@ impl ::protobuf::Message for CastAddBody {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if self.field_type != CastType::CAST {
+            os.write_enum(8, ::protobuf::ProtobufEnum::value(&self.field_type))?;
+        }
         if let ::std::option::Option::Some(ref v) = self.parent {
             match v {
                 &CastAddBody_oneof_parent::parent_cast_id(ref v) => {
@@ -2288,6 +2617,11 @@ impl ::protobuf::Message for CastAddBody {
                 |m: &CastAddBody| { &m.embeds },
                 |m: &mut CastAddBody| { &mut m.embeds },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CastType>>(
+                "type",
+                |m: &CastAddBody| { &m.field_type },
+                |m: &mut CastAddBody| { &mut m.field_type },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CastAddBody>(
                 "CastAddBody",
                 fields,
@@ -2311,6 +2645,7 @@ impl ::protobuf::Clear for CastAddBody {
         self.text.clear();
         self.mentions_positions.clear();
         self.embeds.clear();
+        self.field_type = CastType::CAST;
         self.unknown_fields.clear();
     }
 }
@@ -3773,163 +4108,1467 @@ impl ::protobuf::reflect::ProtobufValue for LinkBody {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct FrameActionBody {
+pub struct LinkCompactStateBody {
     // message fields
-    pub url: ::std::vec::Vec<u8>,
-    pub button_index: u32,
-    pub cast_id: ::protobuf::SingularPtrField<CastId>,
-    pub input_text: ::std::vec::Vec<u8>,
-    pub state: ::std::vec::Vec<u8>,
+    pub field_type: ::std::string::String,
+    pub target_fids: ::std::vec::Vec<u64>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a FrameActionBody {
-    fn default() -> &'a FrameActionBody {
-        <FrameActionBody as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a LinkCompactStateBody {
+    fn default() -> &'a LinkCompactStateBody {
+        <LinkCompactStateBody as ::protobuf::Message>::default_instance()
     }
 }
 
-impl FrameActionBody {
-    pub fn new() -> FrameActionBody {
+impl LinkCompactStateBody {
+    pub fn new() -> LinkCompactStateBody {
         ::std::default::Default::default()
     }
 
-    // bytes url = 1;
+    // string type = 1;
 
 
-    pub fn get_url(&self) -> &[u8] {
-        &self.url
+    pub fn get_field_type(&self) -> &str {
+        &self.field_type
     }
-    pub fn clear_url(&mut self) {
-        self.url.clear();
+    pub fn clear_field_type(&mut self) {
+        self.field_type.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_url(&mut self, v: ::std::vec::Vec<u8>) {
-        self.url = v;
+    pub fn set_field_type(&mut self, v: ::std::string::String) {
+        self.field_type = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_url(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.url
+    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
+        &mut self.field_type
     }
 
     // Take field
-    pub fn take_url(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.url, ::std::vec::Vec::new())
+    pub fn take_field_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
     }
 
-    // uint32 button_index = 2;
+    // repeated uint64 target_fids = 2;
 
 
-    pub fn get_button_index(&self) -> u32 {
-        self.button_index
+    pub fn get_target_fids(&self) -> &[u64] {
+        &self.target_fids
     }
-    pub fn clear_button_index(&mut self) {
-        self.button_index = 0;
+    pub fn clear_target_fids(&mut self) {
+        self.target_fids.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_button_index(&mut self, v: u32) {
-        self.button_index = v;
+    pub fn set_target_fids(&mut self, v: ::std::vec::Vec<u64>) {
+        self.target_fids = v;
     }
 
-    // .CastId cast_id = 3;
-
+    // Mutable pointer to the field.
+    pub fn mut_target_fids(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.target_fids
+    }
 
-    pub fn get_cast_id(&self) -> &CastId {
-        self.cast_id.as_ref().unwrap_or_else(|| <CastId as ::protobuf::Message>::default_instance())
+    // Take field
+    pub fn take_target_fids(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.target_fids, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for LinkCompactStateBody {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.target_fids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field_type);
+        }
+        for value in &self.target_fids {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field_type.is_empty() {
+            os.write_string(1, &self.field_type)?;
+        }
+        for v in &self.target_fids {
+            os.write_uint64(2, *v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LinkCompactStateBody {
+        LinkCompactStateBody::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "type",
+                |m: &LinkCompactStateBody| { &m.field_type },
+                |m: &mut LinkCompactStateBody| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "target_fids",
+                |m: &LinkCompactStateBody| { &m.target_fids },
+                |m: &mut LinkCompactStateBody| { &mut m.target_fids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LinkCompactStateBody>(
+                "LinkCompactStateBody",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LinkCompactStateBody {
+        static instance: ::protobuf::rt::LazyV2<LinkCompactStateBody> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LinkCompactStateBody::new)
+    }
+}
+
+impl ::protobuf::Clear for LinkCompactStateBody {
+    fn clear(&mut self) {
+        self.field_type.clear();
+        self.target_fids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LinkCompactStateBody {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LinkCompactStateBody {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FrameActionBody {
+    // message fields
+    pub url: ::std::vec::Vec<u8>,
+    pub button_index: u32,
+    pub cast_id: ::protobuf::SingularPtrField<CastId>,
+    pub input_text: ::std::vec::Vec<u8>,
+    pub state: ::std::vec::Vec<u8>,
+    pub transaction_id: ::std::vec::Vec<u8>,
+    pub address: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FrameActionBody {
+    fn default() -> &'a FrameActionBody {
+        <FrameActionBody as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FrameActionBody {
+    pub fn new() -> FrameActionBody {
+        ::std::default::Default::default()
+    }
+
+    // bytes url = 1;
+
+
+    pub fn get_url(&self) -> &[u8] {
+        &self.url
+    }
+    pub fn clear_url(&mut self) {
+        self.url.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_url(&mut self, v: ::std::vec::Vec<u8>) {
+        self.url = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_url(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.url
+    }
+
+    // Take field
+    pub fn take_url(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.url, ::std::vec::Vec::new())
+    }
+
+    // uint32 button_index = 2;
+
+
+    pub fn get_button_index(&self) -> u32 {
+        self.button_index
+    }
+    pub fn clear_button_index(&mut self) {
+        self.button_index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_button_index(&mut self, v: u32) {
+        self.button_index = v;
+    }
+
+    // .CastId cast_id = 3;
+
+
+    pub fn get_cast_id(&self) -> &CastId {
+        self.cast_id.as_ref().unwrap_or_else(|| <CastId as ::protobuf::Message>::default_instance())
     }
     pub fn clear_cast_id(&mut self) {
         self.cast_id.clear();
     }
 
-    pub fn has_cast_id(&self) -> bool {
-        self.cast_id.is_some()
+    pub fn has_cast_id(&self) -> bool {
+        self.cast_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cast_id(&mut self, v: CastId) {
+        self.cast_id = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cast_id(&mut self) -> &mut CastId {
+        if self.cast_id.is_none() {
+            self.cast_id.set_default();
+        }
+        self.cast_id.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_cast_id(&mut self) -> CastId {
+        self.cast_id.take().unwrap_or_else(|| CastId::new())
+    }
+
+    // bytes input_text = 4;
+
+
+    pub fn get_input_text(&self) -> &[u8] {
+        &self.input_text
+    }
+    pub fn clear_input_text(&mut self) {
+        self.input_text.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_input_text(&mut self, v: ::std::vec::Vec<u8>) {
+        self.input_text = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_input_text(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.input_text
+    }
+
+    // Take field
+    pub fn take_input_text(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.input_text, ::std::vec::Vec::new())
+    }
+
+    // bytes state = 5;
+
+
+    pub fn get_state(&self) -> &[u8] {
+        &self.state
+    }
+    pub fn clear_state(&mut self) {
+        self.state.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: ::std::vec::Vec<u8>) {
+        self.state = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_state(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.state
+    }
+
+    // Take field
+    pub fn take_state(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.state, ::std::vec::Vec::new())
+    }
+
+    // bytes transaction_id = 6;
+
+
+    pub fn get_transaction_id(&self) -> &[u8] {
+        &self.transaction_id
+    }
+    pub fn clear_transaction_id(&mut self) {
+        self.transaction_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transaction_id(&mut self, v: ::std::vec::Vec<u8>) {
+        self.transaction_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_transaction_id(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.transaction_id
+    }
+
+    // Take field
+    pub fn take_transaction_id(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.transaction_id, ::std::vec::Vec::new())
+    }
+
+    // bytes address = 7;
+
+
+    pub fn get_address(&self) -> &[u8] {
+        &self.address
+    }
+    pub fn clear_address(&mut self) {
+        self.address.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_address(&mut self, v: ::std::vec::Vec<u8>) {
+        self.address = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_address(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.address
+    }
+
+    // Take field
+    pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for FrameActionBody {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cast_id {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.url)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.button_index = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.cast_id)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.input_text)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.state)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.transaction_id)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.url.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.url);
+        }
+        if self.button_index != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.button_index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.cast_id.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.input_text.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.input_text);
+        }
+        if !self.state.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.state);
+        }
+        if !self.transaction_id.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.transaction_id);
+        }
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.address);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.url.is_empty() {
+            os.write_bytes(1, &self.url)?;
+        }
+        if self.button_index != 0 {
+            os.write_uint32(2, self.button_index)?;
+        }
+        if let Some(ref v) = self.cast_id.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.input_text.is_empty() {
+            os.write_bytes(4, &self.input_text)?;
+        }
+        if !self.state.is_empty() {
+            os.write_bytes(5, &self.state)?;
+        }
+        if !self.transaction_id.is_empty() {
+            os.write_bytes(6, &self.transaction_id)?;
+        }
+        if !self.address.is_empty() {
+            os.write_bytes(7, &self.address)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FrameActionBody {
+        FrameActionBody::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "url",
+                |m: &FrameActionBody| { &m.url },
+                |m: &mut FrameActionBody| { &mut m.url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "button_index",
+                |m: &FrameActionBody| { &m.button_index },
+                |m: &mut FrameActionBody| { &mut m.button_index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CastId>>(
+                "cast_id",
+                |m: &FrameActionBody| { &m.cast_id },
+                |m: &mut FrameActionBody| { &mut m.cast_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "input_text",
+                |m: &FrameActionBody| { &m.input_text },
+                |m: &mut FrameActionBody| { &mut m.input_text },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "state",
+                |m: &FrameActionBody| { &m.state },
+                |m: &mut FrameActionBody| { &mut m.state },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "transaction_id",
+                |m: &FrameActionBody| { &m.transaction_id },
+                |m: &mut FrameActionBody| { &mut m.transaction_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "address",
+                |m: &FrameActionBody| { &m.address },
+                |m: &mut FrameActionBody| { &mut m.address },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FrameActionBody>(
+                "FrameActionBody",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FrameActionBody {
+        static instance: ::protobuf::rt::LazyV2<FrameActionBody> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FrameActionBody::new)
+    }
+}
+
+impl ::protobuf::Clear for FrameActionBody {
+    fn clear(&mut self) {
+        self.url.clear();
+        self.button_index = 0;
+        self.cast_id.clear();
+        self.input_text.clear();
+        self.state.clear();
+        self.transaction_id.clear();
+        self.address.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FrameActionBody {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FrameActionBody {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct LendStorageBody {
+    // message fields
+    pub to_fid: u64,
+    pub num_units: u64,
+    pub unit_type: StorageUnitType,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a LendStorageBody {
+    fn default() -> &'a LendStorageBody {
+        <LendStorageBody as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl LendStorageBody {
+    pub fn new() -> LendStorageBody {
+        ::std::default::Default::default()
+    }
+
+    // uint64 to_fid = 1;
+
+
+    pub fn get_to_fid(&self) -> u64 {
+        self.to_fid
+    }
+    pub fn clear_to_fid(&mut self) {
+        self.to_fid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to_fid(&mut self, v: u64) {
+        self.to_fid = v;
+    }
+
+    // uint64 num_units = 2;
+
+
+    pub fn get_num_units(&self) -> u64 {
+        self.num_units
+    }
+    pub fn clear_num_units(&mut self) {
+        self.num_units = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_num_units(&mut self, v: u64) {
+        self.num_units = v;
+    }
+
+    // .StorageUnitType unit_type = 3;
+
+
+    pub fn get_unit_type(&self) -> StorageUnitType {
+        self.unit_type
+    }
+    pub fn clear_unit_type(&mut self) {
+        self.unit_type = StorageUnitType::UNIT_TYPE_LEGACY;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unit_type(&mut self, v: StorageUnitType) {
+        self.unit_type = v;
+    }
+}
+
+impl ::protobuf::Message for LendStorageBody {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.to_fid = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.num_units = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.unit_type, 3, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.to_fid != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.to_fid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.num_units != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.num_units, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.unit_type != StorageUnitType::UNIT_TYPE_LEGACY {
+            my_size += ::protobuf::rt::enum_size(3, self.unit_type);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.to_fid != 0 {
+            os.write_uint64(1, self.to_fid)?;
+        }
+        if self.num_units != 0 {
+            os.write_uint64(2, self.num_units)?;
+        }
+        if self.unit_type != StorageUnitType::UNIT_TYPE_LEGACY {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.unit_type))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> LendStorageBody {
+        LendStorageBody::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "to_fid",
+                |m: &LendStorageBody| { &m.to_fid },
+                |m: &mut LendStorageBody| { &mut m.to_fid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "num_units",
+                |m: &LendStorageBody| { &m.num_units },
+                |m: &mut LendStorageBody| { &mut m.num_units },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<StorageUnitType>>(
+                "unit_type",
+                |m: &LendStorageBody| { &m.unit_type },
+                |m: &mut LendStorageBody| { &mut m.unit_type },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<LendStorageBody>(
+                "LendStorageBody",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static LendStorageBody {
+        static instance: ::protobuf::rt::LazyV2<LendStorageBody> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(LendStorageBody::new)
+    }
+}
+
+impl ::protobuf::Clear for LendStorageBody {
+    fn clear(&mut self) {
+        self.to_fid = 0;
+        self.num_units = 0;
+        self.unit_type = StorageUnitType::UNIT_TYPE_LEGACY;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for LendStorageBody {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for LendStorageBody {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KeyAddBody {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub key_type: u32,
+    pub custody_signature: ::std::vec::Vec<u8>,
+    pub deadline: u32,
+    pub nonce: u32,
+    pub metadata: ::std::vec::Vec<u8>,
+    pub metadata_type: u32,
+    pub registration_tx_hash: ::std::vec::Vec<u8>,
+    pub scopes: ::std::vec::Vec<i32>,
+    pub ttl: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KeyAddBody {
+    fn default() -> &'a KeyAddBody {
+        <KeyAddBody as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyAddBody {
+    pub fn new() -> KeyAddBody {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // uint32 key_type = 2;
+
+
+    pub fn get_key_type(&self) -> u32 {
+        self.key_type
+    }
+    pub fn clear_key_type(&mut self) {
+        self.key_type = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_type(&mut self, v: u32) {
+        self.key_type = v;
+    }
+
+    // bytes custody_signature = 3;
+
+
+    pub fn get_custody_signature(&self) -> &[u8] {
+        &self.custody_signature
+    }
+    pub fn clear_custody_signature(&mut self) {
+        self.custody_signature.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_custody_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.custody_signature = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_custody_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.custody_signature
+    }
+
+    // Take field
+    pub fn take_custody_signature(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.custody_signature, ::std::vec::Vec::new())
+    }
+
+    // uint32 deadline = 4;
+
+
+    pub fn get_deadline(&self) -> u32 {
+        self.deadline
+    }
+    pub fn clear_deadline(&mut self) {
+        self.deadline = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deadline(&mut self, v: u32) {
+        self.deadline = v;
+    }
+
+    // uint32 nonce = 5;
+
+
+    pub fn get_nonce(&self) -> u32 {
+        self.nonce
+    }
+    pub fn clear_nonce(&mut self) {
+        self.nonce = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: u32) {
+        self.nonce = v;
+    }
+
+    // bytes metadata = 6;
+
+
+    pub fn get_metadata(&self) -> &[u8] {
+        &self.metadata
+    }
+    pub fn clear_metadata(&mut self) {
+        self.metadata.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_metadata(&mut self, v: ::std::vec::Vec<u8>) {
+        self.metadata = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_metadata(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.metadata
+    }
+
+    // Take field
+    pub fn take_metadata(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.metadata, ::std::vec::Vec::new())
+    }
+
+    // uint32 metadata_type = 7;
+
+
+    pub fn get_metadata_type(&self) -> u32 {
+        self.metadata_type
+    }
+    pub fn clear_metadata_type(&mut self) {
+        self.metadata_type = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_metadata_type(&mut self, v: u32) {
+        self.metadata_type = v;
+    }
+
+    // bytes registration_tx_hash = 8;
+
+
+    pub fn get_registration_tx_hash(&self) -> &[u8] {
+        &self.registration_tx_hash
+    }
+    pub fn clear_registration_tx_hash(&mut self) {
+        self.registration_tx_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_registration_tx_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.registration_tx_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_registration_tx_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.registration_tx_hash
+    }
+
+    // Take field
+    pub fn take_registration_tx_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.registration_tx_hash, ::std::vec::Vec::new())
+    }
+
+    // repeated int32 scopes = 9;
+
+
+    pub fn get_scopes(&self) -> &[i32] {
+        &self.scopes
+    }
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::std::vec::Vec<i32>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::std::vec::Vec<i32> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::std::vec::Vec<i32> {
+        ::std::mem::replace(&mut self.scopes, ::std::vec::Vec::new())
+    }
+
+    // uint32 ttl = 10;
+
+
+    pub fn get_ttl(&self) -> u32 {
+        self.ttl
+    }
+    pub fn clear_ttl(&mut self) {
+        self.ttl = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl(&mut self, v: u32) {
+        self.ttl = v;
+    }
+}
+
+impl ::protobuf::Message for KeyAddBody {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.key_type = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.custody_signature)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.deadline = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.nonce = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.metadata)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.metadata_type = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.registration_tx_hash)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_repeated_int32_into(wire_type, is, &mut self.scopes)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.ttl = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if self.key_type != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.key_type, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.custody_signature.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.custody_signature);
+        }
+        if self.deadline != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.deadline, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.nonce != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.nonce, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.metadata.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.metadata);
+        }
+        if self.metadata_type != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.metadata_type, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.registration_tx_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(8, &self.registration_tx_hash);
+        }
+        for value in &self.scopes {
+            my_size += ::protobuf::rt::value_size(9, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.ttl != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.ttl, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if self.key_type != 0 {
+            os.write_uint32(2, self.key_type)?;
+        }
+        if !self.custody_signature.is_empty() {
+            os.write_bytes(3, &self.custody_signature)?;
+        }
+        if self.deadline != 0 {
+            os.write_uint32(4, self.deadline)?;
+        }
+        if self.nonce != 0 {
+            os.write_uint32(5, self.nonce)?;
+        }
+        if !self.metadata.is_empty() {
+            os.write_bytes(6, &self.metadata)?;
+        }
+        if self.metadata_type != 0 {
+            os.write_uint32(7, self.metadata_type)?;
+        }
+        if !self.registration_tx_hash.is_empty() {
+            os.write_bytes(8, &self.registration_tx_hash)?;
+        }
+        for v in &self.scopes {
+            os.write_int32(9, *v)?;
+        };
+        if self.ttl != 0 {
+            os.write_uint32(10, self.ttl)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KeyAddBody {
+        KeyAddBody::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "key",
+                |m: &KeyAddBody| { &m.key },
+                |m: &mut KeyAddBody| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "key_type",
+                |m: &KeyAddBody| { &m.key_type },
+                |m: &mut KeyAddBody| { &mut m.key_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "custody_signature",
+                |m: &KeyAddBody| { &m.custody_signature },
+                |m: &mut KeyAddBody| { &mut m.custody_signature },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "deadline",
+                |m: &KeyAddBody| { &m.deadline },
+                |m: &mut KeyAddBody| { &mut m.deadline },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "nonce",
+                |m: &KeyAddBody| { &m.nonce },
+                |m: &mut KeyAddBody| { &mut m.nonce },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "metadata",
+                |m: &KeyAddBody| { &m.metadata },
+                |m: &mut KeyAddBody| { &mut m.metadata },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "metadata_type",
+                |m: &KeyAddBody| { &m.metadata_type },
+                |m: &mut KeyAddBody| { &mut m.metadata_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "registration_tx_hash",
+                |m: &KeyAddBody| { &m.registration_tx_hash },
+                |m: &mut KeyAddBody| { &mut m.registration_tx_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "scopes",
+                |m: &KeyAddBody| { &m.scopes },
+                |m: &mut KeyAddBody| { &mut m.scopes },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "ttl",
+                |m: &KeyAddBody| { &m.ttl },
+                |m: &mut KeyAddBody| { &mut m.ttl },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<KeyAddBody>(
+                "KeyAddBody",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static KeyAddBody {
+        static instance: ::protobuf::rt::LazyV2<KeyAddBody> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(KeyAddBody::new)
+    }
+}
+
+impl ::protobuf::Clear for KeyAddBody {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.key_type = 0;
+        self.custody_signature.clear();
+        self.deadline = 0;
+        self.nonce = 0;
+        self.metadata.clear();
+        self.metadata_type = 0;
+        self.registration_tx_hash.clear();
+        self.scopes.clear();
+        self.ttl = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KeyAddBody {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyAddBody {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KeyRemoveBody {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub signature: ::std::vec::Vec<u8>,
+    pub signature_type: u32,
+    pub deadline: u32,
+    pub nonce: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a KeyRemoveBody {
+    fn default() -> &'a KeyRemoveBody {
+        <KeyRemoveBody as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl KeyRemoveBody {
+    pub fn new() -> KeyRemoveBody {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_cast_id(&mut self, v: CastId) {
-        self.cast_id = ::protobuf::SingularPtrField::some(v);
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_cast_id(&mut self) -> &mut CastId {
-        if self.cast_id.is_none() {
-            self.cast_id.set_default();
-        }
-        self.cast_id.as_mut().unwrap()
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
     }
 
     // Take field
-    pub fn take_cast_id(&mut self) -> CastId {
-        self.cast_id.take().unwrap_or_else(|| CastId::new())
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
     }
 
-    // bytes input_text = 4;
+    // bytes signature = 2;
 
 
-    pub fn get_input_text(&self) -> &[u8] {
-        &self.input_text
+    pub fn get_signature(&self) -> &[u8] {
+        &self.signature
     }
-    pub fn clear_input_text(&mut self) {
-        self.input_text.clear();
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_input_text(&mut self, v: ::std::vec::Vec<u8>) {
-        self.input_text = v;
+    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.signature = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_input_text(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.input_text
+    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.signature
     }
 
     // Take field
-    pub fn take_input_text(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.input_text, ::std::vec::Vec::new())
+    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.signature, ::std::vec::Vec::new())
     }
 
-    // bytes state = 5;
+    // uint32 signature_type = 3;
 
 
-    pub fn get_state(&self) -> &[u8] {
-        &self.state
+    pub fn get_signature_type(&self) -> u32 {
+        self.signature_type
     }
-    pub fn clear_state(&mut self) {
-        self.state.clear();
+    pub fn clear_signature_type(&mut self) {
+        self.signature_type = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_state(&mut self, v: ::std::vec::Vec<u8>) {
-        self.state = v;
+    pub fn set_signature_type(&mut self, v: u32) {
+        self.signature_type = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_state(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.state
+    // uint32 deadline = 4;
+
+
+    pub fn get_deadline(&self) -> u32 {
+        self.deadline
+    }
+    pub fn clear_deadline(&mut self) {
+        self.deadline = 0;
     }
 
-    // Take field
-    pub fn take_state(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.state, ::std::vec::Vec::new())
+    // Param is passed by value, moved
+    pub fn set_deadline(&mut self, v: u32) {
+        self.deadline = v;
+    }
+
+    // uint32 nonce = 5;
+
+
+    pub fn get_nonce(&self) -> u32 {
+        self.nonce
+    }
+    pub fn clear_nonce(&mut self) {
+        self.nonce = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: u32) {
+        self.nonce = v;
     }
 }
 
-impl ::protobuf::Message for FrameActionBody {
+impl ::protobuf::Message for KeyRemoveBody {
     fn is_initialized(&self) -> bool {
-        for v in &self.cast_id {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -3938,23 +5577,31 @@ impl ::protobuf::Message for FrameActionBody {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.url)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
                 },
                 2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.signature)?;
+                },
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.button_index = tmp;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.cast_id)?;
+                    self.signature_type = tmp;
                 },
                 4 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.input_text)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.deadline = tmp;
                 },
                 5 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.state)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.nonce = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3968,21 +5615,20 @@ impl ::protobuf::Message for FrameActionBody {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.url.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.url);
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
         }
-        if self.button_index != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.button_index, ::protobuf::wire_format::WireTypeVarint);
+        if !self.signature.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.signature);
         }
-        if let Some(ref v) = self.cast_id.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if self.signature_type != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.signature_type, ::protobuf::wire_format::WireTypeVarint);
         }
-        if !self.input_text.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(4, &self.input_text);
+        if self.deadline != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.deadline, ::protobuf::wire_format::WireTypeVarint);
         }
-        if !self.state.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(5, &self.state);
+        if self.nonce != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.nonce, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3990,22 +5636,20 @@ impl ::protobuf::Message for FrameActionBody {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.url.is_empty() {
-            os.write_bytes(1, &self.url)?;
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
         }
-        if self.button_index != 0 {
-            os.write_uint32(2, self.button_index)?;
+        if !self.signature.is_empty() {
+            os.write_bytes(2, &self.signature)?;
         }
-        if let Some(ref v) = self.cast_id.as_ref() {
-            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if self.signature_type != 0 {
+            os.write_uint32(3, self.signature_type)?;
         }
-        if !self.input_text.is_empty() {
-            os.write_bytes(4, &self.input_text)?;
+        if self.deadline != 0 {
+            os.write_uint32(4, self.deadline)?;
         }
-        if !self.state.is_empty() {
-            os.write_bytes(5, &self.state)?;
+        if self.nonce != 0 {
+            os.write_uint32(5, self.nonce)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4037,8 +5681,8 @@ impl ::protobuf::Message for FrameActionBody {
         Self::descriptor_static()
     }
 
-    fn new() -> FrameActionBody {
-        FrameActionBody::new()
+    fn new() -> KeyRemoveBody {
+        KeyRemoveBody::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4046,62 +5690,62 @@ impl ::protobuf::Message for FrameActionBody {
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                "url",
-                |m: &FrameActionBody| { &m.url },
-                |m: &mut FrameActionBody| { &mut m.url },
+                "key",
+                |m: &KeyRemoveBody| { &m.key },
+                |m: &mut KeyRemoveBody| { &mut m.key },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                "button_index",
-                |m: &FrameActionBody| { &m.button_index },
-                |m: &mut FrameActionBody| { &mut m.button_index },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "signature",
+                |m: &KeyRemoveBody| { &m.signature },
+                |m: &mut KeyRemoveBody| { &mut m.signature },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CastId>>(
-                "cast_id",
-                |m: &FrameActionBody| { &m.cast_id },
-                |m: &mut FrameActionBody| { &mut m.cast_id },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "signature_type",
+                |m: &KeyRemoveBody| { &m.signature_type },
+                |m: &mut KeyRemoveBody| { &mut m.signature_type },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                "input_text",
-                |m: &FrameActionBody| { &m.input_text },
-                |m: &mut FrameActionBody| { &mut m.input_text },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "deadline",
+                |m: &KeyRemoveBody| { &m.deadline },
+                |m: &mut KeyRemoveBody| { &mut m.deadline },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                "state",
-                |m: &FrameActionBody| { &m.state },
-                |m: &mut FrameActionBody| { &mut m.state },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "nonce",
+                |m: &KeyRemoveBody| { &m.nonce },
+                |m: &mut KeyRemoveBody| { &mut m.nonce },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FrameActionBody>(
-                "FrameActionBody",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<KeyRemoveBody>(
+                "KeyRemoveBody",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static FrameActionBody {
-        static instance: ::protobuf::rt::LazyV2<FrameActionBody> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(FrameActionBody::new)
+    fn default_instance() -> &'static KeyRemoveBody {
+        static instance: ::protobuf::rt::LazyV2<KeyRemoveBody> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(KeyRemoveBody::new)
     }
 }
 
-impl ::protobuf::Clear for FrameActionBody {
+impl ::protobuf::Clear for KeyRemoveBody {
     fn clear(&mut self) {
-        self.url.clear();
-        self.button_index = 0;
-        self.cast_id.clear();
-        self.input_text.clear();
-        self.state.clear();
+        self.key.clear();
+        self.signature.clear();
+        self.signature_type = 0;
+        self.deadline = 0;
+        self.nonce = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for FrameActionBody {
+impl ::std::fmt::Debug for KeyRemoveBody {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for FrameActionBody {
+impl ::protobuf::reflect::ProtobufValue for KeyRemoveBody {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
@@ -4224,6 +5868,10 @@ pub enum MessageType {
     MESSAGE_TYPE_USER_DATA_ADD = 11,
     MESSAGE_TYPE_USERNAME_PROOF = 12,
     MESSAGE_TYPE_FRAME_ACTION = 13,
+    MESSAGE_TYPE_LINK_COMPACT_STATE = 14,
+    MESSAGE_TYPE_LEND_STORAGE = 15,
+    MESSAGE_TYPE_KEY_ADD = 16,
+    MESSAGE_TYPE_KEY_REMOVE = 17,
 }
 
 impl ::protobuf::ProtobufEnum for MessageType {
@@ -4245,6 +5893,10 @@ impl ::protobuf::ProtobufEnum for MessageType {
             11 => ::std::option::Option::Some(MessageType::MESSAGE_TYPE_USER_DATA_ADD),
             12 => ::std::option::Option::Some(MessageType::MESSAGE_TYPE_USERNAME_PROOF),
             13 => ::std::option::Option::Some(MessageType::MESSAGE_TYPE_FRAME_ACTION),
+            14 => ::std::option::Option::Some(MessageType::MESSAGE_TYPE_LINK_COMPACT_STATE),
+            15 => ::std::option::Option::Some(MessageType::MESSAGE_TYPE_LEND_STORAGE),
+            16 => ::std::option::Option::Some(MessageType::MESSAGE_TYPE_KEY_ADD),
+            17 => ::std::option::Option::Some(MessageType::MESSAGE_TYPE_KEY_REMOVE),
             _ => ::std::option::Option::None
         }
     }
@@ -4263,6 +5915,10 @@ impl ::protobuf::ProtobufEnum for MessageType {
             MessageType::MESSAGE_TYPE_USER_DATA_ADD,
             MessageType::MESSAGE_TYPE_USERNAME_PROOF,
             MessageType::MESSAGE_TYPE_FRAME_ACTION,
+            MessageType::MESSAGE_TYPE_LINK_COMPACT_STATE,
+            MessageType::MESSAGE_TYPE_LEND_STORAGE,
+            MessageType::MESSAGE_TYPE_KEY_ADD,
+            MessageType::MESSAGE_TYPE_KEY_REMOVE,
         ];
         values
     }
@@ -4354,6 +6010,13 @@ pub enum UserDataType {
     USER_DATA_TYPE_BIO = 3,
     USER_DATA_TYPE_URL = 5,
     USER_DATA_TYPE_USERNAME = 6,
+    USER_DATA_TYPE_LOCATION = 7,
+    USER_DATA_TYPE_TWITTER = 8,
+    USER_DATA_TYPE_GITHUB = 9,
+    USER_DATA_TYPE_BANNER = 10,
+    USER_DATA_PRIMARY_ADDRESS_ETHEREUM = 11,
+    USER_DATA_PRIMARY_ADDRESS_SOLANA = 12,
+    USER_DATA_TYPE_PROFILE_TOKEN = 13,
 }
 
 impl ::protobuf::ProtobufEnum for UserDataType {
@@ -4369,6 +6032,13 @@ impl ::protobuf::ProtobufEnum for UserDataType {
             3 => ::std::option::Option::Some(UserDataType::USER_DATA_TYPE_BIO),
             5 => ::std::option::Option::Some(UserDataType::USER_DATA_TYPE_URL),
             6 => ::std::option::Option::Some(UserDataType::USER_DATA_TYPE_USERNAME),
+            7 => ::std::option::Option::Some(UserDataType::USER_DATA_TYPE_LOCATION),
+            8 => ::std::option::Option::Some(UserDataType::USER_DATA_TYPE_TWITTER),
+            9 => ::std::option::Option::Some(UserDataType::USER_DATA_TYPE_GITHUB),
+            10 => ::std::option::Option::Some(UserDataType::USER_DATA_TYPE_BANNER),
+            11 => ::std::option::Option::Some(UserDataType::USER_DATA_PRIMARY_ADDRESS_ETHEREUM),
+            12 => ::std::option::Option::Some(UserDataType::USER_DATA_PRIMARY_ADDRESS_SOLANA),
+            13 => ::std::option::Option::Some(UserDataType::USER_DATA_TYPE_PROFILE_TOKEN),
             _ => ::std::option::Option::None
         }
     }
@@ -4381,6 +6051,13 @@ impl ::protobuf::ProtobufEnum for UserDataType {
             UserDataType::USER_DATA_TYPE_BIO,
             UserDataType::USER_DATA_TYPE_URL,
             UserDataType::USER_DATA_TYPE_USERNAME,
+            UserDataType::USER_DATA_TYPE_LOCATION,
+            UserDataType::USER_DATA_TYPE_TWITTER,
+            UserDataType::USER_DATA_TYPE_GITHUB,
+            UserDataType::USER_DATA_TYPE_BANNER,
+            UserDataType::USER_DATA_PRIMARY_ADDRESS_ETHEREUM,
+            UserDataType::USER_DATA_PRIMARY_ADDRESS_SOLANA,
+            UserDataType::USER_DATA_TYPE_PROFILE_TOKEN,
         ];
         values
     }
@@ -4408,6 +6085,59 @@ impl ::protobuf::reflect::ProtobufValue for UserDataType {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CastType {
+    CAST = 0,
+    LONG_CAST = 1,
+    TEN_K_CAST = 2,
+}
+
+impl ::protobuf::ProtobufEnum for CastType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CastType> {
+        match value {
+            0 => ::std::option::Option::Some(CastType::CAST),
+            1 => ::std::option::Option::Some(CastType::LONG_CAST),
+            2 => ::std::option::Option::Some(CastType::TEN_K_CAST),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CastType] = &[
+            CastType::CAST,
+            CastType::LONG_CAST,
+            CastType::TEN_K_CAST,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<CastType>("CastType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for CastType {
+}
+
+impl ::std::default::Default for CastType {
+    fn default() -> Self {
+        CastType::CAST
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CastType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum ReactionType {
     REACTION_TYPE_NONE = 0,
@@ -4511,6 +6241,59 @@ impl ::protobuf::reflect::ProtobufValue for Protocol {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum StorageUnitType {
+    UNIT_TYPE_LEGACY = 0,
+    UNIT_TYPE_2024 = 1,
+    UNIT_TYPE_2025 = 2,
+}
+
+impl ::protobuf::ProtobufEnum for StorageUnitType {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<StorageUnitType> {
+        match value {
+            0 => ::std::option::Option::Some(StorageUnitType::UNIT_TYPE_LEGACY),
+            1 => ::std::option::Option::Some(StorageUnitType::UNIT_TYPE_2024),
+            2 => ::std::option::Option::Some(StorageUnitType::UNIT_TYPE_2025),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [StorageUnitType] = &[
+            StorageUnitType::UNIT_TYPE_LEGACY,
+            StorageUnitType::UNIT_TYPE_2024,
+            StorageUnitType::UNIT_TYPE_2025,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<StorageUnitType>("StorageUnitType", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for StorageUnitType {
+}
+
+impl ::std::default::Default for StorageUnitType {
+    fn default() -> Self {
+        StorageUnitType::UNIT_TYPE_LEGACY
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StorageUnitType {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\rmessage.proto\x1a\x14username_proof.proto\"\x8f\x02\n\x07Message\x12\
     \"\n\x04data\x18\x01\x20\x01(\x0b2\x0c.MessageDataR\x04dataB\0\x12\x14\n\
@@ -4519,7 +6302,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x04\x20\x01(\x0cR\tsignatureB\0\x12=\n\x10signature_scheme\x18\x05\x20\
     \x01(\x0e2\x10.SignatureSchemeR\x0fsignatureSchemeB\0\x12\x18\n\x06signe\
     r\x18\x06\x20\x01(\x0cR\x06signerB\0\x12\x1f\n\ndata_bytes\x18\x07\x20\
-    \x01(\x0cR\tdataBytesB\0:\0\"\xf1\x05\n\x0bMessageData\x12\"\n\x04type\
+    \x01(\x0cR\tdataBytesB\0:\0\"\xf4\x07\n\x0bMessageData\x12\"\n\x04type\
     \x18\x01\x20\x01(\x0e2\x0c.MessageTypeR\x04typeB\0\x12\x12\n\x03fid\x18\
     \x02\x20\x01(\x04R\x03fidB\0\x12\x1e\n\ttimestamp\x18\x03\x20\x01(\rR\tt\
     imestampB\0\x12-\n\x07network\x18\x04\x20\x01(\x0e2\x11.FarcasterNetwork\
@@ -4535,62 +6318,99 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     inkBodyH\0R\x08linkBodyB\0\x12B\n\x13username_proof_body\x18\x0f\x20\x01\
     (\x0b2\x0e.UserNameProofH\0R\x11usernameProofBodyB\0\x12@\n\x11frame_act\
     ion_body\x18\x10\x20\x01(\x0b2\x10.FrameActionBodyH\0R\x0fframeActionBod\
-    yB\0B\x06\n\x04body:\0\"M\n\x0cUserDataBody\x12#\n\x04type\x18\x01\x20\
-    \x01(\x0e2\r.UserDataTypeR\x04typeB\0\x12\x16\n\x05value\x18\x02\x20\x01\
-    (\tR\x05valueB\0:\0\"N\n\x05Embed\x12\x14\n\x03url\x18\x01\x20\x01(\tH\0\
-    R\x03urlB\0\x12$\n\x07cast_id\x18\x02\x20\x01(\x0b2\x07.CastIdH\0R\x06ca\
-    stIdB\0B\x07\n\x05embed:\0\"\xa5\x02\n\x0bCastAddBody\x12-\n\x11embeds_d\
-    eprecated\x18\x01\x20\x03(\tR\x10embedsDeprecatedB\0\x12\x1c\n\x08mentio\
-    ns\x18\x02\x20\x03(\x04R\x08mentionsB\0\x121\n\x0eparent_cast_id\x18\x03\
-    \x20\x01(\x0b2\x07.CastIdH\0R\x0cparentCastIdB\0\x12!\n\nparent_url\x18\
-    \x07\x20\x01(\tH\0R\tparentUrlB\0\x12\x14\n\x04text\x18\x04\x20\x01(\tR\
-    \x04textB\0\x12/\n\x12mentions_positions\x18\x05\x20\x03(\rR\x11mentions\
-    PositionsB\0\x12\x20\n\x06embeds\x18\x06\x20\x03(\x0b2\x06.EmbedR\x06emb\
-    edsB\0B\x08\n\x06parent:\0\"5\n\x0eCastRemoveBody\x12!\n\x0btarget_hash\
-    \x18\x01\x20\x01(\x0cR\ntargetHashB\0:\0\"4\n\x06CastId\x12\x12\n\x03fid\
-    \x18\x01\x20\x01(\x04R\x03fidB\0\x12\x14\n\x04hash\x18\x02\x20\x01(\x0cR\
-    \x04hashB\0:\0\"\x95\x01\n\x0cReactionBody\x12#\n\x04type\x18\x01\x20\
-    \x01(\x0e2\r.ReactionTypeR\x04typeB\0\x121\n\x0etarget_cast_id\x18\x02\
-    \x20\x01(\x0b2\x07.CastIdH\0R\x0ctargetCastIdB\0\x12!\n\ntarget_url\x18\
-    \x03\x20\x01(\tH\0R\ttargetUrlB\0B\x08\n\x06target:\0\"\xfb\x01\n\x1aVer\
-    ificationAddAddressBody\x12\x1a\n\x07address\x18\x01\x20\x01(\x0cR\x07ad\
-    dressB\0\x12)\n\x0fclaim_signature\x18\x02\x20\x01(\x0cR\x0eclaimSignatu\
-    reB\0\x12\x1f\n\nblock_hash\x18\x03\x20\x01(\x0cR\tblockHashB\0\x12-\n\
-    \x11verification_type\x18\x04\x20\x01(\rR\x10verificationTypeB\0\x12\x1b\
-    \n\x08chain_id\x18\x05\x20\x01(\rR\x07chainIdB\0\x12'\n\x08protocol\x18\
-    \x07\x20\x01(\x0e2\t.ProtocolR\x08protocolB\0:\0\"_\n\x16VerificationRem\
-    oveBody\x12\x1a\n\x07address\x18\x01\x20\x01(\x0cR\x07addressB\0\x12'\n\
-    \x08protocol\x18\x02\x20\x01(\x0e2\t.ProtocolR\x08protocolB\0:\0\"}\n\
-    \x08LinkBody\x12\x14\n\x04type\x18\x01\x20\x01(\tR\x04typeB\0\x12,\n\x10\
-    displayTimestamp\x18\x02\x20\x01(\rR\x10displayTimestampB\0\x12!\n\ntarg\
-    et_fid\x18\x03\x20\x01(\x04H\0R\ttargetFidB\0B\x08\n\x06target:\0\"\xa9\
-    \x01\n\x0fFrameActionBody\x12\x12\n\x03url\x18\x01\x20\x01(\x0cR\x03urlB\
-    \0\x12#\n\x0cbutton_index\x18\x02\x20\x01(\rR\x0bbuttonIndexB\0\x12\"\n\
-    \x07cast_id\x18\x03\x20\x01(\x0b2\x07.CastIdR\x06castIdB\0\x12\x1f\n\nin\
-    put_text\x18\x04\x20\x01(\x0cR\tinputTextB\0\x12\x16\n\x05state\x18\x05\
-    \x20\x01(\x0cR\x05stateB\0:\0*<\n\nHashScheme\x12\x14\n\x10HASH_SCHEME_N\
-    ONE\x10\0\x12\x16\n\x12HASH_SCHEME_BLAKE3\x10\x01\x1a\0*i\n\x0fSignature\
-    Scheme\x12\x19\n\x15SIGNATURE_SCHEME_NONE\x10\0\x12\x1c\n\x18SIGNATURE_S\
-    CHEME_ED25519\x10\x01\x12\x1b\n\x17SIGNATURE_SCHEME_EIP712\x10\x02\x1a\0\
-    *\x8e\x03\n\x0bMessageType\x12\x15\n\x11MESSAGE_TYPE_NONE\x10\0\x12\x19\
-    \n\x15MESSAGE_TYPE_CAST_ADD\x10\x01\x12\x1c\n\x18MESSAGE_TYPE_CAST_REMOV\
-    E\x10\x02\x12\x1d\n\x19MESSAGE_TYPE_REACTION_ADD\x10\x03\x12\x20\n\x1cME\
-    SSAGE_TYPE_REACTION_REMOVE\x10\x04\x12\x19\n\x15MESSAGE_TYPE_LINK_ADD\
-    \x10\x05\x12\x1c\n\x18MESSAGE_TYPE_LINK_REMOVE\x10\x06\x12-\n)MESSAGE_TY\
-    PE_VERIFICATION_ADD_ETH_ADDRESS\x10\x07\x12$\n\x20MESSAGE_TYPE_VERIFICAT\
-    ION_REMOVE\x10\x08\x12\x1e\n\x1aMESSAGE_TYPE_USER_DATA_ADD\x10\x0b\x12\
-    \x1f\n\x1bMESSAGE_TYPE_USERNAME_PROOF\x10\x0c\x12\x1d\n\x19MESSAGE_TYPE_\
-    FRAME_ACTION\x10\r\x1a\0*\x8c\x01\n\x10FarcasterNetwork\x12\x1a\n\x16FAR\
-    CASTER_NETWORK_NONE\x10\0\x12\x1d\n\x19FARCASTER_NETWORK_MAINNET\x10\x01\
-    \x12\x1d\n\x19FARCASTER_NETWORK_TESTNET\x10\x02\x12\x1c\n\x18FARCASTER_N\
-    ETWORK_DEVNET\x10\x03\x1a\0*\xaa\x01\n\x0cUserDataType\x12\x17\n\x13USER\
-    _DATA_TYPE_NONE\x10\0\x12\x16\n\x12USER_DATA_TYPE_PFP\x10\x01\x12\x1a\n\
-    \x16USER_DATA_TYPE_DISPLAY\x10\x02\x12\x16\n\x12USER_DATA_TYPE_BIO\x10\
-    \x03\x12\x16\n\x12USER_DATA_TYPE_URL\x10\x05\x12\x1b\n\x17USER_DATA_TYPE\
-    _USERNAME\x10\x06\x1a\0*Z\n\x0cReactionType\x12\x16\n\x12REACTION_TYPE_N\
-    ONE\x10\0\x12\x16\n\x12REACTION_TYPE_LIKE\x10\x01\x12\x18\n\x14REACTION_\
-    TYPE_RECAST\x10\x02\x1a\0*8\n\x08Protocol\x12\x15\n\x11PROTOCOL_ETHEREUM\
-    \x10\0\x12\x13\n\x0fPROTOCOL_SOLANA\x10\x01\x1a\0B\0b\x06proto3\
+    yB\0\x12P\n\x17link_compact_state_body\x18\x11\x20\x01(\x0b2\x15.LinkCom\
+    pactStateBodyH\0R\x14linkCompactStateBodyB\0\x12@\n\x11lend_storage_body\
+    \x18\x12\x20\x01(\x0b2\x10.LendStorageBodyH\0R\x0flendStorageBodyB\0\x12\
+    1\n\x0ckey_add_body\x18\x13\x20\x01(\x0b2\x0b.KeyAddBodyH\0R\nkeyAddBody\
+    B\0\x12:\n\x0fkey_remove_body\x18\x14\x20\x01(\x0b2\x0e.KeyRemoveBodyH\0\
+    R\rkeyRemoveBodyB\0B\x06\n\x04body:\0\"M\n\x0cUserDataBody\x12#\n\x04typ\
+    e\x18\x01\x20\x01(\x0e2\r.UserDataTypeR\x04typeB\0\x12\x16\n\x05value\
+    \x18\x02\x20\x01(\tR\x05valueB\0:\0\"N\n\x05Embed\x12\x14\n\x03url\x18\
+    \x01\x20\x01(\tH\0R\x03urlB\0\x12$\n\x07cast_id\x18\x02\x20\x01(\x0b2\
+    \x07.CastIdH\0R\x06castIdB\0B\x07\n\x05embed:\0\"\xc6\x02\n\x0bCastAddBo\
+    dy\x12-\n\x11embeds_deprecated\x18\x01\x20\x03(\tR\x10embedsDeprecatedB\
+    \0\x12\x1c\n\x08mentions\x18\x02\x20\x03(\x04R\x08mentionsB\0\x121\n\x0e\
+    parent_cast_id\x18\x03\x20\x01(\x0b2\x07.CastIdH\0R\x0cparentCastIdB\0\
+    \x12!\n\nparent_url\x18\x07\x20\x01(\tH\0R\tparentUrlB\0\x12\x14\n\x04te\
+    xt\x18\x04\x20\x01(\tR\x04textB\0\x12/\n\x12mentions_positions\x18\x05\
+    \x20\x03(\rR\x11mentionsPositionsB\0\x12\x20\n\x06embeds\x18\x06\x20\x03\
+    (\x0b2\x06.EmbedR\x06embedsB\0\x12\x1f\n\x04type\x18\x08\x20\x01(\x0e2\t\
+    .CastTypeR\x04typeB\0B\x08\n\x06parent:\0\"5\n\x0eCastRemoveBody\x12!\n\
+    \x0btarget_hash\x18\x01\x20\x01(\x0cR\ntargetHashB\0:\0\"4\n\x06CastId\
+    \x12\x12\n\x03fid\x18\x01\x20\x01(\x04R\x03fidB\0\x12\x14\n\x04hash\x18\
+    \x02\x20\x01(\x0cR\x04hashB\0:\0\"\x95\x01\n\x0cReactionBody\x12#\n\x04t\
+    ype\x18\x01\x20\x01(\x0e2\r.ReactionTypeR\x04typeB\0\x121\n\x0etarget_ca\
+    st_id\x18\x02\x20\x01(\x0b2\x07.CastIdH\0R\x0ctargetCastIdB\0\x12!\n\nta\
+    rget_url\x18\x03\x20\x01(\tH\0R\ttargetUrlB\0B\x08\n\x06target:\0\"\xfb\
+    \x01\n\x1aVerificationAddAddressBody\x12\x1a\n\x07address\x18\x01\x20\
+    \x01(\x0cR\x07addressB\0\x12)\n\x0fclaim_signature\x18\x02\x20\x01(\x0cR\
+    \x0eclaimSignatureB\0\x12\x1f\n\nblock_hash\x18\x03\x20\x01(\x0cR\tblock\
+    HashB\0\x12-\n\x11verification_type\x18\x04\x20\x01(\rR\x10verificationT\
+    ypeB\0\x12\x1b\n\x08chain_id\x18\x05\x20\x01(\rR\x07chainIdB\0\x12'\n\
+    \x08protocol\x18\x07\x20\x01(\x0e2\t.ProtocolR\x08protocolB\0:\0\"_\n\
+    \x16VerificationRemoveBody\x12\x1a\n\x07address\x18\x01\x20\x01(\x0cR\
+    \x07addressB\0\x12'\n\x08protocol\x18\x02\x20\x01(\x0e2\t.ProtocolR\x08p\
+    rotocolB\0:\0\"}\n\x08LinkBody\x12\x14\n\x04type\x18\x01\x20\x01(\tR\x04\
+    typeB\0\x12,\n\x10displayTimestamp\x18\x02\x20\x01(\rR\x10displayTimesta\
+    mpB\0\x12!\n\ntarget_fid\x18\x03\x20\x01(\x04H\0R\ttargetFidB\0B\x08\n\
+    \x06target:\0\"Q\n\x14LinkCompactStateBody\x12\x14\n\x04type\x18\x01\x20\
+    \x01(\tR\x04typeB\0\x12!\n\x0btarget_fids\x18\x02\x20\x03(\x04R\ntargetF\
+    idsB\0:\0\"\xee\x01\n\x0fFrameActionBody\x12\x12\n\x03url\x18\x01\x20\
+    \x01(\x0cR\x03urlB\0\x12#\n\x0cbutton_index\x18\x02\x20\x01(\rR\x0bbutto\
+    nIndexB\0\x12\"\n\x07cast_id\x18\x03\x20\x01(\x0b2\x07.CastIdR\x06castId\
+    B\0\x12\x1f\n\ninput_text\x18\x04\x20\x01(\x0cR\tinputTextB\0\x12\x16\n\
+    \x05state\x18\x05\x20\x01(\x0cR\x05stateB\0\x12'\n\x0etransaction_id\x18\
+    \x06\x20\x01(\x0cR\rtransactionIdB\0\x12\x1a\n\x07address\x18\x07\x20\
+    \x01(\x0cR\x07addressB\0:\0\"|\n\x0fLendStorageBody\x12\x17\n\x06to_fid\
+    \x18\x01\x20\x01(\x04R\x05toFidB\0\x12\x1d\n\tnum_units\x18\x02\x20\x01(\
+    \x04R\x08numUnitsB\0\x12/\n\tunit_type\x18\x03\x20\x01(\x0e2\x10.Storage\
+    UnitTypeR\x08unitTypeB\0:\0\"\xcb\x02\n\nKeyAddBody\x12\x12\n\x03key\x18\
+    \x01\x20\x01(\x0cR\x03keyB\0\x12\x1b\n\x08key_type\x18\x02\x20\x01(\rR\
+    \x07keyTypeB\0\x12-\n\x11custody_signature\x18\x03\x20\x01(\x0cR\x10cust\
+    odySignatureB\0\x12\x1c\n\x08deadline\x18\x04\x20\x01(\rR\x08deadlineB\0\
+    \x12\x16\n\x05nonce\x18\x05\x20\x01(\rR\x05nonceB\0\x12\x1c\n\x08metadat\
+    a\x18\x06\x20\x01(\x0cR\x08metadataB\0\x12%\n\rmetadata_type\x18\x07\x20\
+    \x01(\rR\x0cmetadataTypeB\0\x122\n\x14registration_tx_hash\x18\x08\x20\
+    \x01(\x0cR\x12registrationTxHashB\0\x12\x18\n\x06scopes\x18\t\x20\x03(\
+    \x05R\x06scopesB\0\x12\x12\n\x03ttl\x18\n\x20\x01(\rR\x03ttlB\0:\0\"\xa4\
+    \x01\n\rKeyRemoveBody\x12\x12\n\x03key\x18\x01\x20\x01(\x0cR\x03keyB\0\
+    \x12\x1e\n\tsignature\x18\x02\x20\x01(\x0cR\tsignatureB\0\x12'\n\x0esign\
+    ature_type\x18\x03\x20\x01(\rR\rsignatureTypeB\0\x12\x1c\n\x08deadline\
+    \x18\x04\x20\x01(\rR\x08deadlineB\0\x12\x16\n\x05nonce\x18\x05\x20\x01(\
+    \rR\x05nonceB\0:\0*<\n\nHashScheme\x12\x14\n\x10HASH_SCHEME_NONE\x10\0\
+    \x12\x16\n\x12HASH_SCHEME_BLAKE3\x10\x01\x1a\0*i\n\x0fSignatureScheme\
+    \x12\x19\n\x15SIGNATURE_SCHEME_NONE\x10\0\x12\x1c\n\x18SIGNATURE_SCHEME_\
+    ED25519\x10\x01\x12\x1b\n\x17SIGNATURE_SCHEME_EIP712\x10\x02\x1a\0*\x89\
+    \x04\n\x0bMessageType\x12\x15\n\x11MESSAGE_TYPE_NONE\x10\0\x12\x19\n\x15\
+    MESSAGE_TYPE_CAST_ADD\x10\x01\x12\x1c\n\x18MESSAGE_TYPE_CAST_REMOVE\x10\
+    \x02\x12\x1d\n\x19MESSAGE_TYPE_REACTION_ADD\x10\x03\x12\x20\n\x1cMESSAGE\
+    _TYPE_REACTION_REMOVE\x10\x04\x12\x19\n\x15MESSAGE_TYPE_LINK_ADD\x10\x05\
+    \x12\x1c\n\x18MESSAGE_TYPE_LINK_REMOVE\x10\x06\x12-\n)MESSAGE_TYPE_VERIF\
+    ICATION_ADD_ETH_ADDRESS\x10\x07\x12$\n\x20MESSAGE_TYPE_VERIFICATION_REMO\
+    VE\x10\x08\x12\x1e\n\x1aMESSAGE_TYPE_USER_DATA_ADD\x10\x0b\x12\x1f\n\x1b\
+    MESSAGE_TYPE_USERNAME_PROOF\x10\x0c\x12\x1d\n\x19MESSAGE_TYPE_FRAME_ACTI\
+    ON\x10\r\x12#\n\x1fMESSAGE_TYPE_LINK_COMPACT_STATE\x10\x0e\x12\x1d\n\x19\
+    MESSAGE_TYPE_LEND_STORAGE\x10\x0f\x12\x18\n\x14MESSAGE_TYPE_KEY_ADD\x10\
+    \x10\x12\x1b\n\x17MESSAGE_TYPE_KEY_REMOVE\x10\x11\x1a\0*\x8c\x01\n\x10Fa\
+    rcasterNetwork\x12\x1a\n\x16FARCASTER_NETWORK_NONE\x10\0\x12\x1d\n\x19FA\
+    RCASTER_NETWORK_MAINNET\x10\x01\x12\x1d\n\x19FARCASTER_NETWORK_TESTNET\
+    \x10\x02\x12\x1c\n\x18FARCASTER_NETWORK_DEVNET\x10\x03\x1a\0*\x89\x03\n\
+    \x0cUserDataType\x12\x17\n\x13USER_DATA_TYPE_NONE\x10\0\x12\x16\n\x12USE\
+    R_DATA_TYPE_PFP\x10\x01\x12\x1a\n\x16USER_DATA_TYPE_DISPLAY\x10\x02\x12\
+    \x16\n\x12USER_DATA_TYPE_BIO\x10\x03\x12\x16\n\x12USER_DATA_TYPE_URL\x10\
+    \x05\x12\x1b\n\x17USER_DATA_TYPE_USERNAME\x10\x06\x12\x1b\n\x17USER_DATA\
+    _TYPE_LOCATION\x10\x07\x12\x1a\n\x16USER_DATA_TYPE_TWITTER\x10\x08\x12\
+    \x19\n\x15USER_DATA_TYPE_GITHUB\x10\t\x12\x19\n\x15USER_DATA_TYPE_BANNER\
+    \x10\n\x12&\n\"USER_DATA_PRIMARY_ADDRESS_ETHEREUM\x10\x0b\x12$\n\x20USER\
+    _DATA_PRIMARY_ADDRESS_SOLANA\x10\x0c\x12\x20\n\x1cUSER_DATA_TYPE_PROFILE\
+    _TOKEN\x10\r\x1a\0*5\n\x08CastType\x12\x08\n\x04CAST\x10\0\x12\r\n\tLONG\
+    _CAST\x10\x01\x12\x0e\n\nTEN_K_CAST\x10\x02\x1a\0*Z\n\x0cReactionType\
+    \x12\x16\n\x12REACTION_TYPE_NONE\x10\0\x12\x16\n\x12REACTION_TYPE_LIKE\
+    \x10\x01\x12\x18\n\x14REACTION_TYPE_RECAST\x10\x02\x1a\0*8\n\x08Protocol\
+    \x12\x15\n\x11PROTOCOL_ETHEREUM\x10\0\x12\x13\n\x0fPROTOCOL_SOLANA\x10\
+    \x01\x1a\0*Q\n\x0fStorageUnitType\x12\x14\n\x10UNIT_TYPE_LEGACY\x10\0\
+    \x12\x12\n\x0eUNIT_TYPE_2024\x10\x01\x12\x12\n\x0eUNIT_TYPE_2025\x10\x02\
+    \x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
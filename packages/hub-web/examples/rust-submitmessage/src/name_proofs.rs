@@ -0,0 +1,232 @@
+//! Username proofs for fnames and ENS names, mirroring packages/core/src/userNameProof.ts.
+//!
+//! The fname server signs a `UserNameProof` (name, timestamp, owner) as EIP-712 typed data when an
+//! fname is registered or transferred. ENS proofs are checked by hubs against the chain and are
+//! gossiped as `MESSAGE_TYPE_USERNAME_PROOF` messages.
+
+use crate::bodies::UsernameProofBuilder;
+use crate::eip712::{
+    encode_address, encode_string, encode_uint, hash_struct, hash_typed_data, Eip712Domain,
+};
+use crate::eth::{recover_address, Address, EthResult, EthWallet};
+use crate::farcaster_time::to_farcaster_time;
+use crate::message::FarcasterNetwork;
+use crate::username_proof::{UserNameProof, UserNameType};
+use crate::validations::{validate_fname, validate_username_proof_body, ValidationError};
+use crate::MessageBuilder;
+
+pub const EIP_712_USERNAME_PROOF: &str =
+    "UserNameProof(string name,uint256 timestamp,address owner)";
+
+/// The address the fname server signs proofs with.
+pub const FNAME_SERVER_SIGNER: Address = [
+    0xbc, 0x52, 0x74, 0xef, 0xc2, 0x66, 0x31, 0x10, 0x15, 0x79, 0x3d, 0x89, 0xe9, 0xb5, 0x91, 0xfa,
+    0x46, 0x29, 0x47, 0x41,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum NameProofError {
+    #[error(transparent)]
+    Invalid(#[from] ValidationError),
+    #[error("expected a {expected:?} proof")]
+    InvalidUsernameType { expected: UserNameType },
+    #[error("owner must be 20 bytes")]
+    InvalidOwner,
+    #[error("invalid username proof signature")]
+    InvalidSignature,
+}
+
+pub type NameProofResult<T = ()> = Result<T, NameProofError>;
+
+/// The typed data signed for a username proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserNameProofClaim {
+    pub name: String,
+    /// Unix timestamp of the proof in seconds.
+    pub timestamp: u64,
+    pub owner: Address,
+}
+
+impl UserNameProofClaim {
+    pub fn new(name: impl Into<String>, timestamp: u64, owner: Address) -> Self {
+        Self {
+            name: name.into(),
+            timestamp,
+            owner,
+        }
+    }
+
+    /// Rebuilds the claim a proof was signed over.
+    pub fn from_proof(proof: &UserNameProof) -> NameProofResult<Self> {
+        let name = String::from_utf8(proof.get_name().to_vec()).map_err(|err| {
+            ValidationError::InvalidFname(String::from_utf8_lossy(err.as_bytes()).into())
+        })?;
+        let owner =
+            Address::try_from(proof.get_owner()).map_err(|_| NameProofError::InvalidOwner)?;
+        Ok(Self::new(name, proof.get_timestamp(), owner))
+    }
+
+    pub fn struct_hash(&self) -> [u8; 32] {
+        hash_struct(
+            EIP_712_USERNAME_PROOF,
+            &[
+                encode_string(&self.name),
+                encode_uint(self.timestamp),
+                encode_address(&self.owner),
+            ],
+        )
+    }
+
+    pub fn digest(&self) -> [u8; 32] {
+        hash_typed_data(&Eip712Domain::username(), &self.struct_hash())
+    }
+
+    /// Signs the claim as the fname server would and returns the proof for `fid`.
+    pub fn sign(
+        &self,
+        wallet: &EthWallet,
+        fid: u64,
+        name_type: UserNameType,
+    ) -> EthResult<UserNameProof> {
+        let mut proof = UserNameProof::new();
+        proof.set_name(self.name.as_bytes().to_vec());
+        proof.set_timestamp(self.timestamp);
+        proof.set_owner(self.owner.to_vec());
+        proof.set_signature(wallet.sign_hash(&self.digest())?.to_vec());
+        proof.set_fid(fid);
+        proof.set_field_type(name_type);
+        Ok(proof)
+    }
+}
+
+/// Checks that an fname proof has a valid name and was signed by `signer`, which is normally
+/// [`FNAME_SERVER_SIGNER`].
+pub fn verify_fname_proof(proof: &UserNameProof, signer: &Address) -> NameProofResult {
+    if proof.get_field_type() != UserNameType::USERNAME_TYPE_FNAME {
+        return Err(NameProofError::InvalidUsernameType {
+            expected: UserNameType::USERNAME_TYPE_FNAME,
+        });
+    }
+    let claim = UserNameProofClaim::from_proof(proof)?;
+    validate_fname(&claim.name)?;
+    match recover_address(&claim.digest(), proof.get_signature()) {
+        Ok(recovered) if recovered == *signer => Ok(()),
+        _ => Err(NameProofError::InvalidSignature),
+    }
+}
+
+/// Wraps an ENS proof in a `MESSAGE_TYPE_USERNAME_PROOF` message from the proof's fid, timestamped
+/// at the proof's timestamp as hubs require.
+pub fn username_proof_message(
+    proof: UserNameProof,
+    network: FarcasterNetwork,
+) -> NameProofResult<MessageBuilder> {
+    let timestamp = to_farcaster_time(proof.get_timestamp().saturating_mul(1000))
+        .map_err(ValidationError::from)?;
+    let builder = MessageBuilder::new(proof.get_fid(), network, UsernameProofBuilder::new(proof));
+    let mut data = builder.data().clone();
+    data.set_timestamp(timestamp);
+    validate_username_proof_body(data.get_username_proof_body(), &data)?;
+    Ok(builder.timestamp(timestamp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::wallet::TEST_KEY;
+    use crate::test_utils::test_signer;
+    use crate::validations::{message_data, validate_message_data_at};
+
+    fn fname_proof() -> UserNameProof {
+        let mut proof = UserNameProof::new();
+        proof.set_name(b"farcaster".to_vec());
+        proof.set_timestamp(1628882891);
+        proof.set_owner(hex::decode("8773442740c17c9d0f0b87022c722f9a136206ed").unwrap());
+        proof.set_signature(
+            hex::decode(
+                "b7181760f14eda0028e0b647ff15f45235526ced3b4ae07fcce06141b73d3296\
+                 0d3253776e62f761363fb8137087192047763f4af838950a96f3885f3c2289c41b",
+            )
+            .unwrap(),
+        );
+        proof.set_fid(1);
+        proof.set_field_type(UserNameType::USERNAME_TYPE_FNAME);
+        proof
+    }
+
+    #[test]
+    fn verifies_fname_server_signatures() {
+        let proof = fname_proof();
+        verify_fname_proof(&proof, &FNAME_SERVER_SIGNER).unwrap();
+        assert!(matches!(
+            verify_fname_proof(&proof, &[1; 20]),
+            Err(NameProofError::InvalidSignature)
+        ));
+
+        let mut other = proof.clone();
+        other.set_timestamp(1628882892);
+        assert!(matches!(
+            verify_fname_proof(&other, &FNAME_SERVER_SIGNER),
+            Err(NameProofError::InvalidSignature)
+        ));
+
+        let wallet = EthWallet::from_bytes(&[9u8; 32]).unwrap();
+        let proof = UserNameProofClaim::new("alice", 1700000000, [2; 20])
+            .sign(&wallet, 2, UserNameType::USERNAME_TYPE_FNAME)
+            .unwrap();
+        verify_fname_proof(&proof, &wallet.address()).unwrap();
+    }
+
+    #[test]
+    fn matches_username_proof_vectors() {
+        let proof = fname_proof();
+        let claim = UserNameProofClaim::from_proof(&proof).unwrap();
+        assert_eq!(
+            hex::encode(claim.digest()),
+            "047687db9e657ae0fd622624d8a474de66a694a0e6ee238bc57e47cee6970514"
+        );
+        assert_eq!(
+            recover_address(&claim.digest(), proof.get_signature()).unwrap(),
+            FNAME_SERVER_SIGNER
+        );
+
+        let wallet = EthWallet::from_hex(TEST_KEY).unwrap();
+        let signed = claim
+            .sign(&wallet, 1, UserNameType::USERNAME_TYPE_FNAME)
+            .unwrap();
+        assert_eq!(
+            hex::encode(signed.get_signature()),
+            "01614b973af70aba7bdc226fdc1a335097525422309dc5d9d3df5446470ca927\
+             6256fde4265b32e130cb6aeb21390bf4a80065b7db88c72e8783d60d5db852ce1c"
+        );
+    }
+
+    #[tokio::test]
+    async fn wraps_ens_proofs_in_messages() {
+        let wallet = EthWallet::from_bytes(&[9u8; 32]).unwrap();
+        let timestamp = 1700000000;
+        let proof = UserNameProofClaim::new("alice.eth", timestamp, wallet.address())
+            .sign(&wallet, 2, UserNameType::USERNAME_TYPE_ENS_L1)
+            .unwrap();
+        let network = FarcasterNetwork::FARCASTER_NETWORK_MAINNET;
+        let message = username_proof_message(proof, network)
+            .unwrap()
+            .build(&test_signer())
+            .await
+            .unwrap();
+        let data = &message_data(&message).unwrap();
+        assert_eq!(data.get_fid(), 2);
+        assert_eq!(
+            data.get_timestamp(),
+            to_farcaster_time(timestamp * 1000).unwrap()
+        );
+        validate_message_data_at(data, data.get_timestamp()).unwrap();
+
+        assert!(matches!(
+            username_proof_message(fname_proof(), network),
+            Err(NameProofError::Invalid(
+                ValidationError::InvalidUsernameType
+            ))
+        ));
+    }
+}
@@ -0,0 +1,2175 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `replication.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetShardSnapshotMetadataRequest {
+    // message fields
+    pub shard_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetShardSnapshotMetadataRequest {
+    fn default() -> &'a GetShardSnapshotMetadataRequest {
+        <GetShardSnapshotMetadataRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetShardSnapshotMetadataRequest {
+    pub fn new() -> GetShardSnapshotMetadataRequest {
+        ::std::default::Default::default()
+    }
+
+    // uint32 shard_id = 1;
+
+
+    pub fn get_shard_id(&self) -> u32 {
+        self.shard_id
+    }
+    pub fn clear_shard_id(&mut self) {
+        self.shard_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shard_id(&mut self, v: u32) {
+        self.shard_id = v;
+    }
+}
+
+impl ::protobuf::Message for GetShardSnapshotMetadataRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.shard_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.shard_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.shard_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.shard_id != 0 {
+            os.write_uint32(1, self.shard_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetShardSnapshotMetadataRequest {
+        GetShardSnapshotMetadataRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "shard_id",
+                |m: &GetShardSnapshotMetadataRequest| { &m.shard_id },
+                |m: &mut GetShardSnapshotMetadataRequest| { &mut m.shard_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetShardSnapshotMetadataRequest>(
+                "GetShardSnapshotMetadataRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetShardSnapshotMetadataRequest {
+        static instance: ::protobuf::rt::LazyV2<GetShardSnapshotMetadataRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetShardSnapshotMetadataRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for GetShardSnapshotMetadataRequest {
+    fn clear(&mut self) {
+        self.shard_id = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetShardSnapshotMetadataRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetShardSnapshotMetadataRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ShardSnapshotMetadata {
+    // message fields
+    pub shard_id: u32,
+    pub height: u64,
+    pub timestamp: u64,
+    pub shard_chunk: ::protobuf::SingularPtrField<super::blocks::ShardChunk>,
+    pub block: ::protobuf::SingularPtrField<super::blocks::Block>,
+    pub num_items: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShardSnapshotMetadata {
+    fn default() -> &'a ShardSnapshotMetadata {
+        <ShardSnapshotMetadata as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShardSnapshotMetadata {
+    pub fn new() -> ShardSnapshotMetadata {
+        ::std::default::Default::default()
+    }
+
+    // uint32 shard_id = 1;
+
+
+    pub fn get_shard_id(&self) -> u32 {
+        self.shard_id
+    }
+    pub fn clear_shard_id(&mut self) {
+        self.shard_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shard_id(&mut self, v: u32) {
+        self.shard_id = v;
+    }
+
+    // uint64 height = 2;
+
+
+    pub fn get_height(&self) -> u64 {
+        self.height
+    }
+    pub fn clear_height(&mut self) {
+        self.height = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_height(&mut self, v: u64) {
+        self.height = v;
+    }
+
+    // uint64 timestamp = 3;
+
+
+    pub fn get_timestamp(&self) -> u64 {
+        self.timestamp
+    }
+    pub fn clear_timestamp(&mut self) {
+        self.timestamp = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_timestamp(&mut self, v: u64) {
+        self.timestamp = v;
+    }
+
+    // .ShardChunk shard_chunk = 5;
+
+
+    pub fn get_shard_chunk(&self) -> &super::blocks::ShardChunk {
+        self.shard_chunk.as_ref().unwrap_or_else(|| <super::blocks::ShardChunk as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_shard_chunk(&mut self) {
+        self.shard_chunk.clear();
+    }
+
+    pub fn has_shard_chunk(&self) -> bool {
+        self.shard_chunk.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shard_chunk(&mut self, v: super::blocks::ShardChunk) {
+        self.shard_chunk = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_shard_chunk(&mut self) -> &mut super::blocks::ShardChunk {
+        if self.shard_chunk.is_none() {
+            self.shard_chunk.set_default();
+        }
+        self.shard_chunk.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_shard_chunk(&mut self) -> super::blocks::ShardChunk {
+        self.shard_chunk.take().unwrap_or_else(|| super::blocks::ShardChunk::new())
+    }
+
+    // .Block block = 6;
+
+
+    pub fn get_block(&self) -> &super::blocks::Block {
+        self.block.as_ref().unwrap_or_else(|| <super::blocks::Block as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_block(&mut self) {
+        self.block.clear();
+    }
+
+    pub fn has_block(&self) -> bool {
+        self.block.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block(&mut self, v: super::blocks::Block) {
+        self.block = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_block(&mut self) -> &mut super::blocks::Block {
+        if self.block.is_none() {
+            self.block.set_default();
+        }
+        self.block.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_block(&mut self) -> super::blocks::Block {
+        self.block.take().unwrap_or_else(|| super::blocks::Block::new())
+    }
+
+    // uint64 num_items = 7;
+
+
+    pub fn get_num_items(&self) -> u64 {
+        self.num_items
+    }
+    pub fn clear_num_items(&mut self) {
+        self.num_items = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_num_items(&mut self, v: u64) {
+        self.num_items = v;
+    }
+}
+
+impl ::protobuf::Message for ShardSnapshotMetadata {
+    fn is_initialized(&self) -> bool {
+        for v in &self.shard_chunk {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.block {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.shard_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.height = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.timestamp = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.shard_chunk)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.block)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.num_items = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.shard_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.shard_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.timestamp != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.timestamp, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.shard_chunk.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.block.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.num_items != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.num_items, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.shard_id != 0 {
+            os.write_uint32(1, self.shard_id)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if self.timestamp != 0 {
+            os.write_uint64(3, self.timestamp)?;
+        }
+        if let Some(ref v) = self.shard_chunk.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.block.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.num_items != 0 {
+            os.write_uint64(7, self.num_items)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShardSnapshotMetadata {
+        ShardSnapshotMetadata::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "shard_id",
+                |m: &ShardSnapshotMetadata| { &m.shard_id },
+                |m: &mut ShardSnapshotMetadata| { &mut m.shard_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "height",
+                |m: &ShardSnapshotMetadata| { &m.height },
+                |m: &mut ShardSnapshotMetadata| { &mut m.height },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "timestamp",
+                |m: &ShardSnapshotMetadata| { &m.timestamp },
+                |m: &mut ShardSnapshotMetadata| { &mut m.timestamp },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::blocks::ShardChunk>>(
+                "shard_chunk",
+                |m: &ShardSnapshotMetadata| { &m.shard_chunk },
+                |m: &mut ShardSnapshotMetadata| { &mut m.shard_chunk },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::blocks::Block>>(
+                "block",
+                |m: &ShardSnapshotMetadata| { &m.block },
+                |m: &mut ShardSnapshotMetadata| { &mut m.block },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "num_items",
+                |m: &ShardSnapshotMetadata| { &m.num_items },
+                |m: &mut ShardSnapshotMetadata| { &mut m.num_items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShardSnapshotMetadata>(
+                "ShardSnapshotMetadata",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShardSnapshotMetadata {
+        static instance: ::protobuf::rt::LazyV2<ShardSnapshotMetadata> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShardSnapshotMetadata::new)
+    }
+}
+
+impl ::protobuf::Clear for ShardSnapshotMetadata {
+    fn clear(&mut self) {
+        self.shard_id = 0;
+        self.height = 0;
+        self.timestamp = 0;
+        self.shard_chunk.clear();
+        self.block.clear();
+        self.num_items = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShardSnapshotMetadata {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShardSnapshotMetadata {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetShardSnapshotMetadataResponse {
+    // message fields
+    pub snapshots: ::protobuf::RepeatedField<ShardSnapshotMetadata>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetShardSnapshotMetadataResponse {
+    fn default() -> &'a GetShardSnapshotMetadataResponse {
+        <GetShardSnapshotMetadataResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetShardSnapshotMetadataResponse {
+    pub fn new() -> GetShardSnapshotMetadataResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ShardSnapshotMetadata snapshots = 1;
+
+
+    pub fn get_snapshots(&self) -> &[ShardSnapshotMetadata] {
+        &self.snapshots
+    }
+    pub fn clear_snapshots(&mut self) {
+        self.snapshots.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snapshots(&mut self, v: ::protobuf::RepeatedField<ShardSnapshotMetadata>) {
+        self.snapshots = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_snapshots(&mut self) -> &mut ::protobuf::RepeatedField<ShardSnapshotMetadata> {
+        &mut self.snapshots
+    }
+
+    // Take field
+    pub fn take_snapshots(&mut self) -> ::protobuf::RepeatedField<ShardSnapshotMetadata> {
+        ::std::mem::replace(&mut self.snapshots, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for GetShardSnapshotMetadataResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.snapshots {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.snapshots)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.snapshots {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.snapshots {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetShardSnapshotMetadataResponse {
+        GetShardSnapshotMetadataResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShardSnapshotMetadata>>(
+                "snapshots",
+                |m: &GetShardSnapshotMetadataResponse| { &m.snapshots },
+                |m: &mut GetShardSnapshotMetadataResponse| { &mut m.snapshots },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetShardSnapshotMetadataResponse>(
+                "GetShardSnapshotMetadataResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetShardSnapshotMetadataResponse {
+        static instance: ::protobuf::rt::LazyV2<GetShardSnapshotMetadataResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetShardSnapshotMetadataResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for GetShardSnapshotMetadataResponse {
+    fn clear(&mut self) {
+        self.snapshots.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetShardSnapshotMetadataResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetShardSnapshotMetadataResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetShardTransactionsRequest {
+    // message fields
+    pub shard_id: u32,
+    pub height: u64,
+    pub trie_virtual_shard: u32,
+    pub page_token: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetShardTransactionsRequest {
+    fn default() -> &'a GetShardTransactionsRequest {
+        <GetShardTransactionsRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetShardTransactionsRequest {
+    pub fn new() -> GetShardTransactionsRequest {
+        ::std::default::Default::default()
+    }
+
+    // uint32 shard_id = 1;
+
+
+    pub fn get_shard_id(&self) -> u32 {
+        self.shard_id
+    }
+    pub fn clear_shard_id(&mut self) {
+        self.shard_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shard_id(&mut self, v: u32) {
+        self.shard_id = v;
+    }
+
+    // uint64 height = 2;
+
+
+    pub fn get_height(&self) -> u64 {
+        self.height
+    }
+    pub fn clear_height(&mut self) {
+        self.height = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_height(&mut self, v: u64) {
+        self.height = v;
+    }
+
+    // uint32 trie_virtual_shard = 3;
+
+
+    pub fn get_trie_virtual_shard(&self) -> u32 {
+        self.trie_virtual_shard
+    }
+    pub fn clear_trie_virtual_shard(&mut self) {
+        self.trie_virtual_shard = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_trie_virtual_shard(&mut self, v: u32) {
+        self.trie_virtual_shard = v;
+    }
+
+    // string page_token = 4;
+
+
+    pub fn get_page_token(&self) -> &str {
+        &self.page_token
+    }
+    pub fn clear_page_token(&mut self) {
+        self.page_token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_page_token(&mut self, v: ::std::string::String) {
+        self.page_token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_page_token(&mut self) -> &mut ::std::string::String {
+        &mut self.page_token
+    }
+
+    // Take field
+    pub fn take_page_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.page_token, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GetShardTransactionsRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.shard_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.height = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.trie_virtual_shard = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.page_token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.shard_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.shard_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.trie_virtual_shard != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.trie_virtual_shard, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.page_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.shard_id != 0 {
+            os.write_uint32(1, self.shard_id)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if self.trie_virtual_shard != 0 {
+            os.write_uint32(3, self.trie_virtual_shard)?;
+        }
+        if !self.page_token.is_empty() {
+            os.write_string(4, &self.page_token)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetShardTransactionsRequest {
+        GetShardTransactionsRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "shard_id",
+                |m: &GetShardTransactionsRequest| { &m.shard_id },
+                |m: &mut GetShardTransactionsRequest| { &mut m.shard_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "height",
+                |m: &GetShardTransactionsRequest| { &m.height },
+                |m: &mut GetShardTransactionsRequest| { &mut m.height },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "trie_virtual_shard",
+                |m: &GetShardTransactionsRequest| { &m.trie_virtual_shard },
+                |m: &mut GetShardTransactionsRequest| { &mut m.trie_virtual_shard },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "page_token",
+                |m: &GetShardTransactionsRequest| { &m.page_token },
+                |m: &mut GetShardTransactionsRequest| { &mut m.page_token },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetShardTransactionsRequest>(
+                "GetShardTransactionsRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetShardTransactionsRequest {
+        static instance: ::protobuf::rt::LazyV2<GetShardTransactionsRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetShardTransactionsRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for GetShardTransactionsRequest {
+    fn clear(&mut self) {
+        self.shard_id = 0;
+        self.height = 0;
+        self.trie_virtual_shard = 0;
+        self.page_token.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetShardTransactionsRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetShardTransactionsRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GetShardTransactionsResponse {
+    // message fields
+    pub trie_messages: ::protobuf::RepeatedField<ShardTrieEntryWithMessage>,
+    pub fid_account_roots: ::protobuf::RepeatedField<FidAccountRootHash>,
+    pub next_page_token: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GetShardTransactionsResponse {
+    fn default() -> &'a GetShardTransactionsResponse {
+        <GetShardTransactionsResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GetShardTransactionsResponse {
+    pub fn new() -> GetShardTransactionsResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ShardTrieEntryWithMessage trie_messages = 1;
+
+
+    pub fn get_trie_messages(&self) -> &[ShardTrieEntryWithMessage] {
+        &self.trie_messages
+    }
+    pub fn clear_trie_messages(&mut self) {
+        self.trie_messages.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_trie_messages(&mut self, v: ::protobuf::RepeatedField<ShardTrieEntryWithMessage>) {
+        self.trie_messages = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_trie_messages(&mut self) -> &mut ::protobuf::RepeatedField<ShardTrieEntryWithMessage> {
+        &mut self.trie_messages
+    }
+
+    // Take field
+    pub fn take_trie_messages(&mut self) -> ::protobuf::RepeatedField<ShardTrieEntryWithMessage> {
+        ::std::mem::replace(&mut self.trie_messages, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .FidAccountRootHash fid_account_roots = 2;
+
+
+    pub fn get_fid_account_roots(&self) -> &[FidAccountRootHash] {
+        &self.fid_account_roots
+    }
+    pub fn clear_fid_account_roots(&mut self) {
+        self.fid_account_roots.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fid_account_roots(&mut self, v: ::protobuf::RepeatedField<FidAccountRootHash>) {
+        self.fid_account_roots = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_fid_account_roots(&mut self) -> &mut ::protobuf::RepeatedField<FidAccountRootHash> {
+        &mut self.fid_account_roots
+    }
+
+    // Take field
+    pub fn take_fid_account_roots(&mut self) -> ::protobuf::RepeatedField<FidAccountRootHash> {
+        ::std::mem::replace(&mut self.fid_account_roots, ::protobuf::RepeatedField::new())
+    }
+
+    // string next_page_token = 3;
+
+
+    pub fn get_next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    pub fn clear_next_page_token(&mut self) {
+        self.next_page_token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_page_token(&mut self, v: ::std::string::String) {
+        self.next_page_token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_next_page_token(&mut self) -> &mut ::std::string::String {
+        &mut self.next_page_token
+    }
+
+    // Take field
+    pub fn take_next_page_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.next_page_token, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GetShardTransactionsResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.trie_messages {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.fid_account_roots {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.trie_messages)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.fid_account_roots)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.next_page_token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.trie_messages {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.fid_account_roots {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.next_page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.next_page_token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.trie_messages {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.fid_account_roots {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.next_page_token.is_empty() {
+            os.write_string(3, &self.next_page_token)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GetShardTransactionsResponse {
+        GetShardTransactionsResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShardTrieEntryWithMessage>>(
+                "trie_messages",
+                |m: &GetShardTransactionsResponse| { &m.trie_messages },
+                |m: &mut GetShardTransactionsResponse| { &mut m.trie_messages },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FidAccountRootHash>>(
+                "fid_account_roots",
+                |m: &GetShardTransactionsResponse| { &m.fid_account_roots },
+                |m: &mut GetShardTransactionsResponse| { &mut m.fid_account_roots },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "next_page_token",
+                |m: &GetShardTransactionsResponse| { &m.next_page_token },
+                |m: &mut GetShardTransactionsResponse| { &mut m.next_page_token },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GetShardTransactionsResponse>(
+                "GetShardTransactionsResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GetShardTransactionsResponse {
+        static instance: ::protobuf::rt::LazyV2<GetShardTransactionsResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GetShardTransactionsResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for GetShardTransactionsResponse {
+    fn clear(&mut self) {
+        self.trie_messages.clear();
+        self.fid_account_roots.clear();
+        self.next_page_token.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GetShardTransactionsResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GetShardTransactionsResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ShardTrieEntryWithMessage {
+    // message fields
+    pub trie_key: ::std::vec::Vec<u8>,
+    pub user_message: ::protobuf::SingularPtrField<super::message::Message>,
+    pub on_chain_event: ::protobuf::SingularPtrField<super::onchain_event::OnChainEvent>,
+    pub fname_transfer: ::protobuf::SingularPtrField<super::blocks::FnameTransfer>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShardTrieEntryWithMessage {
+    fn default() -> &'a ShardTrieEntryWithMessage {
+        <ShardTrieEntryWithMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShardTrieEntryWithMessage {
+    pub fn new() -> ShardTrieEntryWithMessage {
+        ::std::default::Default::default()
+    }
+
+    // bytes trie_key = 1;
+
+
+    pub fn get_trie_key(&self) -> &[u8] {
+        &self.trie_key
+    }
+    pub fn clear_trie_key(&mut self) {
+        self.trie_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_trie_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.trie_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_trie_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.trie_key
+    }
+
+    // Take field
+    pub fn take_trie_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.trie_key, ::std::vec::Vec::new())
+    }
+
+    // .Message user_message = 2;
+
+
+    pub fn get_user_message(&self) -> &super::message::Message {
+        self.user_message.as_ref().unwrap_or_else(|| <super::message::Message as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_user_message(&mut self) {
+        self.user_message.clear();
+    }
+
+    pub fn has_user_message(&self) -> bool {
+        self.user_message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_message(&mut self, v: super::message::Message) {
+        self.user_message = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_message(&mut self) -> &mut super::message::Message {
+        if self.user_message.is_none() {
+            self.user_message.set_default();
+        }
+        self.user_message.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_user_message(&mut self) -> super::message::Message {
+        self.user_message.take().unwrap_or_else(|| super::message::Message::new())
+    }
+
+    // .OnChainEvent on_chain_event = 3;
+
+
+    pub fn get_on_chain_event(&self) -> &super::onchain_event::OnChainEvent {
+        self.on_chain_event.as_ref().unwrap_or_else(|| <super::onchain_event::OnChainEvent as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_on_chain_event(&mut self) {
+        self.on_chain_event.clear();
+    }
+
+    pub fn has_on_chain_event(&self) -> bool {
+        self.on_chain_event.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_on_chain_event(&mut self, v: super::onchain_event::OnChainEvent) {
+        self.on_chain_event = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_on_chain_event(&mut self) -> &mut super::onchain_event::OnChainEvent {
+        if self.on_chain_event.is_none() {
+            self.on_chain_event.set_default();
+        }
+        self.on_chain_event.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_on_chain_event(&mut self) -> super::onchain_event::OnChainEvent {
+        self.on_chain_event.take().unwrap_or_else(|| super::onchain_event::OnChainEvent::new())
+    }
+
+    // .FnameTransfer fname_transfer = 4;
+
+
+    pub fn get_fname_transfer(&self) -> &super::blocks::FnameTransfer {
+        self.fname_transfer.as_ref().unwrap_or_else(|| <super::blocks::FnameTransfer as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_fname_transfer(&mut self) {
+        self.fname_transfer.clear();
+    }
+
+    pub fn has_fname_transfer(&self) -> bool {
+        self.fname_transfer.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fname_transfer(&mut self, v: super::blocks::FnameTransfer) {
+        self.fname_transfer = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_fname_transfer(&mut self) -> &mut super::blocks::FnameTransfer {
+        if self.fname_transfer.is_none() {
+            self.fname_transfer.set_default();
+        }
+        self.fname_transfer.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_fname_transfer(&mut self) -> super::blocks::FnameTransfer {
+        self.fname_transfer.take().unwrap_or_else(|| super::blocks::FnameTransfer::new())
+    }
+}
+
+impl ::protobuf::Message for ShardTrieEntryWithMessage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.user_message {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.on_chain_event {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.fname_transfer {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.trie_key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.user_message)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.on_chain_event)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.fname_transfer)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.trie_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.trie_key);
+        }
+        if let Some(ref v) = self.user_message.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.on_chain_event.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.fname_transfer.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.trie_key.is_empty() {
+            os.write_bytes(1, &self.trie_key)?;
+        }
+        if let Some(ref v) = self.user_message.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.on_chain_event.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.fname_transfer.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShardTrieEntryWithMessage {
+        ShardTrieEntryWithMessage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "trie_key",
+                |m: &ShardTrieEntryWithMessage| { &m.trie_key },
+                |m: &mut ShardTrieEntryWithMessage| { &mut m.trie_key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::message::Message>>(
+                "user_message",
+                |m: &ShardTrieEntryWithMessage| { &m.user_message },
+                |m: &mut ShardTrieEntryWithMessage| { &mut m.user_message },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::onchain_event::OnChainEvent>>(
+                "on_chain_event",
+                |m: &ShardTrieEntryWithMessage| { &m.on_chain_event },
+                |m: &mut ShardTrieEntryWithMessage| { &mut m.on_chain_event },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::blocks::FnameTransfer>>(
+                "fname_transfer",
+                |m: &ShardTrieEntryWithMessage| { &m.fname_transfer },
+                |m: &mut ShardTrieEntryWithMessage| { &mut m.fname_transfer },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShardTrieEntryWithMessage>(
+                "ShardTrieEntryWithMessage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShardTrieEntryWithMessage {
+        static instance: ::protobuf::rt::LazyV2<ShardTrieEntryWithMessage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShardTrieEntryWithMessage::new)
+    }
+}
+
+impl ::protobuf::Clear for ShardTrieEntryWithMessage {
+    fn clear(&mut self) {
+        self.trie_key.clear();
+        self.user_message.clear();
+        self.on_chain_event.clear();
+        self.fname_transfer.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShardTrieEntryWithMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShardTrieEntryWithMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct FidAccountRootHash {
+    // message fields
+    pub fid: u64,
+    pub account_root_hash: ::std::vec::Vec<u8>,
+    pub num_messages: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a FidAccountRootHash {
+    fn default() -> &'a FidAccountRootHash {
+        <FidAccountRootHash as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl FidAccountRootHash {
+    pub fn new() -> FidAccountRootHash {
+        ::std::default::Default::default()
+    }
+
+    // uint64 fid = 1;
+
+
+    pub fn get_fid(&self) -> u64 {
+        self.fid
+    }
+    pub fn clear_fid(&mut self) {
+        self.fid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_fid(&mut self, v: u64) {
+        self.fid = v;
+    }
+
+    // bytes account_root_hash = 2;
+
+
+    pub fn get_account_root_hash(&self) -> &[u8] {
+        &self.account_root_hash
+    }
+    pub fn clear_account_root_hash(&mut self) {
+        self.account_root_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_root_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.account_root_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_account_root_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.account_root_hash
+    }
+
+    // Take field
+    pub fn take_account_root_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.account_root_hash, ::std::vec::Vec::new())
+    }
+
+    // uint64 num_messages = 3;
+
+
+    pub fn get_num_messages(&self) -> u64 {
+        self.num_messages
+    }
+    pub fn clear_num_messages(&mut self) {
+        self.num_messages = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_num_messages(&mut self, v: u64) {
+        self.num_messages = v;
+    }
+}
+
+impl ::protobuf::Message for FidAccountRootHash {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.fid = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.account_root_hash)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.num_messages = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.fid != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.fid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.account_root_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.account_root_hash);
+        }
+        if self.num_messages != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.num_messages, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.fid != 0 {
+            os.write_uint64(1, self.fid)?;
+        }
+        if !self.account_root_hash.is_empty() {
+            os.write_bytes(2, &self.account_root_hash)?;
+        }
+        if self.num_messages != 0 {
+            os.write_uint64(3, self.num_messages)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> FidAccountRootHash {
+        FidAccountRootHash::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "fid",
+                |m: &FidAccountRootHash| { &m.fid },
+                |m: &mut FidAccountRootHash| { &mut m.fid },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "account_root_hash",
+                |m: &FidAccountRootHash| { &m.account_root_hash },
+                |m: &mut FidAccountRootHash| { &mut m.account_root_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "num_messages",
+                |m: &FidAccountRootHash| { &m.num_messages },
+                |m: &mut FidAccountRootHash| { &mut m.num_messages },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<FidAccountRootHash>(
+                "FidAccountRootHash",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static FidAccountRootHash {
+        static instance: ::protobuf::rt::LazyV2<FidAccountRootHash> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(FidAccountRootHash::new)
+    }
+}
+
+impl ::protobuf::Clear for FidAccountRootHash {
+    fn clear(&mut self) {
+        self.fid = 0;
+        self.account_root_hash.clear();
+        self.num_messages = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for FidAccountRootHash {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for FidAccountRootHash {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReplicationTriePartStatus {
+    // message fields
+    pub shard_id: u32,
+    pub height: u64,
+    pub virtual_trie_shard: u32,
+    pub next_page_token: ::std::string::String,
+    pub last_response: u32,
+    pub last_fid: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReplicationTriePartStatus {
+    fn default() -> &'a ReplicationTriePartStatus {
+        <ReplicationTriePartStatus as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReplicationTriePartStatus {
+    pub fn new() -> ReplicationTriePartStatus {
+        ::std::default::Default::default()
+    }
+
+    // uint32 shard_id = 1;
+
+
+    pub fn get_shard_id(&self) -> u32 {
+        self.shard_id
+    }
+    pub fn clear_shard_id(&mut self) {
+        self.shard_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shard_id(&mut self, v: u32) {
+        self.shard_id = v;
+    }
+
+    // uint64 height = 2;
+
+
+    pub fn get_height(&self) -> u64 {
+        self.height
+    }
+    pub fn clear_height(&mut self) {
+        self.height = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_height(&mut self, v: u64) {
+        self.height = v;
+    }
+
+    // uint32 virtual_trie_shard = 3;
+
+
+    pub fn get_virtual_trie_shard(&self) -> u32 {
+        self.virtual_trie_shard
+    }
+    pub fn clear_virtual_trie_shard(&mut self) {
+        self.virtual_trie_shard = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_virtual_trie_shard(&mut self, v: u32) {
+        self.virtual_trie_shard = v;
+    }
+
+    // string next_page_token = 4;
+
+
+    pub fn get_next_page_token(&self) -> &str {
+        &self.next_page_token
+    }
+    pub fn clear_next_page_token(&mut self) {
+        self.next_page_token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_page_token(&mut self, v: ::std::string::String) {
+        self.next_page_token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_next_page_token(&mut self) -> &mut ::std::string::String {
+        &mut self.next_page_token
+    }
+
+    // Take field
+    pub fn take_next_page_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.next_page_token, ::std::string::String::new())
+    }
+
+    // uint32 last_response = 5;
+
+
+    pub fn get_last_response(&self) -> u32 {
+        self.last_response
+    }
+    pub fn clear_last_response(&mut self) {
+        self.last_response = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_response(&mut self, v: u32) {
+        self.last_response = v;
+    }
+
+    // uint64 last_fid = 6;
+
+
+    pub fn get_last_fid(&self) -> u64 {
+        self.last_fid
+    }
+    pub fn clear_last_fid(&mut self) {
+        self.last_fid = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_fid(&mut self, v: u64) {
+        self.last_fid = v;
+    }
+}
+
+impl ::protobuf::Message for ReplicationTriePartStatus {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.shard_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.height = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.virtual_trie_shard = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.next_page_token)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.last_response = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.last_fid = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.shard_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.shard_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.height != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.height, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.virtual_trie_shard != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.virtual_trie_shard, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.next_page_token.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.next_page_token);
+        }
+        if self.last_response != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.last_response, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.last_fid != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.last_fid, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.shard_id != 0 {
+            os.write_uint32(1, self.shard_id)?;
+        }
+        if self.height != 0 {
+            os.write_uint64(2, self.height)?;
+        }
+        if self.virtual_trie_shard != 0 {
+            os.write_uint32(3, self.virtual_trie_shard)?;
+        }
+        if !self.next_page_token.is_empty() {
+            os.write_string(4, &self.next_page_token)?;
+        }
+        if self.last_response != 0 {
+            os.write_uint32(5, self.last_response)?;
+        }
+        if self.last_fid != 0 {
+            os.write_uint64(6, self.last_fid)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReplicationTriePartStatus {
+        ReplicationTriePartStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "shard_id",
+                |m: &ReplicationTriePartStatus| { &m.shard_id },
+                |m: &mut ReplicationTriePartStatus| { &mut m.shard_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "height",
+                |m: &ReplicationTriePartStatus| { &m.height },
+                |m: &mut ReplicationTriePartStatus| { &mut m.height },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "virtual_trie_shard",
+                |m: &ReplicationTriePartStatus| { &m.virtual_trie_shard },
+                |m: &mut ReplicationTriePartStatus| { &mut m.virtual_trie_shard },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "next_page_token",
+                |m: &ReplicationTriePartStatus| { &m.next_page_token },
+                |m: &mut ReplicationTriePartStatus| { &mut m.next_page_token },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "last_response",
+                |m: &ReplicationTriePartStatus| { &m.last_response },
+                |m: &mut ReplicationTriePartStatus| { &mut m.last_response },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "last_fid",
+                |m: &ReplicationTriePartStatus| { &m.last_fid },
+                |m: &mut ReplicationTriePartStatus| { &mut m.last_fid },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReplicationTriePartStatus>(
+                "ReplicationTriePartStatus",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReplicationTriePartStatus {
+        static instance: ::protobuf::rt::LazyV2<ReplicationTriePartStatus> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReplicationTriePartStatus::new)
+    }
+}
+
+impl ::protobuf::Clear for ReplicationTriePartStatus {
+    fn clear(&mut self) {
+        self.shard_id = 0;
+        self.height = 0;
+        self.virtual_trie_shard = 0;
+        self.next_page_token.clear();
+        self.last_response = 0;
+        self.last_fid = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReplicationTriePartStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReplicationTriePartStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x11replication.proto\x1a\x0cblocks.proto\x1a\rmessage.proto\x1a\x13on\
+    chain_event.proto\"@\n\x1fGetShardSnapshotMetadataRequest\x12\x1b\n\x08s\
+    hard_id\x18\x01\x20\x01(\rR\x07shardIdB\0:\0\"\xdf\x01\n\x15ShardSnapsho\
+    tMetadata\x12\x1b\n\x08shard_id\x18\x01\x20\x01(\rR\x07shardIdB\0\x12\
+    \x18\n\x06height\x18\x02\x20\x01(\x04R\x06heightB\0\x12\x1e\n\ttimestamp\
+    \x18\x03\x20\x01(\x04R\ttimestampB\0\x12.\n\x0bshard_chunk\x18\x05\x20\
+    \x01(\x0b2\x0b.ShardChunkR\nshardChunkB\0\x12\x1e\n\x05block\x18\x06\x20\
+    \x01(\x0b2\x06.BlockR\x05blockB\0\x12\x1d\n\tnum_items\x18\x07\x20\x01(\
+    \x04R\x08numItemsB\0:\0\"\\\n\x20GetShardSnapshotMetadataResponse\x126\n\
+    \tsnapshots\x18\x01\x20\x03(\x0b2\x16.ShardSnapshotMetadataR\tsnapshotsB\
+    \0:\0\"\xa7\x01\n\x1bGetShardTransactionsRequest\x12\x1b\n\x08shard_id\
+    \x18\x01\x20\x01(\rR\x07shardIdB\0\x12\x18\n\x06height\x18\x02\x20\x01(\
+    \x04R\x06heightB\0\x12.\n\x12trie_virtual_shard\x18\x03\x20\x01(\rR\x10t\
+    rieVirtualShardB\0\x12\x1f\n\npage_token\x18\x04\x20\x01(\tR\tpageTokenB\
+    \0:\0\"\xd0\x01\n\x1cGetShardTransactionsResponse\x12A\n\rtrie_messages\
+    \x18\x01\x20\x03(\x0b2\x1a.ShardTrieEntryWithMessageR\x0ctrieMessagesB\0\
+    \x12A\n\x11fid_account_roots\x18\x02\x20\x03(\x0b2\x13.FidAccountRootHas\
+    hR\x0ffidAccountRootsB\0\x12(\n\x0fnext_page_token\x18\x03\x20\x01(\tR\r\
+    nextPageTokenB\0:\0\"\xd9\x01\n\x19ShardTrieEntryWithMessage\x12\x1b\n\
+    \x08trie_key\x18\x01\x20\x01(\x0cR\x07trieKeyB\0\x12-\n\x0cuser_message\
+    \x18\x02\x20\x01(\x0b2\x08.MessageR\x0buserMessageB\0\x125\n\x0eon_chain\
+    _event\x18\x03\x20\x01(\x0b2\r.OnChainEventR\x0conChainEventB\0\x127\n\
+    \x0efname_transfer\x18\x04\x20\x01(\x0b2\x0e.FnameTransferR\rfnameTransf\
+    erB\0:\0\"}\n\x12FidAccountRootHash\x12\x12\n\x03fid\x18\x01\x20\x01(\
+    \x04R\x03fidB\0\x12,\n\x11account_root_hash\x18\x02\x20\x01(\x0cR\x0facc\
+    ountRootHashB\0\x12#\n\x0cnum_messages\x18\x03\x20\x01(\x04R\x0bnumMessa\
+    gesB\0:\0\"\xf2\x01\n\x19ReplicationTriePartStatus\x12\x1b\n\x08shard_id\
+    \x18\x01\x20\x01(\rR\x07shardIdB\0\x12\x18\n\x06height\x18\x02\x20\x01(\
+    \x04R\x06heightB\0\x12.\n\x12virtual_trie_shard\x18\x03\x20\x01(\rR\x10v\
+    irtualTrieShardB\0\x12(\n\x0fnext_page_token\x18\x04\x20\x01(\tR\rnextPa\
+    geTokenB\0\x12%\n\rlast_response\x18\x05\x20\x01(\rR\x0clastResponseB\0\
+    \x12\x1b\n\x08last_fid\x18\x06\x20\x01(\x04R\x07lastFidB\0:\0B\0b\x06pro\
+    to3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
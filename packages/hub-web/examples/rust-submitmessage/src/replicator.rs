@@ -0,0 +1,1080 @@
+//! Replicates hub messages into a local SQLite database, like packages/shuttle does for Postgres.
+//!
+//! Every message is kept in `messages` with its encoded bytes. Casts, reactions, links,
+//! verifications, user data and username proofs are also written to a table per type so they can
+//! be queried offline. Removes, prunes and revokes never delete rows; they set `deleted_at`,
+//! `pruned_at` or `revoked_at` (unix milliseconds) instead. Every cast remove is also kept in
+//! `cast_removes` under the remover's fid, so an add of that fid's cast that arrives late is stored
+//! as deleted.
+//!
+//! Timestamps of messages are Farcaster times, as in `MessageData`.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
+
+use async_trait::async_trait;
+use protobuf::{Message as _, ProtobufEnum};
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Transaction};
+
+use crate::farcaster_time::{Clock, SystemClock};
+use crate::http::{HttpError, HubHttpClient, MessagesPage, PageOptions};
+use crate::hub_event::{HubEvent, HubEventType};
+use crate::message::{Embed_oneof_embed, Message, MessageData, MessageType};
+use crate::subscriber::{BoxError, HubEventHandler};
+use crate::username_proof::UserNameProof;
+use crate::validations::{message_data, validate_message, ValidationError};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS messages (
+    hash BLOB PRIMARY KEY,
+    fid INTEGER NOT NULL,
+    type INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    signer BLOB NOT NULL,
+    raw BLOB NOT NULL,
+    deleted_at INTEGER,
+    pruned_at INTEGER,
+    revoked_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS messages_fid_type ON messages (fid, type);
+
+CREATE TABLE IF NOT EXISTS casts (
+    hash BLOB PRIMARY KEY,
+    fid INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    text TEXT NOT NULL,
+    parent_fid INTEGER,
+    parent_hash BLOB,
+    parent_url TEXT,
+    embeds TEXT NOT NULL,
+    mentions TEXT NOT NULL,
+    mentions_positions TEXT NOT NULL,
+    deleted_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS casts_fid_timestamp ON casts (fid, timestamp);
+
+CREATE TABLE IF NOT EXISTS cast_removes (
+    fid INTEGER NOT NULL,
+    target_hash BLOB NOT NULL,
+    deleted_at INTEGER NOT NULL,
+    PRIMARY KEY (fid, target_hash)
+);
+
+CREATE TABLE IF NOT EXISTS reactions (
+    hash BLOB PRIMARY KEY,
+    fid INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    reaction_type INTEGER NOT NULL,
+    target_cast_fid INTEGER,
+    target_cast_hash BLOB,
+    target_url TEXT,
+    deleted_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS reactions_fid ON reactions (fid, reaction_type);
+
+CREATE TABLE IF NOT EXISTS links (
+    hash BLOB PRIMARY KEY,
+    fid INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    type TEXT NOT NULL,
+    target_fid INTEGER,
+    display_timestamp INTEGER,
+    deleted_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS links_fid_type ON links (fid, type);
+
+CREATE TABLE IF NOT EXISTS verifications (
+    hash BLOB PRIMARY KEY,
+    fid INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    address BLOB NOT NULL,
+    protocol INTEGER NOT NULL,
+    block_hash BLOB NOT NULL,
+    deleted_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS verifications_fid_address ON verifications (fid, address);
+
+CREATE TABLE IF NOT EXISTS user_data (
+    hash BLOB PRIMARY KEY,
+    fid INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    type INTEGER NOT NULL,
+    value TEXT NOT NULL,
+    deleted_at INTEGER
+);
+CREATE INDEX IF NOT EXISTS user_data_fid_type ON user_data (fid, type);
+
+CREATE TABLE IF NOT EXISTS username_proofs (
+    name TEXT PRIMARY KEY,
+    fid INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    type INTEGER NOT NULL,
+    owner BLOB NOT NULL,
+    signature BLOB NOT NULL,
+    hash BLOB,
+    deleted_at INTEGER
+);
+";
+
+/// Tables with one row per message, keyed by the message hash.
+const MESSAGE_TABLES: [&str; 6] = [
+    "casts",
+    "reactions",
+    "links",
+    "verifications",
+    "user_data",
+    "username_proofs",
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReplicatorError {
+    #[error("sqlite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+    #[error("invalid message: {0}")]
+    Invalid(#[from] ValidationError),
+    #[error("failed to encode message: {0}")]
+    Encode(#[from] protobuf::ProtobufError),
+    #[error("failed to read messages from hub: {0}")]
+    Http(#[from] HttpError),
+}
+
+pub type ReplicatorResult<T = ()> = Result<T, ReplicatorError>;
+
+/// Writes hub messages and events into a SQLite database.
+#[derive(Debug)]
+pub struct Replicator {
+    conn: Connection,
+    validate: bool,
+    clock: Arc<dyn Clock>,
+}
+
+impl Replicator {
+    /// Opens (or creates) the database at `path` and applies the schema.
+    pub fn open(path: impl AsRef<Path>) -> ReplicatorResult<Self> {
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> ReplicatorResult<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    pub fn with_connection(conn: Connection) -> ReplicatorResult<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self {
+            conn,
+            validate: true,
+            clock: Arc::new(SystemClock),
+        })
+    }
+
+    /// Whether merged messages are validated (including their hash and signature) first.
+    /// Defaults to true.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Sets the clock used for `deleted_at`, `pruned_at` and `revoked_at`.
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// The underlying connection, for queries.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    fn now_ms(&self) -> i64 {
+        self.clock
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as i64)
+    }
+
+    /// Stores a message and applies it to the table for its type. Returns false if the message
+    /// was already superseded by a newer one with the same key, in which case it is stored as
+    /// deleted.
+    pub fn merge_message(&mut self, message: &Message) -> ReplicatorResult<bool> {
+        if self.validate {
+            validate_message(message)?;
+        }
+        let now = self.now_ms();
+        let tx = self.conn.transaction()?;
+        let merged = merge_message(&tx, message, now)?;
+        tx.commit()?;
+        Ok(merged)
+    }
+
+    /// Marks a message removed by a conflicting message as deleted.
+    pub fn delete_message(&mut self, message: &Message) -> ReplicatorResult {
+        self.mark_message(message, "deleted_at")
+    }
+
+    /// Marks a message pruned by the hub's storage limits.
+    pub fn prune_message(&mut self, message: &Message) -> ReplicatorResult {
+        self.mark_message(message, "pruned_at")
+    }
+
+    /// Marks a message whose signer was revoked.
+    pub fn revoke_message(&mut self, message: &Message) -> ReplicatorResult {
+        self.mark_message(message, "revoked_at")
+    }
+
+    fn mark_message(&mut self, message: &Message, column: &str) -> ReplicatorResult {
+        let now = self.now_ms();
+        let tx = self.conn.transaction()?;
+        mark_message(&tx, message.get_hash(), column, now)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Stores a username proof received outside of a message, e.g. from a
+    /// `MERGE_USERNAME_PROOF` event.
+    pub fn merge_username_proof(&mut self, proof: &UserNameProof) -> ReplicatorResult {
+        let tx = self.conn.transaction()?;
+        upsert_username_proof(&tx, proof, None)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Applies a hub event: merges, prunes and revokes messages and username proofs. Other event
+    /// types are ignored.
+    pub fn apply_event(&mut self, event: &HubEvent) -> ReplicatorResult {
+        let now = self.now_ms();
+        match event.get_field_type() {
+            HubEventType::HUB_EVENT_TYPE_MERGE_MESSAGE => {
+                let body = event.get_merge_message_body();
+                if self.validate {
+                    validate_message(body.get_message())?;
+                }
+                let tx = self.conn.transaction()?;
+                for deleted in body.get_deleted_messages() {
+                    mark_message(&tx, deleted.get_hash(), "deleted_at", now)?;
+                }
+                merge_message(&tx, body.get_message(), now)?;
+                tx.commit()?;
+            }
+            HubEventType::HUB_EVENT_TYPE_PRUNE_MESSAGE => {
+                self.prune_message(event.get_prune_message_body().get_message())?
+            }
+            HubEventType::HUB_EVENT_TYPE_REVOKE_MESSAGE => {
+                self.revoke_message(event.get_revoke_message_body().get_message())?
+            }
+            HubEventType::HUB_EVENT_TYPE_MERGE_USERNAME_PROOF => {
+                let body = event.get_merge_username_proof_body();
+                let tx = self.conn.transaction()?;
+                if body.has_deleted_username_proof() {
+                    tx.execute(
+                        "UPDATE username_proofs SET deleted_at = ? WHERE name = ?",
+                        params![now, proof_name(body.get_deleted_username_proof())],
+                    )?;
+                }
+                if body.has_username_proof() {
+                    upsert_username_proof(&tx, body.get_username_proof(), None)?;
+                }
+                tx.commit()?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Copies every cast, reaction, link, verification and user data message of `fid` from the
+    /// hub. Returns the number of messages merged.
+    pub async fn backfill_fid(
+        &mut self,
+        client: &HubHttpClient,
+        fid: u64,
+    ) -> ReplicatorResult<usize> {
+        let mut merged = 0;
+        for kind in [
+            Backfill::Casts,
+            Backfill::Reactions,
+            Backfill::Links,
+            Backfill::Verifications,
+            Backfill::UserData,
+        ] {
+            let mut page = PageOptions::default();
+            loop {
+                let MessagesPage {
+                    messages,
+                    next_page_token,
+                } = kind.fetch(client, fid, &page).await?;
+                for message in &messages {
+                    self.merge_message(message)?;
+                    merged += 1;
+                }
+                match next_page_token {
+                    Some(token) => page.page_token = Some(token),
+                    None => break,
+                }
+            }
+        }
+        Ok(merged)
+    }
+}
+
+#[async_trait]
+impl HubEventHandler for Replicator {
+    async fn handle_event(&mut self, event: &HubEvent) -> Result<(), BoxError> {
+        Ok(self.apply_event(event)?)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Backfill {
+    Casts,
+    Reactions,
+    Links,
+    Verifications,
+    UserData,
+}
+
+impl Backfill {
+    async fn fetch(
+        self,
+        client: &HubHttpClient,
+        fid: u64,
+        page: &PageOptions,
+    ) -> Result<MessagesPage, HttpError> {
+        match self {
+            Self::Casts => client.casts_by_fid(fid, page).await,
+            Self::Reactions => client.reactions_by_fid(fid, None, page).await,
+            Self::Links => client.links_by_fid(fid, None, page).await,
+            Self::Verifications => client.verifications_by_fid(fid, page).await,
+            Self::UserData => client.user_data_by_fid(fid, page).await,
+        }
+    }
+}
+
+fn blob(bytes: &[u8]) -> SqlValue {
+    SqlValue::Blob(bytes.to_vec())
+}
+
+fn text(text: &str) -> SqlValue {
+    SqlValue::Text(text.to_string())
+}
+
+fn integer(value: impl Into<i64>) -> SqlValue {
+    SqlValue::Integer(value.into())
+}
+
+fn proof_name(proof: &UserNameProof) -> String {
+    String::from_utf8_lossy(proof.get_name()).into_owned()
+}
+
+/// Stores a message the first time it is seen. A message delivered again keeps its `deleted_at`,
+/// `pruned_at` and `revoked_at`, and its typed row is left alone.
+fn merge_message(tx: &Transaction, message: &Message, now: i64) -> ReplicatorResult<bool> {
+    let data = message_data(message)?;
+    let hash = message.get_hash();
+    let inserted = tx.execute(
+        "INSERT INTO messages (hash, fid, type, timestamp, signer, raw) VALUES (?, ?, ?, ?, ?, ?)
+         ON CONFLICT (hash) DO NOTHING",
+        params![
+            hash,
+            data.get_fid() as i64,
+            data.get_field_type().value(),
+            data.get_timestamp(),
+            message.get_signer(),
+            message.write_to_bytes()?,
+        ],
+    )?;
+    if inserted == 0 {
+        let active: Option<i64> = tx
+            .query_row(
+                "SELECT 1 FROM messages WHERE hash = ?
+                 AND deleted_at IS NULL AND pruned_at IS NULL AND revoked_at IS NULL",
+                [hash],
+                |row| row.get(0),
+            )
+            .optional()?;
+        return Ok(active.is_some());
+    }
+
+    let merged = apply_body(tx, hash, &data, now)?;
+    if !merged {
+        tx.execute(
+            "UPDATE messages SET deleted_at = ? WHERE hash = ?",
+            params![now, hash],
+        )?;
+    }
+    Ok(merged)
+}
+
+/// Writes the typed row for a message. Returns false if a newer message with the same key wins.
+fn apply_body(
+    tx: &Transaction,
+    hash: &[u8],
+    data: &MessageData,
+    now: i64,
+) -> ReplicatorResult<bool> {
+    let fid = integer(data.get_fid() as i64);
+    let timestamp = data.get_timestamp();
+    let row = |values: Vec<(&'static str, SqlValue)>| {
+        let mut row = vec![
+            ("hash", blob(hash)),
+            ("fid", fid.clone()),
+            ("timestamp", integer(timestamp)),
+        ];
+        row.extend(values);
+        row
+    };
+
+    match data.get_field_type() {
+        MessageType::MESSAGE_TYPE_CAST_ADD => {
+            // A cast its author removed, possibly before the add arrived, stays removed
+            let removed: Option<i64> = tx
+                .query_row(
+                    "SELECT deleted_at FROM cast_removes WHERE fid = ? AND target_hash = ?",
+                    params![fid, hash],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            let body = data.get_cast_add_body();
+            let embeds: Vec<_> = body
+                .get_embeds()
+                .iter()
+                .map(|embed| match &embed.embed {
+                    Some(Embed_oneof_embed::url(url)) => serde_json::json!({ "url": url }),
+                    Some(Embed_oneof_embed::cast_id(cast_id)) => serde_json::json!({
+                        "castId": {
+                            "fid": cast_id.get_fid(),
+                            "hash": format!("0x{}", hex::encode(cast_id.get_hash())),
+                        }
+                    }),
+                    None => serde_json::json!({}),
+                })
+                .collect();
+            let (parent_fid, parent_hash) = if body.has_parent_cast_id() {
+                let parent = body.get_parent_cast_id();
+                (integer(parent.get_fid() as i64), blob(parent.get_hash()))
+            } else {
+                (SqlValue::Null, SqlValue::Null)
+            };
+            let parent_url = if body.has_parent_url() {
+                text(body.get_parent_url())
+            } else {
+                SqlValue::Null
+            };
+            let mut values = vec![
+                ("text", text(body.get_text())),
+                ("parent_fid", parent_fid),
+                ("parent_hash", parent_hash),
+                ("parent_url", parent_url),
+                ("embeds", text(&serde_json::to_string(&embeds).unwrap())),
+                (
+                    "mentions",
+                    text(&serde_json::to_string(body.get_mentions()).unwrap()),
+                ),
+                (
+                    "mentions_positions",
+                    text(&serde_json::to_string(body.get_mentions_positions()).unwrap()),
+                ),
+            ];
+            if let Some(deleted_at) = removed {
+                values.push(("deleted_at", integer(deleted_at)));
+            }
+            insert(tx, "casts", row(values))?;
+            Ok(removed.is_none())
+        }
+        MessageType::MESSAGE_TYPE_CAST_REMOVE => {
+            let target_hash = blob(data.get_cast_remove_body().get_target_hash());
+            mark_where(
+                tx,
+                "casts",
+                &[("fid", fid.clone()), ("hash", target_hash.clone())],
+                now,
+            )?;
+            // Remembered for an add of the cast that has not arrived yet
+            tx.execute(
+                "INSERT OR IGNORE INTO cast_removes (fid, target_hash, deleted_at) VALUES (?, ?, ?)",
+                params![fid, target_hash, now],
+            )?;
+            Ok(true)
+        }
+        MessageType::MESSAGE_TYPE_REACTION_ADD | MessageType::MESSAGE_TYPE_REACTION_REMOVE => {
+            let body = data.get_reaction_body();
+            let (target_cast_fid, target_cast_hash) = if body.has_target_cast_id() {
+                let target = body.get_target_cast_id();
+                (integer(target.get_fid() as i64), blob(target.get_hash()))
+            } else {
+                (SqlValue::Null, SqlValue::Null)
+            };
+            let target_url = if body.has_target_url() {
+                text(body.get_target_url())
+            } else {
+                SqlValue::Null
+            };
+            let key = vec![
+                ("fid", fid.clone()),
+                ("reaction_type", integer(body.get_field_type().value())),
+                ("target_cast_fid", target_cast_fid),
+                ("target_cast_hash", target_cast_hash),
+                ("target_url", target_url),
+            ];
+            let add = data.get_field_type() == MessageType::MESSAGE_TYPE_REACTION_ADD;
+            let row = row(key[1..].to_vec());
+            supersede(tx, "reactions", &key, timestamp, row, add, now)
+        }
+        MessageType::MESSAGE_TYPE_LINK_ADD | MessageType::MESSAGE_TYPE_LINK_REMOVE => {
+            let body = data.get_link_body();
+            let target_fid = if body.has_target_fid() {
+                integer(body.get_target_fid() as i64)
+            } else {
+                SqlValue::Null
+            };
+            let key = vec![
+                ("fid", fid.clone()),
+                ("type", text(body.get_field_type())),
+                ("target_fid", target_fid),
+            ];
+            let add = data.get_field_type() == MessageType::MESSAGE_TYPE_LINK_ADD;
+            let display_timestamp = match body.get_displayTimestamp() {
+                0 => SqlValue::Null,
+                display_timestamp => integer(display_timestamp),
+            };
+            let mut values = key[1..].to_vec();
+            values.push(("display_timestamp", display_timestamp));
+            supersede(tx, "links", &key, timestamp, row(values), add, now)
+        }
+        MessageType::MESSAGE_TYPE_LINK_COMPACT_STATE => {
+            // Links of this type older than the compact state and missing from it are removed
+            let body = data.get_link_compact_state_body();
+            let mut stale = tx.prepare(
+                "SELECT hash, target_fid FROM links
+                 WHERE fid = ? AND type = ? AND timestamp < ? AND deleted_at IS NULL",
+            )?;
+            let stale: Vec<Vec<u8>> = stale
+                .query_map(
+                    params![data.get_fid() as i64, body.get_field_type(), timestamp],
+                    |row| Ok((row.get::<_, Vec<u8>>(0)?, row.get::<_, Option<i64>>(1)?)),
+                )?
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .filter(|(_, target_fid)| {
+                    !target_fid.is_some_and(|fid| body.get_target_fids().contains(&(fid as u64)))
+                })
+                .map(|(hash, _)| hash)
+                .collect();
+            for stale_hash in stale {
+                mark_message(tx, &stale_hash, "deleted_at", now)?;
+            }
+            Ok(true)
+        }
+        MessageType::MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS => {
+            let body = data.get_verification_add_address_body();
+            let key = vec![("fid", fid.clone()), ("address", blob(body.get_address()))];
+            let row = row(vec![
+                ("address", blob(body.get_address())),
+                ("protocol", integer(body.get_protocol().value())),
+                ("block_hash", blob(body.get_block_hash())),
+            ]);
+            supersede(tx, "verifications", &key, timestamp, row, true, now)
+        }
+        MessageType::MESSAGE_TYPE_VERIFICATION_REMOVE => {
+            let body = data.get_verification_remove_body();
+            let key = [("fid", fid.clone()), ("address", blob(body.get_address()))];
+            let row = row(vec![
+                ("address", blob(body.get_address())),
+                ("protocol", integer(body.get_protocol().value())),
+                ("block_hash", blob(&[])),
+            ]);
+            supersede(tx, "verifications", &key, timestamp, row, false, now)
+        }
+        MessageType::MESSAGE_TYPE_USER_DATA_ADD => {
+            let body = data.get_user_data_body();
+            let key = vec![
+                ("fid", fid.clone()),
+                ("type", integer(body.get_field_type().value())),
+            ];
+            let mut values = key[1..].to_vec();
+            values.push(("value", text(body.get_value())));
+            supersede(tx, "user_data", &key, timestamp, row(values), true, now)
+        }
+        MessageType::MESSAGE_TYPE_USERNAME_PROOF => {
+            upsert_username_proof(tx, data.get_username_proof_body(), Some(hash))
+        }
+        _ => Ok(true),
+    }
+}
+
+/// Inserts (or replaces) a row built from `columns`.
+fn insert(tx: &Transaction, table: &str, columns: Vec<(&str, SqlValue)>) -> ReplicatorResult {
+    let names: Vec<_> = columns.iter().map(|(name, _)| *name).collect();
+    let sql = format!(
+        "INSERT OR REPLACE INTO {} ({}) VALUES ({})",
+        table,
+        names.join(", "),
+        vec!["?"; names.len()].join(", ")
+    );
+    tx.execute(
+        &sql,
+        params_from_iter(columns.into_iter().map(|(_, value)| value)),
+    )?;
+    Ok(())
+}
+
+fn filter(key: &[(&str, SqlValue)]) -> String {
+    key.iter()
+        .map(|(column, _)| format!("{} IS ?", column))
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+/// Marks the active rows matching `key` and their messages as deleted.
+fn mark_where(
+    tx: &Transaction,
+    table: &str,
+    key: &[(&str, SqlValue)],
+    now: i64,
+) -> ReplicatorResult {
+    let params = || {
+        std::iter::once(SqlValue::Integer(now)).chain(key.iter().map(|(_, value)| value.clone()))
+    };
+    tx.execute(
+        &format!(
+            "UPDATE messages SET deleted_at = ? WHERE hash IN
+             (SELECT hash FROM {} WHERE {} AND deleted_at IS NULL)",
+            table,
+            filter(key)
+        ),
+        params_from_iter(params()),
+    )?;
+    tx.execute(
+        &format!(
+            "UPDATE {} SET deleted_at = ? WHERE {} AND deleted_at IS NULL",
+            table,
+            filter(key)
+        ),
+        params_from_iter(params()),
+    )?;
+    Ok(())
+}
+
+/// Resolves a message against the rows with the same key like hubs do: the later timestamp wins,
+/// then a remove over an add, then the higher hash. If the message wins, the active row is deleted
+/// and `row` is inserted. Removes are inserted already deleted, so an older add that arrives late
+/// can't bring the target back.
+fn supersede(
+    tx: &Transaction,
+    table: &str,
+    key: &[(&str, SqlValue)],
+    timestamp: u32,
+    mut row: Vec<(&str, SqlValue)>,
+    add: bool,
+    now: i64,
+) -> ReplicatorResult<bool> {
+    let hash = row
+        .iter()
+        .find(|(column, _)| *column == "hash")
+        .map_or(SqlValue::Null, |(_, hash)| hash.clone());
+    let removes = [
+        MessageType::MESSAGE_TYPE_REACTION_REMOVE,
+        MessageType::MESSAGE_TYPE_LINK_REMOVE,
+        MessageType::MESSAGE_TYPE_VERIFICATION_REMOVE,
+    ]
+    .map(|message_type| message_type.value().to_string())
+    .join(", ");
+    let newer: Option<i64> = tx
+        .query_row(
+            &format!(
+                "SELECT 1 FROM {table} WHERE {} AND (timestamp,
+                 (SELECT type FROM messages WHERE messages.hash = {table}.hash) IN ({removes}),
+                 hash) > (?, ?, ?) LIMIT 1",
+                filter(key)
+            ),
+            params_from_iter(key.iter().map(|(_, value)| value.clone()).chain([
+                integer(timestamp),
+                integer(!add),
+                hash,
+            ])),
+            |row| row.get(0),
+        )
+        .optional()?;
+    if newer.is_some() {
+        return Ok(false);
+    }
+
+    mark_where(tx, table, key, now)?;
+    if !add {
+        row.push(("deleted_at", integer(now)));
+    }
+    insert(tx, table, row)?;
+    Ok(true)
+}
+
+fn upsert_username_proof(
+    tx: &Transaction,
+    proof: &UserNameProof,
+    hash: Option<&[u8]>,
+) -> ReplicatorResult<bool> {
+    let name = proof_name(proof);
+    let newer: Option<i64> = tx
+        .query_row(
+            "SELECT 1 FROM username_proofs WHERE name = ? AND timestamp > ? AND deleted_at IS NULL",
+            params![name, proof.get_timestamp() as i64],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if newer.is_some() {
+        return Ok(false);
+    }
+    tx.execute(
+        "INSERT OR REPLACE INTO username_proofs
+         (name, fid, timestamp, type, owner, signature, hash) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        params![
+            name,
+            proof.get_fid() as i64,
+            proof.get_timestamp() as i64,
+            proof.get_field_type().value(),
+            proof.get_owner(),
+            proof.get_signature(),
+            hash,
+        ],
+    )?;
+    Ok(true)
+}
+
+/// Sets `column` on the message with `hash` and deletes its typed row.
+fn mark_message(tx: &Transaction, hash: &[u8], column: &str, now: i64) -> ReplicatorResult {
+    tx.execute(
+        &format!("UPDATE messages SET {} = ? WHERE hash = ?", column),
+        params![now, hash],
+    )?;
+    for table in MESSAGE_TABLES {
+        tx.execute(
+            &format!(
+                "UPDATE {} SET deleted_at = ? WHERE hash = ? AND deleted_at IS NULL",
+                table
+            ),
+            params![now, hash],
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bodies::{
+        cast_id, CastAddBuilder, CastRemoveBuilder, LinkBuilder, MessageBody, ReactionBuilder,
+        ReactionTarget, UserDataBuilder,
+    };
+    use crate::farcaster_time::{get_farcaster_time, FixedClock};
+    use crate::hub_event::{MergeMessageBody, PruneMessageBody};
+    use crate::message::{
+        LinkCompactStateBody, MessageData_oneof_body, ReactionType, UserDataType,
+    };
+    use crate::test_utils::{message, message_from};
+
+    fn replicator() -> Replicator {
+        Replicator::open_in_memory()
+            .unwrap()
+            .clock(Arc::new(FixedClock::new(1_700_000_000_000)))
+    }
+
+    fn count(replicator: &Replicator, sql: &str) -> i64 {
+        replicator
+            .connection()
+            .query_row(sql, [], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn merges_casts_and_applies_removes() {
+        let now = get_farcaster_time().unwrap();
+        let mut replicator = replicator();
+        let cast = message(
+            CastAddBuilder::new("hello")
+                .mention(3, 0)
+                .embed_url("https://example.com")
+                .parent_url("https://example.com/channel"),
+            now - 10,
+        )
+        .await;
+        assert!(replicator.merge_message(&cast).unwrap());
+
+        let (text, parent_url, embeds, mentions): (String, String, String, String) = replicator
+            .connection()
+            .query_row(
+                "SELECT text, parent_url, embeds, mentions FROM casts WHERE hash = ?",
+                [cast.get_hash()],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .unwrap();
+        assert_eq!(text, "hello");
+        assert_eq!(parent_url, "https://example.com/channel");
+        assert_eq!(embeds, r#"[{"url":"https://example.com"}]"#);
+        assert_eq!(mentions, "[3]");
+
+        let remove = message(CastRemoveBuilder::new(cast.get_hash().to_vec()), now).await;
+        assert!(replicator.merge_message(&remove).unwrap());
+        assert_eq!(
+            count(
+                &replicator,
+                "SELECT COUNT(*) FROM casts WHERE deleted_at IS NULL"
+            ),
+            0
+        );
+        assert_eq!(
+            count(
+                &replicator,
+                "SELECT COUNT(*) FROM messages WHERE deleted_at IS NULL"
+            ),
+            1
+        );
+
+        let mut invalid = cast.clone();
+        invalid.set_signature(vec![0; 64]);
+        assert!(matches!(
+            replicator.merge_message(&invalid),
+            Err(ReplicatorError::Invalid(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn removed_casts_stay_removed_when_delivered_again() {
+        let now = get_farcaster_time().unwrap();
+        let mut replicator = replicator();
+        let cast = message(CastAddBuilder::new("hello"), now - 10).await;
+        let remove = message(CastRemoveBuilder::new(cast.get_hash().to_vec()), now).await;
+
+        assert!(replicator.merge_message(&cast).unwrap());
+        assert!(replicator.merge_message(&remove).unwrap());
+        assert!(!replicator.merge_message(&cast).unwrap());
+        assert_eq!(
+            count(
+                &replicator,
+                "SELECT COUNT(*) FROM casts WHERE deleted_at IS NULL"
+            ),
+            0
+        );
+        assert_eq!(
+            count(
+                &replicator,
+                "SELECT COUNT(*) FROM messages WHERE deleted_at IS NULL"
+            ),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn removes_casts_whose_add_arrives_later() {
+        let now = get_farcaster_time().unwrap();
+        let mut replicator = replicator();
+        let cast = message(CastAddBuilder::new("hello"), now - 10).await;
+        let remove = message(CastRemoveBuilder::new(cast.get_hash().to_vec()), now).await;
+
+        assert!(replicator.merge_message(&remove).unwrap());
+        assert!(!replicator.merge_message(&cast).unwrap());
+        assert_eq!(
+            count(
+                &replicator,
+                "SELECT COUNT(*) FROM casts WHERE deleted_at IS NULL"
+            ),
+            0
+        );
+        let deleted: Vec<u8> = replicator
+            .connection()
+            .query_row(
+                "SELECT hash FROM messages WHERE deleted_at IS NOT NULL",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(deleted, cast.get_hash());
+    }
+
+    #[tokio::test]
+    async fn ignores_removes_of_other_fids_casts() {
+        let now = get_farcaster_time().unwrap();
+        let mut replicator = replicator();
+        let cast = message(CastAddBuilder::new("hello"), now - 10).await;
+        let remove = message_from(3, CastRemoveBuilder::new(cast.get_hash().to_vec()), now).await;
+
+        assert!(replicator.merge_message(&remove).unwrap());
+        assert!(replicator.merge_message(&cast).unwrap());
+        let fid: i64 = replicator
+            .connection()
+            .query_row(
+                "SELECT fid FROM casts WHERE hash = ? AND deleted_at IS NULL",
+                [cast.get_hash()],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(fid, 2);
+    }
+
+    #[tokio::test]
+    async fn resolves_reactions_and_user_data_by_timestamp() {
+        let now = get_farcaster_time().unwrap();
+        let mut replicator = replicator();
+        let target = || ReactionTarget::Cast(cast_id(3, vec![1; 20]));
+        let like = message(
+            ReactionBuilder::add(ReactionType::REACTION_TYPE_LIKE, target()),
+            now - 5,
+        )
+        .await;
+        let unlike = message(
+            ReactionBuilder::remove(ReactionType::REACTION_TYPE_LIKE, target()),
+            now - 3,
+        )
+        .await;
+        let stale_like = message(
+            ReactionBuilder::add(ReactionType::REACTION_TYPE_LIKE, target()),
+            now - 4,
+        )
+        .await;
+
+        assert!(replicator.merge_message(&like).unwrap());
+        assert!(replicator.merge_message(&unlike).unwrap());
+        assert!(!replicator.merge_message(&stale_like).unwrap());
+        assert_eq!(
+            count(
+                &replicator,
+                "SELECT COUNT(*) FROM reactions WHERE deleted_at IS NULL"
+            ),
+            0
+        );
+
+        let old_name = message(
+            UserDataBuilder::new(UserDataType::USER_DATA_TYPE_DISPLAY, "Old"),
+            now - 2,
+        )
+        .await;
+        let new_name = message(
+            UserDataBuilder::new(UserDataType::USER_DATA_TYPE_DISPLAY, "New"),
+            now - 1,
+        )
+        .await;
+        assert!(replicator.merge_message(&new_name).unwrap());
+        assert!(!replicator.merge_message(&old_name).unwrap());
+        let value: String = replicator
+            .connection()
+            .query_row(
+                "SELECT value FROM user_data WHERE fid = 2 AND deleted_at IS NULL",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(value, "New");
+    }
+
+    #[tokio::test]
+    async fn removes_win_timestamp_ties_in_any_order() {
+        let now = get_farcaster_time().unwrap();
+        let target = || ReactionTarget::Cast(cast_id(3, vec![1; 20]));
+        let like = message(
+            ReactionBuilder::add(ReactionType::REACTION_TYPE_LIKE, target()),
+            now - 5,
+        )
+        .await;
+        let unlike = message(
+            ReactionBuilder::remove(ReactionType::REACTION_TYPE_LIKE, target()),
+            now - 5,
+        )
+        .await;
+
+        for (first, second, merged) in [(&like, &unlike, true), (&unlike, &like, false)] {
+            let mut replicator = replicator();
+            assert!(replicator.merge_message(first).unwrap());
+            assert_eq!(replicator.merge_message(second).unwrap(), merged);
+            assert_eq!(
+                count(
+                    &replicator,
+                    "SELECT COUNT(*) FROM reactions WHERE deleted_at IS NULL"
+                ),
+                0
+            );
+            assert_eq!(
+                count(
+                    &replicator,
+                    "SELECT COUNT(*) FROM messages WHERE deleted_at IS NULL"
+                ),
+                1
+            );
+        }
+    }
+
+    struct CompactState(LinkCompactStateBody);
+
+    impl MessageBody for CompactState {
+        fn message_type(&self) -> MessageType {
+            MessageType::MESSAGE_TYPE_LINK_COMPACT_STATE
+        }
+
+        fn into_body(self) -> MessageData_oneof_body {
+            MessageData_oneof_body::link_compact_state_body(self.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn compacts_links_not_in_the_compact_state() {
+        let now = get_farcaster_time().unwrap();
+        let mut replicator = replicator();
+        for target_fid in [3, 4] {
+            let follow = message(LinkBuilder::add("follow", target_fid), now - 10).await;
+            assert!(replicator.merge_message(&follow).unwrap());
+        }
+
+        let mut body = LinkCompactStateBody::new();
+        body.set_field_type("follow".to_string());
+        body.set_target_fids(vec![3]);
+        let compact_state = message(CompactState(body), now).await;
+        assert!(replicator.merge_message(&compact_state).unwrap());
+        let target_fid: i64 = replicator
+            .connection()
+            .query_row(
+                "SELECT target_fid FROM links WHERE deleted_at IS NULL",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(target_fid, 3);
+    }
+
+    #[tokio::test]
+    async fn applies_hub_events() {
+        let now = get_farcaster_time().unwrap();
+        let mut replicator = replicator();
+        let first = message(CastAddBuilder::new("first"), now - 2).await;
+        let second = message(CastAddBuilder::new("second"), now - 1).await;
+
+        for cast in [&first, &second] {
+            let mut body = MergeMessageBody::new();
+            body.set_message(cast.clone());
+            let mut event = HubEvent::new();
+            event.set_field_type(HubEventType::HUB_EVENT_TYPE_MERGE_MESSAGE);
+            event.set_merge_message_body(body);
+            replicator.handle_event(&event).await.unwrap();
+        }
+
+        let mut body = PruneMessageBody::new();
+        body.set_message(first.clone());
+        let mut event = HubEvent::new();
+        event.set_field_type(HubEventType::HUB_EVENT_TYPE_PRUNE_MESSAGE);
+        event.set_prune_message_body(body);
+        replicator.apply_event(&event).unwrap();
+
+        let pruned: Vec<u8> = replicator
+            .connection()
+            .query_row(
+                "SELECT hash FROM messages WHERE pruned_at IS NOT NULL",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(pruned, first.get_hash());
+        assert_eq!(
+            count(
+                &replicator,
+                "SELECT COUNT(*) FROM casts WHERE deleted_at IS NULL"
+            ),
+            1
+        );
+    }
+}
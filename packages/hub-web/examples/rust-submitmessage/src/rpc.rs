@@ -0,0 +1,1168 @@
+/// Generated client implementations.
+pub mod admin_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct AdminServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl AdminServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> AdminServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> AdminServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            AdminServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn submit_on_chain_event(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::onchain_event::OnChainEvent>,
+        ) -> std::result::Result<tonic::Response<crate::onchain_event::OnChainEvent>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/AdminService/SubmitOnChainEvent");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("AdminService", "SubmitOnChainEvent"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn submit_user_name_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::username_proof::UserNameProof>,
+        ) -> std::result::Result<tonic::Response<crate::username_proof::UserNameProof>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/AdminService/SubmitUserNameProof");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("AdminService", "SubmitUserNameProof"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn upload_snapshot(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::admin_rpc::UploadSnapshotRequest>,
+        ) -> std::result::Result<tonic::Response<crate::admin_rpc::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/AdminService/UploadSnapshot");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("AdminService", "UploadSnapshot"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn retry_onchain_events(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::admin_rpc::RetryOnchainEventsRequest>,
+        ) -> std::result::Result<tonic::Response<crate::admin_rpc::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/AdminService/RetryOnchainEvents");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("AdminService", "RetryOnchainEvents"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn retry_fname_events(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::admin_rpc::RetryFnameRequest>,
+        ) -> std::result::Result<tonic::Response<crate::admin_rpc::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/AdminService/RetryFnameEvents");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("AdminService", "RetryFnameEvents"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn run_onchain_events_migration(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::admin_rpc::RunOnchainEventsMigrationRequest>,
+        ) -> std::result::Result<tonic::Response<crate::admin_rpc::Empty>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/AdminService/RunOnchainEventsMigration");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("AdminService", "RunOnchainEventsMigration"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated client implementations.
+pub mod hub_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::http::Uri;
+    use tonic::codegen::*;
+    #[derive(Debug, Clone)]
+    pub struct HubServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl HubServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> HubServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> HubServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
+                Into<StdError> + Send + Sync,
+        {
+            HubServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn submit_message(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::message::Message>,
+        ) -> std::result::Result<tonic::Response<crate::message::Message>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/SubmitMessage");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "SubmitMessage"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn submit_bulk_messages(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::SubmitBulkMessagesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::SubmitBulkMessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/SubmitBulkMessages");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "SubmitBulkMessages"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn validate_message(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::message::Message>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::ValidationResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/ValidateMessage");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "ValidateMessage"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_blocks(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::BlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<crate::blocks::Block>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetBlocks");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetBlocks"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn get_shard_chunks(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::ShardChunksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::ShardChunksResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetShardChunks");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetShardChunks"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_info(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::GetInfoRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::GetInfoResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetInfo");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetInfo"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_fids(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::FidsResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetFids");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetFids"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_connected_peers(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::GetConnectedPeersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::GetConnectedPeersResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetConnectedPeers");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetConnectedPeers"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn subscribe(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::SubscribeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<crate::hub_event::HubEvent>>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/Subscribe");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "Subscribe"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        pub async fn get_event(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::EventRequest>,
+        ) -> std::result::Result<tonic::Response<crate::hub_event::HubEvent>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetEvent");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetEvent"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_events(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::EventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::EventsResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetEvents");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetEvents"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_cast(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::message::CastId>,
+        ) -> std::result::Result<tonic::Response<crate::message::Message>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetCast");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetCast"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_casts_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetCastsByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetCastsByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_casts_by_parent(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::CastsByParentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetCastsByParent");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetCastsByParent"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_casts_by_mention(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetCastsByMention");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetCastsByMention"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_reaction(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::ReactionRequest>,
+        ) -> std::result::Result<tonic::Response<crate::message::Message>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetReaction");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetReaction"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_reactions_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::ReactionsByFidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetReactionsByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetReactionsByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_reactions_by_cast(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::ReactionsByTargetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetReactionsByCast");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetReactionsByCast"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_reactions_by_target(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::ReactionsByTargetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetReactionsByTarget");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetReactionsByTarget"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_user_data(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::UserDataRequest>,
+        ) -> std::result::Result<tonic::Response<crate::message::Message>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetUserData");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetUserData"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_user_data_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetUserDataByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetUserDataByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_username_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::UsernameProofRequest>,
+        ) -> std::result::Result<tonic::Response<crate::username_proof::UserNameProof>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetUsernameProof");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetUsernameProof"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_user_name_proofs_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::UsernameProofsResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetUserNameProofsByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetUserNameProofsByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_verification(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::VerificationRequest>,
+        ) -> std::result::Result<tonic::Response<crate::message::Message>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetVerification");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetVerification"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_verifications_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetVerificationsByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetVerificationsByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_on_chain_signer(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::SignerRequest>,
+        ) -> std::result::Result<tonic::Response<crate::onchain_event::OnChainEvent>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetOnChainSigner");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetOnChainSigner"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_on_chain_signers_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::OnChainEventResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetOnChainSignersByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetOnChainSignersByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_signer(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::SignerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::SignerResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetSigner");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetSigner"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_signers_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::SignersByFidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::SignersByFidResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetSignersByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetSignersByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_on_chain_events(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::OnChainEventRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::OnChainEventResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetOnChainEvents");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetOnChainEvents"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_id_registry_on_chain_event(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidRequest>,
+        ) -> std::result::Result<tonic::Response<crate::onchain_event::OnChainEvent>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/HubService/GetIdRegistryOnChainEvent");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetIdRegistryOnChainEvent"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_id_registry_on_chain_event_by_address(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::IdRegistryEventByAddressRequest>,
+        ) -> std::result::Result<tonic::Response<crate::onchain_event::OnChainEvent>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/HubService/GetIdRegistryOnChainEventByAddress",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "HubService",
+                "GetIdRegistryOnChainEventByAddress",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_current_storage_limits_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::StorageLimitsResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/HubService/GetCurrentStorageLimitsByFid");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "HubService",
+                "GetCurrentStorageLimitsByFid",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_fid_address_type(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidAddressTypeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::FidAddressTypeResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetFidAddressType");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetFidAddressType"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_link(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::LinkRequest>,
+        ) -> std::result::Result<tonic::Response<crate::message::Message>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetLink");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetLink"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_links_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::LinksByFidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetLinksByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetLinksByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_links_by_target(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::LinksByTargetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetLinksByTarget");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetLinksByTarget"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_link_compact_state_message_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/HubService/GetLinkCompactStateMessageByFid");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "HubService",
+                "GetLinkCompactStateMessageByFid",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_all_cast_messages_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidTimestampRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetAllCastMessagesByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetAllCastMessagesByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_all_reaction_messages_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidTimestampRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/HubService/GetAllReactionMessagesByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetAllReactionMessagesByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_all_verification_messages_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidTimestampRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/HubService/GetAllVerificationMessagesByFid");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "HubService",
+                "GetAllVerificationMessagesByFid",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_all_user_data_messages_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidTimestampRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/HubService/GetAllUserDataMessagesByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetAllUserDataMessagesByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_all_link_messages_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidTimestampRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetAllLinkMessagesByFid");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetAllLinkMessagesByFid"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_all_lend_storage_messages_by_fid(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::FidTimestampRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::MessagesResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/HubService/GetAllLendStorageMessagesByFid");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "HubService",
+                "GetAllLendStorageMessagesByFid",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_trie_metadata_by_prefix(
+            &mut self,
+            request: impl tonic::IntoRequest<crate::request_response::TrieNodeMetadataRequest>,
+        ) -> std::result::Result<
+            tonic::Response<crate::request_response::TrieNodeMetadataResponse>,
+            tonic::Status,
+        > {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = crate::grpc::ProtobufCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/HubService/GetTrieMetadataByPrefix");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("HubService", "GetTrieMetadataByPrefix"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
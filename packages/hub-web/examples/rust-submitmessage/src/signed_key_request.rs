@@ -0,0 +1,286 @@
+//! Signed key requests, the metadata an app attaches when adding a signer through the KeyGateway.
+//!
+//! The app's custody key signs a `SignedKeyRequest` (requestFid, key, deadline) as EIP-712 typed
+//! data. The signature goes on chain ABI-encoded as a `SignedKeyRequestMetadata` struct, which the
+//! SignedKeyRequestValidator contract checks.
+
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+
+use crate::eip712::{encode_bytes, encode_uint, hash_struct, hash_typed_data, Eip712Domain};
+use crate::eth::{recover_address, Address, EthError, EthWallet};
+
+pub const SIGNED_KEY_REQUEST_TYPE: &str =
+    "SignedKeyRequest(uint256 requestFid,bytes key,uint256 deadline)";
+
+#[derive(Debug, thiserror::Error)]
+pub enum KeyRequestError {
+    #[error(transparent)]
+    Eth(#[from] EthError),
+    #[error("metadata is not an ABI-encoded SignedKeyRequestMetadata")]
+    InvalidMetadata,
+    #[error("key request expired at {0}")]
+    Expired(u64),
+    #[error("key request was not signed by its requestSigner")]
+    InvalidSignature,
+}
+
+pub type KeyRequestResult<T = ()> = Result<T, KeyRequestError>;
+
+/// The typed data an app signs to request a key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedKeyRequest {
+    /// Fid of the app requesting the key.
+    pub request_fid: u64,
+    /// The ed25519 public key being added.
+    pub key: [u8; 32],
+    /// Unix timestamp in seconds after which the request is rejected.
+    pub deadline: u64,
+}
+
+impl SignedKeyRequest {
+    pub fn new(request_fid: u64, key: [u8; 32], deadline: u64) -> Self {
+        Self {
+            request_fid,
+            key,
+            deadline,
+        }
+    }
+
+    pub fn struct_hash(&self) -> [u8; 32] {
+        hash_struct(
+            SIGNED_KEY_REQUEST_TYPE,
+            &[
+                encode_uint(self.request_fid),
+                encode_bytes(&self.key),
+                encode_uint(self.deadline),
+            ],
+        )
+    }
+
+    pub fn digest(&self) -> [u8; 32] {
+        hash_typed_data(
+            &Eip712Domain::signed_key_request_validator(),
+            &self.struct_hash(),
+        )
+    }
+
+    /// Signs the request with the app's custody key.
+    pub fn sign(&self, custody: &EthWallet) -> KeyRequestResult<SignedKeyRequestMetadata> {
+        Ok(SignedKeyRequestMetadata {
+            request_fid: self.request_fid,
+            request_signer: custody.address(),
+            signature: custody.sign_hash(&self.digest())?.to_vec(),
+            deadline: self.deadline,
+        })
+    }
+}
+
+/// The `metadata` passed to `KeyGateway.add` for the SignedKeyRequestValidator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedKeyRequestMetadata {
+    pub request_fid: u64,
+    /// Custody address of `request_fid` that signed the request.
+    pub request_signer: Address,
+    pub signature: Vec<u8>,
+    pub deadline: u64,
+}
+
+impl SignedKeyRequestMetadata {
+    /// ABI-encodes the metadata as a single `(uint256,address,bytes,uint256)` tuple parameter.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::new();
+        encoded.extend_from_slice(&encode_uint(0x20));
+        encoded.extend_from_slice(&encode_uint(self.request_fid));
+        encoded.extend_from_slice(&[0u8; 12]);
+        encoded.extend_from_slice(&self.request_signer);
+        encoded.extend_from_slice(&encode_uint(4 * 32));
+        encoded.extend_from_slice(&encode_uint(self.deadline));
+        encoded.extend_from_slice(&encode_uint(self.signature.len() as u64));
+        encoded.extend_from_slice(&self.signature);
+        encoded.resize(encoded.len() + (32 - self.signature.len() % 32) % 32, 0);
+        encoded
+    }
+
+    /// Decodes metadata ABI-encoded by [`Self::encode`]. Offsets and lengths come from untrusted
+    /// input, so any that point outside `bytes` give [`KeyRequestError::InvalidMetadata`].
+    pub fn decode(bytes: &[u8]) -> KeyRequestResult<Self> {
+        let word = |index: usize| {
+            let start = index
+                .checked_mul(32)
+                .ok_or(KeyRequestError::InvalidMetadata)?;
+            let end = start
+                .checked_add(32)
+                .ok_or(KeyRequestError::InvalidMetadata)?;
+            bytes
+                .get(start..end)
+                .ok_or(KeyRequestError::InvalidMetadata)
+        };
+        let uint = |index: usize| -> KeyRequestResult<u64> {
+            let word = word(index)?;
+            if word[..24].iter().any(|byte| *byte != 0) {
+                return Err(KeyRequestError::InvalidMetadata);
+            }
+            Ok(u64::from_be_bytes(word[24..].try_into().unwrap()))
+        };
+        let usize_at = |index: usize| -> KeyRequestResult<usize> {
+            usize::try_from(uint(index)?).map_err(|_| KeyRequestError::InvalidMetadata)
+        };
+        let offset = |base: usize, index: usize| -> KeyRequestResult<usize> {
+            base.checked_add(index)
+                .ok_or(KeyRequestError::InvalidMetadata)
+        };
+
+        let tuple = usize_at(0)? / 32;
+        let signer = word(offset(tuple, 1)?)?;
+        if signer[..12].iter().any(|byte| *byte != 0) {
+            return Err(KeyRequestError::InvalidMetadata);
+        }
+        let tuple_start = tuple
+            .checked_mul(32)
+            .ok_or(KeyRequestError::InvalidMetadata)?;
+        let signature_offset = offset(tuple_start, usize_at(offset(tuple, 2)?)?)?;
+        if signature_offset % 32 != 0 {
+            return Err(KeyRequestError::InvalidMetadata);
+        }
+        let signature_length = usize_at(signature_offset / 32)?;
+        let signature_start = offset(signature_offset, 32)?;
+        let signature_end = offset(signature_start, signature_length)?;
+        let signature = bytes
+            .get(signature_start..signature_end)
+            .ok_or(KeyRequestError::InvalidMetadata)?;
+        Ok(Self {
+            request_fid: uint(tuple)?,
+            request_signer: signer[12..].try_into().unwrap(),
+            signature: signature.to_vec(),
+            deadline: uint(offset(tuple, 3)?)?,
+        })
+    }
+
+    /// Checks offline that `request_signer` signed a request for `key` that has not expired at the
+    /// unix timestamp `now`. Whether `request_signer` is the custody address of `request_fid` can
+    /// only be checked on chain.
+    pub fn verify(&self, key: &[u8; 32], now: u64) -> KeyRequestResult {
+        if self.deadline < now {
+            return Err(KeyRequestError::Expired(self.deadline));
+        }
+        let request = SignedKeyRequest::new(self.request_fid, *key, self.deadline);
+        match recover_address(&request.digest(), &self.signature) {
+            Ok(signer) if signer == self.request_signer => Ok(()),
+            _ => Err(KeyRequestError::InvalidSignature),
+        }
+    }
+}
+
+/// Generates a fresh ed25519 signer for `request_fid` and the metadata to add it on chain,
+/// signed by the app's custody key.
+pub fn generate_signer(
+    custody: &EthWallet,
+    request_fid: u64,
+    deadline: u64,
+) -> KeyRequestResult<(SigningKey, SignedKeyRequestMetadata)> {
+    let signer = SigningKey::generate(&mut OsRng);
+    let request = SignedKeyRequest::new(request_fid, signer.verifying_key().to_bytes(), deadline);
+    let metadata = request.sign(custody)?;
+    Ok((signer, metadata))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::wallet::TEST_KEY;
+
+    #[test]
+    fn generates_and_verifies_signer_metadata() {
+        let custody = EthWallet::from_bytes(&[9u8; 32]).unwrap();
+        let deadline = 1_700_000_000;
+        let (signer, metadata) = generate_signer(&custody, 9152, deadline).unwrap();
+        let key = signer.verifying_key().to_bytes();
+
+        let encoded = metadata.encode();
+        assert_eq!(encoded.len(), 9 * 32);
+        assert_eq!(encoded[..32], encode_uint(0x20));
+        assert_eq!(
+            SignedKeyRequestMetadata::decode(&encoded).unwrap(),
+            metadata
+        );
+
+        metadata.verify(&key, deadline).unwrap();
+        assert!(matches!(
+            metadata.verify(&key, deadline + 1),
+            Err(KeyRequestError::Expired(_))
+        ));
+        assert!(matches!(
+            metadata.verify(&[0; 32], deadline),
+            Err(KeyRequestError::InvalidSignature)
+        ));
+        let mut other = metadata.clone();
+        other.request_fid = 9153;
+        assert!(matches!(
+            other.verify(&key, deadline),
+            Err(KeyRequestError::InvalidSignature)
+        ));
+        assert!(matches!(
+            SignedKeyRequestMetadata::decode(&encoded[..8 * 32]),
+            Err(KeyRequestError::InvalidMetadata)
+        ));
+    }
+
+    #[test]
+    fn matches_signed_key_request_vector() {
+        let custody = EthWallet::from_hex(TEST_KEY).unwrap();
+        let key: [u8; 32] =
+            hex::decode("ea2f1bd3d1a6bd5cfa1b8ce2a3a5f8dd2f0d3c1c1a5a25e2d44b2d0b5c6b7f90")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let request = SignedKeyRequest::new(9152, key, 1_700_000_000);
+        assert_eq!(
+            hex::encode(request.digest()),
+            "136154cc777e73e35c1f74eaedcee0ef790369233f08d4c21b5cf98de027d509"
+        );
+        let metadata = request.sign(&custody).unwrap();
+        assert_eq!(
+            hex::encode(&metadata.signature),
+            "88335171165820638f205b8918337824480de8c28bbeac41f3c3dfd31b12f13e\
+             27514e6d7788a135f02b020825dcb4cc84a662bd40e6978661900bcb504abe4d1b"
+        );
+        metadata.verify(&key, 1_700_000_000).unwrap();
+    }
+
+    fn set_word(encoded: &mut [u8], index: usize, value: u64) {
+        encoded[index * 32..(index + 1) * 32].copy_from_slice(&encode_uint(value));
+    }
+
+    #[test]
+    fn rejects_hostile_offsets() {
+        let custody = EthWallet::from_bytes(&[9u8; 32]).unwrap();
+        let (_, metadata) = generate_signer(&custody, 9152, 1_700_000_000).unwrap();
+        let encoded = metadata.encode();
+
+        for (index, value) in [(0, u64::MAX), (0, u64::MAX - 31), (3, u64::MAX), (3, 96)] {
+            let mut hostile = encoded.clone();
+            set_word(&mut hostile, index, value);
+            assert!(matches!(
+                SignedKeyRequestMetadata::decode(&hostile),
+                Err(KeyRequestError::InvalidMetadata)
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_hostile_signature_lengths() {
+        let custody = EthWallet::from_bytes(&[9u8; 32]).unwrap();
+        let (_, metadata) = generate_signer(&custody, 9152, 1_700_000_000).unwrap();
+        let encoded = metadata.encode();
+
+        for length in [u64::MAX, u64::MAX - 31, 97] {
+            let mut hostile = encoded.clone();
+            set_word(&mut hostile, 5, length);
+            assert!(matches!(
+                SignedKeyRequestMetadata::decode(&hostile),
+                Err(KeyRequestError::InvalidMetadata)
+            ));
+        }
+    }
+}
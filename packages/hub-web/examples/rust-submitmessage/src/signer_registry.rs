@@ -0,0 +1,262 @@
+//! Which ed25519 keys are signers of which fids, from the KeyRegistry's on-chain events.
+//!
+//! Hubs only accept messages from keys that are active signers of the message's fid, and revoke
+//! every message of a key once it is removed. [`OnChainSignerRegistry`] replays the signer
+//! `OnChainEvent`s to answer the same question offline, at any block.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::frames::{FrameResult, SignerLookup};
+use crate::http::{from_json, JsonError};
+use crate::hub_event::{HubEvent, HubEventType};
+use crate::onchain_event::{OnChainEvent, OnChainEventType, SignerEventType};
+use crate::subscriber::{BoxError, HubEventHandler};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SignerRegistryError {
+    #[error("failed to read events: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("events are not valid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid event: {0}")]
+    Event(#[from] JsonError),
+}
+
+pub type SignerRegistryResult<T = ()> = Result<T, SignerRegistryError>;
+
+/// The state of a key for an fid at some block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignerStatus {
+    Active,
+    /// The key was removed, or reset by the admin.
+    Revoked,
+    /// The key had not been added.
+    Unknown,
+}
+
+/// Answers whether a key was a signer of an fid at a block.
+pub trait SignerRegistry: Send + Sync {
+    fn signer_status(&self, fid: u64, signer: &[u8], block_number: u32) -> SignerStatus;
+}
+
+/// The block number, log index and type of a signer event.
+type SignerEvent = (u32, u32, SignerEventType);
+
+/// A [`SignerRegistry`] built from signer `OnChainEvent`s.
+#[derive(Debug, Clone, Default)]
+pub struct OnChainSignerRegistry {
+    /// The events of each fid and key, in chain order.
+    events: HashMap<(u64, Vec<u8>), Vec<SignerEvent>>,
+}
+
+impl OnChainSignerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads events saved as hub JSON, either a `{"events": [...]}` response from
+    /// `/v1/onChainSignersByFid` or `/v1/onChainEventsByFid`, or a bare array of events. Any
+    /// other JSON is an error rather than an empty registry.
+    pub fn load(path: impl AsRef<Path>) -> SignerRegistryResult<Self> {
+        let value: Value = serde_json::from_slice(&std::fs::read(path)?)?;
+        let events = value.get("events").unwrap_or(&value);
+        let Some(events) = events.as_array() else {
+            return Err(JsonError::InvalidValue {
+                field: "events".to_string(),
+                value: events.clone(),
+            }
+            .into());
+        };
+        let mut registry = Self::new();
+        for event in events {
+            registry.merge_event(&from_json(event)?);
+        }
+        Ok(registry)
+    }
+
+    /// Records a signer event. Returns false for other events and events already recorded.
+    pub fn merge_event(&mut self, event: &OnChainEvent) -> bool {
+        if event.get_field_type() != OnChainEventType::EVENT_TYPE_SIGNER
+            || !event.has_signer_event_body()
+        {
+            return false;
+        }
+        let body = event.get_signer_event_body();
+        let entry = (
+            event.get_block_number(),
+            event.get_log_index(),
+            body.get_event_type(),
+        );
+        let events = self
+            .events
+            .entry((event.get_fid(), body.get_key().to_vec()))
+            .or_default();
+        match events.binary_search_by_key(&(entry.0, entry.1), |(block, log, _)| (*block, *log)) {
+            Ok(_) => false,
+            Err(index) => {
+                events.insert(index, entry);
+                true
+            }
+        }
+    }
+
+    /// Records the on-chain event of a `HUB_EVENT_TYPE_MERGE_ON_CHAIN_EVENT`.
+    pub fn apply_event(&mut self, event: &HubEvent) -> bool {
+        event.get_field_type() == HubEventType::HUB_EVENT_TYPE_MERGE_ON_CHAIN_EVENT
+            && self.merge_event(event.get_merge_on_chain_event_body().get_on_chain_event())
+    }
+
+    /// The keys of `fid` that are active as of the latest recorded event.
+    pub fn active_signers(&self, fid: u64) -> Vec<&[u8]> {
+        self.events
+            .keys()
+            .filter(|(key_fid, key)| {
+                *key_fid == fid && self.signer_status(fid, key, u32::MAX) == SignerStatus::Active
+            })
+            .map(|(_, key)| key.as_slice())
+            .collect()
+    }
+}
+
+impl SignerRegistry for OnChainSignerRegistry {
+    fn signer_status(&self, fid: u64, signer: &[u8], block_number: u32) -> SignerStatus {
+        let last = self.events.get(&(fid, signer.to_vec())).and_then(|events| {
+            events
+                .iter()
+                .take_while(|(block, _, _)| *block <= block_number)
+                .last()
+        });
+        match last {
+            Some((_, _, SignerEventType::SIGNER_EVENT_TYPE_ADD)) => SignerStatus::Active,
+            Some((_, _, SignerEventType::SIGNER_EVENT_TYPE_NONE)) | None => SignerStatus::Unknown,
+            Some(_) => SignerStatus::Revoked,
+        }
+    }
+}
+
+#[async_trait]
+impl HubEventHandler for OnChainSignerRegistry {
+    async fn handle_event(&mut self, event: &HubEvent) -> Result<(), BoxError> {
+        self.apply_event(event);
+        Ok(())
+    }
+}
+
+/// Checks frame signers against the latest recorded events.
+#[async_trait]
+impl SignerLookup for OnChainSignerRegistry {
+    async fn is_active_signer(&self, fid: u64, signer: &[u8]) -> FrameResult<bool> {
+        Ok(self.signer_status(fid, signer, u32::MAX) == SignerStatus::Active)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::to_json;
+    use crate::hub_event::MergeOnChainEventBody;
+    use crate::onchain_event::SignerEventBody;
+    use std::io::Write;
+
+    fn signer_event(
+        fid: u64,
+        key: &[u8],
+        event_type: SignerEventType,
+        block_number: u32,
+    ) -> OnChainEvent {
+        let mut body = SignerEventBody::new();
+        body.set_key(key.to_vec());
+        body.set_key_type(1);
+        body.set_event_type(event_type);
+        let mut event = OnChainEvent::new();
+        event.set_field_type(OnChainEventType::EVENT_TYPE_SIGNER);
+        event.set_fid(fid);
+        event.set_block_number(block_number);
+        event.set_signer_event_body(body);
+        event
+    }
+
+    #[test]
+    fn tracks_signer_status_by_block() {
+        let mut registry = OnChainSignerRegistry::new();
+        let add = signer_event(2, &[1; 32], SignerEventType::SIGNER_EVENT_TYPE_ADD, 100);
+        let remove = signer_event(2, &[1; 32], SignerEventType::SIGNER_EVENT_TYPE_REMOVE, 200);
+        // Events may arrive out of order.
+        assert!(registry.merge_event(&remove));
+        assert!(registry.merge_event(&add));
+        assert!(!registry.merge_event(&add));
+
+        assert_eq!(
+            registry.signer_status(2, &[1; 32], 99),
+            SignerStatus::Unknown
+        );
+        assert_eq!(
+            registry.signer_status(2, &[1; 32], 150),
+            SignerStatus::Active
+        );
+        assert_eq!(
+            registry.signer_status(2, &[1; 32], 200),
+            SignerStatus::Revoked
+        );
+        assert_eq!(
+            registry.signer_status(3, &[1; 32], 150),
+            SignerStatus::Unknown
+        );
+        assert!(registry.active_signers(2).is_empty());
+
+        let mut body = MergeOnChainEventBody::new();
+        body.set_on_chain_event(signer_event(
+            2,
+            &[2; 32],
+            SignerEventType::SIGNER_EVENT_TYPE_ADD,
+            300,
+        ));
+        let mut event = HubEvent::new();
+        event.set_field_type(HubEventType::HUB_EVENT_TYPE_MERGE_ON_CHAIN_EVENT);
+        event.set_merge_on_chain_event_body(body);
+        assert!(registry.apply_event(&event));
+        assert_eq!(registry.active_signers(2), vec![&[2u8; 32][..]]);
+    }
+
+    #[test]
+    fn loads_events_from_hub_json() {
+        let events = [
+            signer_event(2, &[1; 32], SignerEventType::SIGNER_EVENT_TYPE_ADD, 100),
+            signer_event(2, &[3; 32], SignerEventType::SIGNER_EVENT_TYPE_ADD, 100),
+        ];
+        let json =
+            serde_json::json!({ "events": events.iter().map(|e| to_json(e)).collect::<Vec<_>>() });
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(json.to_string().as_bytes()).unwrap();
+
+        let registry = OnChainSignerRegistry::load(file.path()).unwrap();
+        assert_eq!(
+            registry.signer_status(2, &[1; 32], 100),
+            SignerStatus::Active
+        );
+        assert_eq!(registry.active_signers(2).len(), 2);
+    }
+
+    #[test]
+    fn rejects_json_without_events() {
+        for json in [r#"{"messages": []}"#, r#"{"events": {}}"#, "42", "null"] {
+            let mut file = tempfile::NamedTempFile::new().unwrap();
+            file.write_all(json.as_bytes()).unwrap();
+            assert!(
+                matches!(
+                    OnChainSignerRegistry::load(file.path()),
+                    Err(SignerRegistryError::Event(JsonError::InvalidValue { .. }))
+                ),
+                "{json}"
+            );
+        }
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"[]").unwrap();
+        assert!(OnChainSignerRegistry::load(file.path()).is_ok());
+    }
+}
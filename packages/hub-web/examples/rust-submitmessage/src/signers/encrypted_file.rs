@@ -0,0 +1,109 @@
+use std::path::Path;
+
+use argon2::Argon2;
+use async_trait::async_trait;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use ed25519_dalek::{Signature, SigningKey, VerifyingKey};
+use rand::RngCore;
+
+use super::{InMemorySigner, MessageSigner, SignerError};
+
+const MAGIC: &[u8; 4] = b"FCK1";
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+
+/// Signs with an ed25519 key stored encrypted on disk.
+///
+/// The file holds `FCK1 || salt || nonce || ciphertext`, where the ChaCha20-Poly1305 key is derived
+/// from a passphrase with Argon2id.
+#[derive(Debug, Clone)]
+pub struct EncryptedFileSigner {
+    inner: InMemorySigner,
+}
+
+impl EncryptedFileSigner {
+    /// Decrypts the key stored at `path` with `passphrase`.
+    pub fn load(path: impl AsRef<Path>, passphrase: &[u8]) -> Result<Self, SignerError> {
+        let contents = std::fs::read(path)?;
+        let header = MAGIC.len() + SALT_LENGTH + NONCE_LENGTH;
+        if contents.len() <= header || &contents[..MAGIC.len()] != MAGIC {
+            return Err(SignerError::InvalidKeyFile);
+        }
+        let salt = &contents[MAGIC.len()..MAGIC.len() + SALT_LENGTH];
+        let nonce = Nonce::from_slice(&contents[MAGIC.len() + SALT_LENGTH..header]);
+
+        let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, salt)?);
+        let secret_key = cipher
+            .decrypt(nonce, &contents[header..])
+            .map_err(|_| SignerError::Decrypt)?;
+        Ok(Self {
+            inner: InMemorySigner::from_bytes(&secret_key)?,
+        })
+    }
+
+    /// Encrypts `signing_key` with `passphrase` and writes it to `path`.
+    pub fn save(
+        path: impl AsRef<Path>,
+        signing_key: &SigningKey,
+        passphrase: &[u8],
+    ) -> Result<(), SignerError> {
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut nonce = [0u8; NONCE_LENGTH];
+        rand::thread_rng().fill_bytes(&mut salt);
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &salt)?);
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), signing_key.to_bytes().as_slice())
+            .map_err(|_| SignerError::InvalidKey)?;
+
+        let mut contents = MAGIC.to_vec();
+        contents.extend_from_slice(&salt);
+        contents.extend_from_slice(&nonce);
+        contents.extend_from_slice(&ciphertext);
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+fn derive_key(passphrase: &[u8], salt: &[u8]) -> Result<Key, SignerError> {
+    let mut key = Key::default();
+    Argon2::default()
+        .hash_password_into(passphrase, salt, &mut key)
+        .map_err(|_| SignerError::InvalidKeyFile)?;
+    Ok(key)
+}
+
+#[async_trait]
+impl MessageSigner for EncryptedFileSigner {
+    async fn sign(&self, hash: &[u8]) -> Result<Signature, SignerError> {
+        self.inner.sign(hash).await
+    }
+
+    fn public_key(&self) -> VerifyingKey {
+        self.inner.public_key()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_signing_key;
+
+    #[test]
+    fn round_trips_key_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("signer.key");
+        let signing_key = test_signing_key();
+
+        EncryptedFileSigner::save(&path, &signing_key, b"hunter2").unwrap();
+        let signer = EncryptedFileSigner::load(&path, b"hunter2").unwrap();
+        assert_eq!(signer.public_key(), signing_key.verifying_key());
+
+        assert!(matches!(
+            EncryptedFileSigner::load(&path, b"wrong"),
+            Err(SignerError::Decrypt)
+        ));
+    }
+}
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+
+use super::{MessageSigner, SignerError};
+
+/// Signs with an ed25519 key held in process memory.
+#[derive(Debug, Clone)]
+pub struct InMemorySigner {
+    signing_key: SigningKey,
+}
+
+impl InMemorySigner {
+    pub fn new(signing_key: SigningKey) -> Self {
+        Self { signing_key }
+    }
+
+    /// Creates a signer from a 32-byte ed25519 secret key.
+    pub fn from_bytes(secret_key: &[u8]) -> Result<Self, SignerError> {
+        let secret_key = <[u8; 32]>::try_from(secret_key).map_err(|_| SignerError::InvalidKey)?;
+        Ok(Self::new(SigningKey::from_bytes(&secret_key)))
+    }
+}
+
+#[async_trait]
+impl MessageSigner for InMemorySigner {
+    async fn sign(&self, hash: &[u8]) -> Result<Signature, SignerError> {
+        Ok(self.signing_key.sign(hash))
+    }
+
+    fn public_key(&self) -> VerifyingKey {
+        self.signing_key.verifying_key()
+    }
+}
@@ -0,0 +1,40 @@
+//! Signers that produce the ed25519 signature over a message hash.
+//!
+//! `MessageBuilder` only talks to a `MessageSigner`, so the key material can live in memory, in an
+//! encrypted file, or in a separate process entirely.
+
+use async_trait::async_trait;
+use ed25519_dalek::{Signature, VerifyingKey};
+
+mod encrypted_file;
+mod in_memory;
+mod unix_socket;
+
+pub use encrypted_file::EncryptedFileSigner;
+pub use in_memory::InMemorySigner;
+pub use unix_socket::{serve_unix_socket_signer, UnixSocketSigner};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SignerError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid key file")]
+    InvalidKeyFile,
+    #[error("failed to decrypt key file")]
+    Decrypt,
+    #[error("invalid ed25519 key")]
+    InvalidKey,
+    #[error("hash is too long")]
+    InvalidHash,
+    #[error("remote signer error: {0}")]
+    Remote(String),
+}
+
+#[async_trait]
+pub trait MessageSigner: Send + Sync {
+    /// Signs a 20-byte message hash.
+    async fn sign(&self, hash: &[u8]) -> Result<Signature, SignerError>;
+
+    /// The ed25519 public key placed in `Message.signer`.
+    fn public_key(&self) -> VerifyingKey;
+}
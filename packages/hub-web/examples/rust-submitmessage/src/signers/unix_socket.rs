@@ -0,0 +1,259 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::Mutex;
+
+use super::{MessageSigner, SignerError};
+
+// Requests are a one byte op code followed by its payload. Responses are a one byte status
+// followed by the result on success, or a length-prefixed error message on failure.
+const OP_PUBLIC_KEY: u8 = 1;
+const OP_SIGN: u8 = 2;
+const STATUS_OK: u8 = 0;
+const STATUS_ERR: u8 = 1;
+
+/// Delegates signing to a process listening on a local Unix socket, so the key never has to be
+/// loaded by the process building messages.
+///
+/// Requests share one connection. Each exchange runs in its own task that holds the connection
+/// until the response has been read, so dropping a `sign` future part way through can't leave a
+/// stale response for the next request. A connection that fails mid-exchange is dropped, and the
+/// next request connects again.
+#[derive(Debug)]
+pub struct UnixSocketSigner {
+    path: PathBuf,
+    stream: Arc<Mutex<Option<UnixStream>>>,
+    public_key: VerifyingKey,
+}
+
+impl UnixSocketSigner {
+    /// Connects to the signer at `path` and fetches its public key.
+    pub async fn connect(path: impl AsRef<Path>) -> Result<Self, SignerError> {
+        let path = path.as_ref().to_path_buf();
+        let mut stream = UnixStream::connect(&path).await?;
+        stream.write_all(&[OP_PUBLIC_KEY]).await?;
+        let public_key = read_response::<32>(&mut stream).await?;
+        let public_key =
+            VerifyingKey::from_bytes(&public_key).map_err(|_| SignerError::InvalidKey)?;
+        Ok(Self {
+            path,
+            stream: Arc::new(Mutex::new(Some(stream))),
+            public_key,
+        })
+    }
+}
+
+async fn read_response<const N: usize>(stream: &mut UnixStream) -> Result<[u8; N], SignerError> {
+    match stream.read_u8().await? {
+        STATUS_OK => {
+            let mut payload = [0u8; N];
+            stream.read_exact(&mut payload).await?;
+            Ok(payload)
+        }
+        _ => {
+            let mut message = vec![0u8; stream.read_u8().await? as usize];
+            stream.read_exact(&mut message).await?;
+            Err(SignerError::Remote(
+                String::from_utf8_lossy(&message).into_owned(),
+            ))
+        }
+    }
+}
+
+#[async_trait]
+impl MessageSigner for UnixSocketSigner {
+    async fn sign(&self, hash: &[u8]) -> Result<Signature, SignerError> {
+        let length = u8::try_from(hash.len()).map_err(|_| SignerError::InvalidHash)?;
+        let mut request = vec![OP_SIGN, length];
+        request.extend_from_slice(hash);
+        let stream = self.stream.clone();
+        let path = self.path.clone();
+        let exchange = tokio::spawn(async move {
+            let mut stream = stream.lock().await;
+            let connection = match stream.as_mut() {
+                Some(connection) => connection,
+                None => stream.insert(UnixStream::connect(&path).await?),
+            };
+            let response = match connection.write_all(&request).await {
+                Ok(()) => read_response::<64>(connection).await,
+                Err(e) => Err(e.into()),
+            };
+            // An error response is read in full; anything else leaves the connection unusable
+            if matches!(response, Err(SignerError::Io(_))) {
+                *stream = None;
+            }
+            response
+        });
+        let signature = exchange.await.map_err(std::io::Error::other)??;
+        Ok(Signature::from_bytes(&signature))
+    }
+
+    fn public_key(&self) -> VerifyingKey {
+        self.public_key
+    }
+}
+
+/// Serves signing requests for `signing_key` on `listener`.
+///
+/// This is a local stand-in for a remote signer, useful for development and tests.
+pub async fn serve_unix_socket_signer(
+    listener: UnixListener,
+    signing_key: SigningKey,
+) -> Result<(), SignerError> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let signing_key = signing_key.clone();
+        tokio::spawn(async move {
+            // A failed connection only affects that client
+            let _ = handle_connection(stream, &signing_key).await;
+        });
+    }
+}
+
+async fn handle_connection(
+    mut stream: UnixStream,
+    signing_key: &SigningKey,
+) -> Result<(), SignerError> {
+    loop {
+        let op = match stream.read_u8().await {
+            Ok(op) => op,
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        match op {
+            OP_PUBLIC_KEY => {
+                stream.write_all(&[STATUS_OK]).await?;
+                stream
+                    .write_all(signing_key.verifying_key().as_bytes())
+                    .await?;
+            }
+            OP_SIGN => {
+                let mut hash = vec![0u8; stream.read_u8().await? as usize];
+                stream.read_exact(&mut hash).await?;
+                stream.write_all(&[STATUS_OK]).await?;
+                stream
+                    .write_all(&signing_key.sign(&hash).to_bytes())
+                    .await?;
+            }
+            _ => {
+                let message = b"unknown op";
+                stream.write_all(&[STATUS_ERR, message.len() as u8]).await?;
+                stream.write_all(message).await?;
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_signing_key;
+    use ed25519_dalek::Verifier;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn signs_over_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("signer.sock");
+        let signing_key = test_signing_key();
+        let listener = UnixListener::bind(&path).unwrap();
+        tokio::spawn(serve_unix_socket_signer(listener, signing_key.clone()));
+
+        let signer = UnixSocketSigner::connect(&path).await.unwrap();
+        assert_eq!(signer.public_key(), signing_key.verifying_key());
+
+        let hash = [3u8; 20];
+        let signature = signer.sign(&hash).await.unwrap();
+        assert!(signing_key
+            .verifying_key()
+            .verify(&hash, &signature)
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn dropped_requests_leave_the_connection_usable() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("signer.sock");
+        let signing_key = test_signing_key();
+        let listener = UnixListener::bind(&path).unwrap();
+        let server_key = signing_key.clone();
+        // Answers every sign request late, so the client can give up on one mid-exchange.
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream.read_u8().await.unwrap();
+            stream.write_all(&[STATUS_OK]).await.unwrap();
+            stream
+                .write_all(server_key.verifying_key().as_bytes())
+                .await
+                .unwrap();
+            while stream.read_u8().await.is_ok() {
+                let mut hash = vec![0u8; stream.read_u8().await.unwrap() as usize];
+                stream.read_exact(&mut hash).await.unwrap();
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                stream.write_all(&[STATUS_OK]).await.unwrap();
+                stream
+                    .write_all(&server_key.sign(&hash).to_bytes())
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let signer = UnixSocketSigner::connect(&path).await.unwrap();
+        let dropped = tokio::time::timeout(Duration::from_millis(20), signer.sign(&[1u8; 20]));
+        assert!(dropped.await.is_err());
+
+        let hash = [2u8; 20];
+        let signature = signer.sign(&hash).await.unwrap();
+        assert!(signing_key
+            .verifying_key()
+            .verify(&hash, &signature)
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn reconnects_after_a_broken_response() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("signer.sock");
+        let signing_key = test_signing_key();
+        let listener = UnixListener::bind(&path).unwrap();
+        let server_key = signing_key.clone();
+        // Closes the first connection half way through a signature, then serves normally.
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            stream.read_u8().await.unwrap();
+            stream.write_all(&[STATUS_OK]).await.unwrap();
+            stream
+                .write_all(server_key.verifying_key().as_bytes())
+                .await
+                .unwrap();
+            stream.read_u8().await.unwrap();
+            let mut hash = vec![0u8; stream.read_u8().await.unwrap() as usize];
+            stream.read_exact(&mut hash).await.unwrap();
+            stream.write_all(&[STATUS_OK]).await.unwrap();
+            stream
+                .write_all(&server_key.sign(&hash).to_bytes()[..32])
+                .await
+                .unwrap();
+            drop(stream);
+            serve_unix_socket_signer(listener, server_key).await
+        });
+
+        let signer = UnixSocketSigner::connect(&path).await.unwrap();
+        assert!(matches!(
+            signer.sign(&[1u8; 20]).await,
+            Err(SignerError::Io(_))
+        ));
+
+        let hash = [2u8; 20];
+        let signature = signer.sign(&hash).await.unwrap();
+        assert!(signing_key
+            .verifying_key()
+            .verify(&hash, &signature)
+            .is_ok());
+    }
+}
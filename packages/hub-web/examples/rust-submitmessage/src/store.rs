@@ -0,0 +1,593 @@
+//! An in-memory message store that resolves conflicts the same way hubs do.
+//!
+//! Every message belongs to a set identified by a [`MessageKey`], and each set holds at most one
+//! message: the add or remove that won. Within a set, later timestamps win; on equal timestamps a
+//! remove beats an add, and otherwise the message with the higher hash wins. Casts are the
+//! exception: a `CastRemove` always beats the `CastAdd` it targets, whatever their timestamps, and
+//! stays behind as a tombstone so the add can't be merged again.
+//!
+//! Once an fid's storage units are known, each of its stores (casts, links, reactions, user data,
+//! verifications) is capped at the limits from [`crate::limits`]. Merging past the limit prunes the
+//! oldest messages of that store, by timestamp and then hash, and reports a prune event for each.
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::errors::{HubError, HubErrorCode};
+use crate::hub_event::{HubEvent, HubEventType, PruneMessageBody};
+use crate::limits::{store_limit, LIMITED_STORE_TYPES};
+use crate::message::{
+    Message, MessageData, MessageType, ReactionBody_oneof_target, ReactionType, UserDataType,
+};
+use crate::request_response::{StorageUnitDetails, StoreType};
+use crate::validations::{message_data, validate_message, ValidationError};
+
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    #[error(transparent)]
+    Invalid(#[from] ValidationError),
+    #[error("{0:?} messages are not stored")]
+    UnsupportedType(MessageType),
+}
+
+pub type StoreResult<T = ()> = Result<T, StoreError>;
+
+/// The cast or url a reaction set is keyed by.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TargetKey {
+    Cast { fid: u64, hash: Vec<u8> },
+    Url(String),
+}
+
+/// Identifies the set a message competes in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageKey {
+    /// A cast, by its own hash for `CastAdd` and by `target_hash` for `CastRemove`.
+    Cast {
+        fid: u64,
+        hash: Vec<u8>,
+    },
+    Reaction {
+        fid: u64,
+        reaction_type: ReactionType,
+        target: TargetKey,
+    },
+    Link {
+        fid: u64,
+        link_type: String,
+        target_fid: u64,
+    },
+    UserData {
+        fid: u64,
+        user_data_type: UserDataType,
+    },
+    Verification {
+        fid: u64,
+        address: Vec<u8>,
+    },
+}
+
+impl MessageKey {
+    /// The key of the set `data` belongs to, given the hash of its message.
+    pub fn of(data: &MessageData, hash: &[u8]) -> StoreResult<Self> {
+        let fid = data.get_fid();
+        Ok(match data.get_field_type() {
+            MessageType::MESSAGE_TYPE_CAST_ADD => MessageKey::Cast {
+                fid,
+                hash: hash.to_vec(),
+            },
+            MessageType::MESSAGE_TYPE_CAST_REMOVE => MessageKey::Cast {
+                fid,
+                hash: data.get_cast_remove_body().get_target_hash().to_vec(),
+            },
+            MessageType::MESSAGE_TYPE_REACTION_ADD | MessageType::MESSAGE_TYPE_REACTION_REMOVE => {
+                let body = data.get_reaction_body();
+                let target = match &body.target {
+                    Some(ReactionBody_oneof_target::target_cast_id(cast_id)) => TargetKey::Cast {
+                        fid: cast_id.get_fid(),
+                        hash: cast_id.get_hash().to_vec(),
+                    },
+                    Some(ReactionBody_oneof_target::target_url(url)) => TargetKey::Url(url.clone()),
+                    None => return Err(ValidationError::MissingTarget.into()),
+                };
+                MessageKey::Reaction {
+                    fid,
+                    reaction_type: body.get_field_type(),
+                    target,
+                }
+            }
+            MessageType::MESSAGE_TYPE_LINK_ADD | MessageType::MESSAGE_TYPE_LINK_REMOVE => {
+                let body = data.get_link_body();
+                MessageKey::Link {
+                    fid,
+                    link_type: body.get_field_type().to_string(),
+                    target_fid: body.get_target_fid(),
+                }
+            }
+            MessageType::MESSAGE_TYPE_USER_DATA_ADD => MessageKey::UserData {
+                fid,
+                user_data_type: data.get_user_data_body().get_field_type(),
+            },
+            MessageType::MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS => MessageKey::Verification {
+                fid,
+                address: data
+                    .get_verification_add_address_body()
+                    .get_address()
+                    .to_vec(),
+            },
+            MessageType::MESSAGE_TYPE_VERIFICATION_REMOVE => MessageKey::Verification {
+                fid,
+                address: data.get_verification_remove_body().get_address().to_vec(),
+            },
+            message_type => return Err(StoreError::UnsupportedType(message_type)),
+        })
+    }
+
+    pub fn fid(&self) -> u64 {
+        match self {
+            MessageKey::Cast { fid, .. }
+            | MessageKey::Reaction { fid, .. }
+            | MessageKey::Link { fid, .. }
+            | MessageKey::UserData { fid, .. }
+            | MessageKey::Verification { fid, .. } => *fid,
+        }
+    }
+
+    /// The store whose storage limit the set counts against.
+    pub fn store_type(&self) -> StoreType {
+        match self {
+            MessageKey::Cast { .. } => StoreType::STORE_TYPE_CASTS,
+            MessageKey::Reaction { .. } => StoreType::STORE_TYPE_REACTIONS,
+            MessageKey::Link { .. } => StoreType::STORE_TYPE_LINKS,
+            MessageKey::UserData { .. } => StoreType::STORE_TYPE_USER_DATA,
+            MessageKey::Verification { .. } => StoreType::STORE_TYPE_VERIFICATIONS,
+        }
+    }
+}
+
+/// The outcome of merging a message into a [`Store`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MergeResult {
+    /// The message won its set; `deleted` holds the message it replaced, if any, and `pruned`
+    /// a `HUB_EVENT_TYPE_PRUNE_MESSAGE` event for each message that no longer fits in storage.
+    Merged {
+        deleted: Vec<Message>,
+        pruned: Vec<HubEvent>,
+    },
+    /// The same message is already stored.
+    Duplicate,
+    /// A message that wins against this one is already stored.
+    Conflict,
+    /// The fid's store is full and the message is older than everything in it.
+    Prunable,
+}
+
+impl MergeResult {
+    pub fn is_merged(&self) -> bool {
+        matches!(self, MergeResult::Merged { .. })
+    }
+
+    /// The error a hub returns when submitting a message that was not merged.
+    pub fn hub_error(&self) -> Option<HubError> {
+        match self {
+            MergeResult::Merged { .. } => None,
+            MergeResult::Duplicate => Some(HubError::new(
+                HubErrorCode::BadRequestDuplicate,
+                "message has already been merged",
+            )),
+            MergeResult::Conflict => Some(HubError::new(
+                HubErrorCode::BadRequestConflict,
+                "message conflicts with a more recent message",
+            )),
+            MergeResult::Prunable => Some(HubError::new(
+                HubErrorCode::BadRequestPrunable,
+                "message would be pruned",
+            )),
+        }
+    }
+}
+
+/// A `HUB_EVENT_TYPE_PRUNE_MESSAGE` event for `message`, as a hub emits it.
+pub fn prune_event(message: Message) -> HubEvent {
+    let mut body = PruneMessageBody::new();
+    body.set_message(message);
+    let mut event = HubEvent::new();
+    event.set_field_type(HubEventType::HUB_EVENT_TYPE_PRUNE_MESSAGE);
+    event.set_prune_message_body(body);
+    event
+}
+
+/// Orders the messages of a store for pruning: oldest timestamp first, then lowest hash.
+type PruneOrder = (u32, Vec<u8>);
+
+#[derive(Debug, Clone)]
+struct Entry {
+    message: Message,
+    data: MessageData,
+}
+
+impl Entry {
+    fn is_remove(&self) -> bool {
+        matches!(
+            self.data.get_field_type(),
+            MessageType::MESSAGE_TYPE_CAST_REMOVE
+                | MessageType::MESSAGE_TYPE_REACTION_REMOVE
+                | MessageType::MESSAGE_TYPE_LINK_REMOVE
+                | MessageType::MESSAGE_TYPE_VERIFICATION_REMOVE
+        )
+    }
+
+    fn prune_order(&self) -> PruneOrder {
+        (self.data.get_timestamp(), self.message.get_hash().to_vec())
+    }
+
+    /// Orders two messages of the same set; the greater one wins.
+    fn compare(&self, other: &Entry, key: &MessageKey) -> Ordering {
+        let timestamp = self.data.get_timestamp().cmp(&other.data.get_timestamp());
+        let remove = self.is_remove().cmp(&other.is_remove());
+        let hash = self.message.get_hash().cmp(other.message.get_hash());
+        match key {
+            MessageKey::Cast { .. } => remove.then(timestamp).then(hash),
+            _ => timestamp.then(remove).then(hash),
+        }
+    }
+}
+
+/// Casts, reactions, links, user data and verifications merged with hub semantics.
+///
+/// Fids without storage units set through [`Store::set_storage_units`] are not limited.
+#[derive(Debug, Clone)]
+pub struct Store {
+    entries: HashMap<MessageKey, Entry>,
+    by_store: HashMap<(u64, StoreType), BTreeMap<PruneOrder, MessageKey>>,
+    units: HashMap<u64, Vec<StorageUnitDetails>>,
+    validate: bool,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Store {
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            by_store: HashMap::new(),
+            units: HashMap::new(),
+            validate: true,
+        }
+    }
+
+    /// Whether merged messages are validated (including their hash and signature) first.
+    /// Defaults to true.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Merges `message` into its set, replacing the message it beats and pruning the fid's
+    /// oldest messages if its store is over the limit.
+    pub fn merge(&mut self, message: Message) -> StoreResult<MergeResult> {
+        if self.validate {
+            validate_message(&message)?;
+        }
+        let data = message_data(&message)?;
+        let key = MessageKey::of(&data, message.get_hash())?;
+        let entry = Entry { message, data };
+
+        match self.entries.get(&key) {
+            Some(existing) if existing.message.get_hash() == entry.message.get_hash() => {
+                return Ok(MergeResult::Duplicate);
+            }
+            Some(existing) if existing.compare(&entry, &key) == Ordering::Greater => {
+                return Ok(MergeResult::Conflict);
+            }
+            _ => {}
+        }
+        if self.is_prunable(&key, &entry) {
+            return Ok(MergeResult::Prunable);
+        }
+
+        let deleted = self.remove(&key);
+        let (fid, store_type) = (key.fid(), key.store_type());
+        self.by_store
+            .entry((fid, store_type))
+            .or_default()
+            .insert(entry.prune_order(), key.clone());
+        self.entries.insert(key, entry);
+        Ok(MergeResult::Merged {
+            deleted: deleted.into_iter().collect(),
+            pruned: self.prune(fid, store_type),
+        })
+    }
+
+    /// Sets the storage units `fid` holds and prunes every store that is now over its limit.
+    pub fn set_storage_units(&mut self, fid: u64, units: Vec<StorageUnitDetails>) -> Vec<HubEvent> {
+        self.units.insert(fid, units);
+        LIMITED_STORE_TYPES
+            .iter()
+            .flat_map(|store_type| self.prune(fid, *store_type))
+            .collect()
+    }
+
+    /// The number of messages `fid` may keep in `store_type`, if it is limited.
+    pub fn limit(&self, fid: u64, store_type: StoreType) -> Option<u64> {
+        self.units
+            .get(&fid)
+            .map(|units| store_limit(store_type, units))
+    }
+
+    /// Whether `entry` would be pruned right away, as hubs reject such messages.
+    fn is_prunable(&self, key: &MessageKey, entry: &Entry) -> bool {
+        let Some(limit) = self.limit(key.fid(), key.store_type()) else {
+            return false;
+        };
+        let Some(messages) = self.by_store.get(&(key.fid(), key.store_type())) else {
+            return limit == 0;
+        };
+        (messages.len() as u64) >= limit
+            && messages
+                .keys()
+                .next()
+                .is_none_or(|oldest| entry.prune_order() < *oldest)
+    }
+
+    fn prune(&mut self, fid: u64, store_type: StoreType) -> Vec<HubEvent> {
+        let Some(limit) = self.limit(fid, store_type) else {
+            return Vec::new();
+        };
+        let Some(messages) = self.by_store.get_mut(&(fid, store_type)) else {
+            return Vec::new();
+        };
+        let mut pruned = Vec::new();
+        while messages.len() as u64 > limit {
+            let (_, key) = messages.pop_first().expect("store is not empty");
+            if let Some(entry) = self.entries.remove(&key) {
+                pruned.push(prune_event(entry.message));
+            }
+        }
+        pruned
+    }
+
+    /// Removes the message of the set identified by `key`.
+    fn remove(&mut self, key: &MessageKey) -> Option<Message> {
+        let entry = self.entries.remove(key)?;
+        if let Some(messages) = self.by_store.get_mut(&(key.fid(), key.store_type())) {
+            messages.remove(&entry.prune_order());
+        }
+        Some(entry.message)
+    }
+
+    /// The message that currently wins the set identified by `key`, add or remove.
+    pub fn get(&self, key: &MessageKey) -> Option<&Message> {
+        self.entries.get(key).map(|entry| &entry.message)
+    }
+
+    /// The add that currently wins the set identified by `key`, if it wasn't removed.
+    pub fn get_add(&self, key: &MessageKey) -> Option<&Message> {
+        self.entries
+            .get(key)
+            .filter(|entry| !entry.is_remove())
+            .map(|entry| &entry.message)
+    }
+
+    /// Every message in the store, in no particular order.
+    pub fn messages(&self) -> impl Iterator<Item = &Message> {
+        self.entries.values().map(|entry| &entry.message)
+    }
+
+    /// Every message stored for `fid`, oldest first (by timestamp, then hash).
+    pub fn messages_by_fid(&self, fid: u64) -> Vec<&Message> {
+        let mut messages: Vec<_> = self
+            .by_store
+            .iter()
+            .filter(|((store_fid, _), _)| *store_fid == fid)
+            .flat_map(|(_, messages)| messages.iter())
+            .collect();
+        messages.sort_by(|a, b| a.0.cmp(b.0));
+        messages
+            .into_iter()
+            .map(|(_, key)| &self.entries[key].message)
+            .collect()
+    }
+
+    /// Every message `fid` has in `store_type`, in the order they would be pruned.
+    pub fn messages_by_store(&self, fid: u64, store_type: StoreType) -> Vec<&Message> {
+        self.by_store
+            .get(&(fid, store_type))
+            .into_iter()
+            .flat_map(|messages| messages.values())
+            .map(|key| &self.entries[key].message)
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bodies::{
+        cast_id, CastAddBuilder, CastRemoveBuilder, LinkBuilder, ReactionBuilder, ReactionTarget,
+        UserDataBuilder, VerificationAddAddressBuilder,
+    };
+    use crate::farcaster_time::get_farcaster_time;
+    use crate::message::StorageUnitType;
+    use crate::test_utils::message;
+
+    fn cast_key(hash: &[u8]) -> MessageKey {
+        MessageKey::Cast {
+            fid: 2,
+            hash: hash.to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn cast_removes_win_regardless_of_timestamp() {
+        let now = get_farcaster_time().unwrap();
+        let mut store = Store::new();
+        let cast = message(CastAddBuilder::new("hello"), now - 10).await;
+        let hash = cast.get_hash().to_vec();
+        let remove = message(CastRemoveBuilder::new(hash.clone()), now - 20).await;
+
+        assert_eq!(
+            store.merge(cast.clone()).unwrap(),
+            MergeResult::Merged {
+                deleted: vec![],
+                pruned: vec![]
+            }
+        );
+        assert_eq!(store.merge(cast.clone()).unwrap(), MergeResult::Duplicate);
+        assert_eq!(
+            store.merge(remove.clone()).unwrap(),
+            MergeResult::Merged {
+                deleted: vec![cast.clone()],
+                pruned: vec![]
+            }
+        );
+        assert_eq!(store.get(&cast_key(&hash)), Some(&remove));
+        assert_eq!(store.get_add(&cast_key(&hash)), None);
+
+        // The tombstone keeps the add from coming back.
+        assert_eq!(store.merge(cast).unwrap(), MergeResult::Conflict);
+    }
+
+    #[tokio::test]
+    async fn reactions_and_links_are_last_write_wins() {
+        let now = get_farcaster_time().unwrap();
+        let mut store = Store::new();
+        let target = || ReactionTarget::Cast(cast_id(3, vec![1; 20]));
+        let like = message(
+            ReactionBuilder::add(ReactionType::REACTION_TYPE_LIKE, target()),
+            now - 10,
+        )
+        .await;
+        let unlike = message(
+            ReactionBuilder::remove(ReactionType::REACTION_TYPE_LIKE, target()),
+            now - 10,
+        )
+        .await;
+        let stale = message(
+            ReactionBuilder::add(ReactionType::REACTION_TYPE_LIKE, target()),
+            now - 20,
+        )
+        .await;
+
+        assert!(store.merge(like.clone()).unwrap().is_merged());
+        // A remove wins a timestamp tie.
+        assert_eq!(
+            store.merge(unlike.clone()).unwrap(),
+            MergeResult::Merged {
+                deleted: vec![like.clone()],
+                pruned: vec![]
+            }
+        );
+        let conflict = store.merge(like).unwrap();
+        assert_eq!(conflict, MergeResult::Conflict);
+        assert_eq!(
+            conflict.hub_error().unwrap().code,
+            HubErrorCode::BadRequestConflict
+        );
+        assert_eq!(store.merge(stale).unwrap(), MergeResult::Conflict);
+
+        let follow = message(LinkBuilder::add("follow", 3), now - 5).await;
+        let unfollow = message(LinkBuilder::remove("follow", 3), now - 20).await;
+        assert!(store.merge(follow.clone()).unwrap().is_merged());
+        assert_eq!(store.merge(unfollow).unwrap(), MergeResult::Conflict);
+        let key = MessageKey::Link {
+            fid: 2,
+            link_type: "follow".to_string(),
+            target_fid: 3,
+        };
+        assert_eq!(store.get_add(&key), Some(&follow));
+        assert_eq!(store.messages_by_fid(2), vec![&unlike, &follow]);
+    }
+
+    #[tokio::test]
+    async fn user_data_is_last_write_wins_per_type() {
+        let now = get_farcaster_time().unwrap();
+        let mut store = Store::new();
+        let old = message(
+            UserDataBuilder::new(UserDataType::USER_DATA_TYPE_BIO, "old"),
+            now - 20,
+        )
+        .await;
+        let new = message(
+            UserDataBuilder::new(UserDataType::USER_DATA_TYPE_BIO, "new"),
+            now - 10,
+        )
+        .await;
+        let name = message(
+            UserDataBuilder::new(UserDataType::USER_DATA_TYPE_DISPLAY, "Two"),
+            now - 30,
+        )
+        .await;
+
+        assert!(store.merge(new.clone()).unwrap().is_merged());
+        assert_eq!(store.merge(old).unwrap(), MergeResult::Conflict);
+        assert!(store.merge(name).unwrap().is_merged());
+        let key = MessageKey::UserData {
+            fid: 2,
+            user_data_type: UserDataType::USER_DATA_TYPE_BIO,
+        };
+        assert_eq!(store.get_add(&key), Some(&new));
+        assert_eq!(store.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn prunes_oldest_messages_over_the_storage_limit() {
+        let now = get_farcaster_time().unwrap();
+        let mut store = Store::new();
+        let mut verifications = Vec::new();
+        for i in 0..7u8 {
+            let body = VerificationAddAddressBuilder::new(vec![i; 20], vec![1; 65], vec![2; 32]);
+            verifications.push(message(body, now - 100 + i as u32).await);
+        }
+        for verification in &verifications[1..6] {
+            assert!(store.merge(verification.clone()).unwrap().is_merged());
+        }
+
+        let mut unit = StorageUnitDetails::new();
+        unit.set_unit_type(StorageUnitType::UNIT_TYPE_2025);
+        unit.set_unit_size(1);
+        assert!(store.set_storage_units(2, vec![unit]).is_empty());
+        let store_type = StoreType::STORE_TYPE_VERIFICATIONS;
+        assert_eq!(store.limit(2, store_type), Some(5));
+
+        // Older than everything in a full store, so a hub would reject it.
+        let result = store.merge(verifications[0].clone()).unwrap();
+        assert_eq!(result, MergeResult::Prunable);
+        assert_eq!(
+            result.hub_error().unwrap().code,
+            HubErrorCode::BadRequestPrunable
+        );
+
+        let MergeResult::Merged { deleted, pruned } =
+            store.merge(verifications[6].clone()).unwrap()
+        else {
+            panic!("expected a merge");
+        };
+        assert!(deleted.is_empty());
+        assert_eq!(pruned, vec![prune_event(verifications[1].clone())]);
+        assert_eq!(
+            store.messages_by_store(2, store_type),
+            verifications[2..].iter().collect::<Vec<_>>()
+        );
+
+        let pruned = store.set_storage_units(2, vec![]);
+        assert_eq!(pruned.len(), 5);
+        assert_eq!(
+            pruned[0].get_field_type(),
+            HubEventType::HUB_EVENT_TYPE_PRUNE_MESSAGE
+        );
+        assert!(store.is_empty());
+    }
+}
@@ -0,0 +1,412 @@
+//! Follows a hub's event log and hands every event to a handler, persisting the id of the last
+//! handled event so a restarted subscriber resumes where it stopped.
+//!
+//! Events are read from the HTTP `/v1/events` endpoint or the gRPC `Subscribe` stream. The cursor
+//! is written after the handler returns, so an event may be handled twice after a crash but is
+//! never skipped.
+
+use std::fmt;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tonic::transport::Channel;
+
+use crate::grpc::HubServiceClient;
+use crate::http::{HttpError, HubHttpClient};
+use crate::hub_event::{HubEvent, HubEventType};
+use crate::request_response::SubscribeRequest;
+
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// The event types an indexer needs to mirror a hub, as in shuttle's `HubSubscriber`.
+pub const DEFAULT_EVENT_TYPES: [HubEventType; 5] = [
+    HubEventType::HUB_EVENT_TYPE_MERGE_ON_CHAIN_EVENT,
+    HubEventType::HUB_EVENT_TYPE_MERGE_MESSAGE,
+    HubEventType::HUB_EVENT_TYPE_MERGE_USERNAME_PROOF,
+    HubEventType::HUB_EVENT_TYPE_PRUNE_MESSAGE,
+    HubEventType::HUB_EVENT_TYPE_REVOKE_MESSAGE,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum SubscriberError {
+    #[error("failed to read hub events: {0}")]
+    Http(#[from] HttpError),
+    #[error("hub event stream failed: {0}")]
+    Grpc(Box<tonic::Status>),
+    #[error("failed to access event cursor: {0}")]
+    Cursor(#[from] std::io::Error),
+    #[error("invalid event cursor {0:?}")]
+    InvalidCursor(String),
+    #[error("event handler failed: {0}")]
+    Handler(BoxError),
+}
+
+impl From<tonic::Status> for SubscriberError {
+    fn from(status: tonic::Status) -> Self {
+        Self::Grpc(Box::new(status))
+    }
+}
+
+impl SubscriberError {
+    /// True if the hub could not be read, i.e. retrying later may succeed.
+    pub fn is_source_error(&self) -> bool {
+        matches!(self, Self::Http(_) | Self::Grpc(_))
+    }
+}
+
+/// Receives every subscribed event in order.
+#[async_trait]
+pub trait HubEventHandler: Send {
+    async fn handle_event(&mut self, event: &HubEvent) -> Result<(), BoxError>;
+}
+
+#[async_trait]
+impl<F, E> HubEventHandler for F
+where
+    F: FnMut(&HubEvent) -> Result<(), E> + Send,
+    E: Into<BoxError>,
+{
+    async fn handle_event(&mut self, event: &HubEvent) -> Result<(), BoxError> {
+        (*self)(event).map_err(Into::into)
+    }
+}
+
+/// The id of the last handled event, stored as decimal text in a file.
+#[derive(Debug, Clone)]
+pub struct EventCursor {
+    path: PathBuf,
+}
+
+impl EventCursor {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the saved event id, or `None` if nothing has been handled yet.
+    pub fn load(&self) -> Result<Option<u64>, SubscriberError> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(text) => text
+                .trim()
+                .parse()
+                .map(Some)
+                .map_err(|_| SubscriberError::InvalidCursor(text)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Replaces the saved event id. The file is swapped in with a rename so a crash never leaves a
+    /// truncated cursor behind.
+    pub fn save(&self, event_id: u64) -> Result<(), SubscriberError> {
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        std::fs::write(&tmp, event_id.to_string())?;
+        std::fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum EventSource {
+    Http(HubHttpClient),
+    Grpc(HubServiceClient<Channel>),
+}
+
+type ErrorHandler = Arc<dyn Fn(&SubscriberError) + Send + Sync>;
+
+/// Streams hub events to a [`HubEventHandler`], resuming after the event saved in its cursor.
+#[derive(Clone)]
+pub struct HubEventSubscriber {
+    source: EventSource,
+    cursor: EventCursor,
+    event_types: Vec<HubEventType>,
+    shard_index: Option<u32>,
+    retry_delay: Duration,
+    on_error: Option<ErrorHandler>,
+}
+
+impl fmt::Debug for HubEventSubscriber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HubEventSubscriber")
+            .field("source", &self.source)
+            .field("cursor", &self.cursor)
+            .field("event_types", &self.event_types)
+            .field("shard_index", &self.shard_index)
+            .field("retry_delay", &self.retry_delay)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HubEventSubscriber {
+    /// Polls `/v1/events` through `client`.
+    pub fn http(client: HubHttpClient, cursor_path: impl Into<PathBuf>) -> Self {
+        Self::new(EventSource::Http(client), cursor_path)
+    }
+
+    /// Follows the gRPC `Subscribe` stream of `client`.
+    pub fn grpc(client: HubServiceClient<Channel>, cursor_path: impl Into<PathBuf>) -> Self {
+        Self::new(EventSource::Grpc(client), cursor_path)
+    }
+
+    fn new(source: EventSource, cursor_path: impl Into<PathBuf>) -> Self {
+        Self {
+            source,
+            cursor: EventCursor::new(cursor_path),
+            event_types: DEFAULT_EVENT_TYPES.to_vec(),
+            shard_index: None,
+            retry_delay: Duration::from_secs(5),
+            on_error: None,
+        }
+    }
+
+    /// Only hands events of these types to the handler. Defaults to [`DEFAULT_EVENT_TYPES`].
+    pub fn event_types(mut self, event_types: impl Into<Vec<HubEventType>>) -> Self {
+        self.event_types = event_types.into();
+        self
+    }
+
+    /// Subscribes to a single shard's events (gRPC only).
+    pub fn shard_index(mut self, shard_index: u32) -> Self {
+        self.shard_index = Some(shard_index);
+        self
+    }
+
+    /// How long [`run`](Self::run) waits before polling or reconnecting again.
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Called with every hub error that [`run`](Self::run) retries, e.g. to log it.
+    pub fn on_error(mut self, on_error: impl Fn(&SubscriberError) + Send + Sync + 'static) -> Self {
+        self.on_error = Some(Arc::new(on_error));
+        self
+    }
+
+    pub fn cursor(&self) -> &EventCursor {
+        &self.cursor
+    }
+
+    /// Handles events until the hub has no more: the HTTP API returns an empty page or the gRPC
+    /// stream ends. Returns the number of events handed to `handler`.
+    pub async fn process_events(
+        &mut self,
+        handler: &mut (impl HubEventHandler + ?Sized),
+    ) -> Result<usize, SubscriberError> {
+        let from_id = self.cursor.load()?.map_or(0, |id| id + 1);
+        match self.source.clone() {
+            EventSource::Http(client) => self.process_http(&client, from_id, handler).await,
+            EventSource::Grpc(mut client) => {
+                let mut request = SubscribeRequest::new();
+                request.set_event_types(self.event_types.clone());
+                request.set_from_id(from_id);
+                if let Some(shard_index) = self.shard_index {
+                    request.set_shard_index(shard_index);
+                }
+                let mut stream = client.subscribe(request).await?.into_inner();
+                let mut handled = 0;
+                while let Some(event) = stream.message().await? {
+                    handled += self.handle(&event, handler).await?;
+                }
+                Ok(handled)
+            }
+        }
+    }
+
+    async fn process_http(
+        &self,
+        client: &HubHttpClient,
+        mut from_id: u64,
+        handler: &mut (impl HubEventHandler + ?Sized),
+    ) -> Result<usize, SubscriberError> {
+        let mut handled = 0;
+        loop {
+            let page = client.events(from_id).await?;
+            let Some(last) = page.events.last() else {
+                return Ok(handled);
+            };
+            let last_id = last.get_id();
+            for event in &page.events {
+                handled += self.handle(event, handler).await?;
+            }
+            from_id = page.next_page_event_id.max(last_id + 1);
+        }
+    }
+
+    async fn handle(
+        &self,
+        event: &HubEvent,
+        handler: &mut (impl HubEventHandler + ?Sized),
+    ) -> Result<usize, SubscriberError> {
+        let wanted = self.event_types.contains(&event.get_field_type());
+        if wanted {
+            handler
+                .handle_event(event)
+                .await
+                .map_err(SubscriberError::Handler)?;
+        }
+        self.cursor.save(event.get_id())?;
+        Ok(wanted as usize)
+    }
+
+    /// Follows the event log until the handler or the cursor fails. Hub errors and closed streams
+    /// are retried after the retry delay; errors are passed to [`on_error`](Self::on_error) first.
+    pub async fn run(
+        &mut self,
+        handler: &mut (impl HubEventHandler + ?Sized),
+    ) -> Result<(), SubscriberError> {
+        loop {
+            match self.process_events(handler).await {
+                Ok(_) => {}
+                Err(err) if err.is_source_error() => {
+                    if let Some(on_error) = &self.on_error {
+                        on_error(&err);
+                    }
+                }
+                Err(err) => return Err(err),
+            }
+            tokio::time::sleep(self.retry_delay).await;
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)] // mock handlers return tonic::Status
+mod tests {
+    use super::*;
+    use crate::grpc::mock::MockHubService;
+    use crate::http::mock::MockHub;
+    use crate::http::to_json;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn event(id: u64, event_type: HubEventType) -> HubEvent {
+        let mut event = HubEvent::new();
+        event.set_id(id);
+        event.set_field_type(event_type);
+        event
+    }
+
+    #[test]
+    fn saves_and_loads_the_cursor() {
+        let dir = tempfile::tempdir().unwrap();
+        let cursor = EventCursor::new(dir.path().join("cursor"));
+        assert_eq!(cursor.load().unwrap(), None);
+        cursor.save(42).unwrap();
+        cursor.save(43).unwrap();
+        assert_eq!(cursor.load().unwrap(), Some(43));
+
+        std::fs::write(cursor.path(), "garbage").unwrap();
+        assert!(matches!(
+            cursor.load(),
+            Err(SubscriberError::InvalidCursor(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn resumes_http_events_from_the_cursor() {
+        let log: Vec<_> = (1..=5)
+            .map(|id| {
+                let event_type = if id == 3 {
+                    HubEventType::HUB_EVENT_TYPE_BLOCK_CONFIRMED
+                } else {
+                    HubEventType::HUB_EVENT_TYPE_MERGE_MESSAGE
+                };
+                event(id, event_type)
+            })
+            .collect();
+        let hub = MockHub::start(move |request| {
+            let from: u64 = request.query["from_event_id"].parse().unwrap();
+            // Two events per page
+            let events: Vec<_> = log
+                .iter()
+                .filter(|event| event.get_id() >= from)
+                .take(2)
+                .map(|event| to_json(event))
+                .collect();
+            let next = events.last().map_or(from, |_| from + 2);
+            (
+                200,
+                json!({ "events": events, "nextPageEventId": next }).to_string(),
+            )
+        })
+        .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cursor");
+        EventCursor::new(&path).save(1).unwrap();
+
+        let mut seen = Vec::new();
+        let mut handler = |event: &HubEvent| -> Result<(), BoxError> {
+            seen.push(event.get_id());
+            Ok(())
+        };
+        let mut subscriber = HubEventSubscriber::http(HubHttpClient::new(hub.url()), &path);
+        assert_eq!(subscriber.process_events(&mut handler).await.unwrap(), 3);
+        assert_eq!(subscriber.cursor().load().unwrap(), Some(5));
+
+        // Nothing new after a restart
+        let mut subscriber = HubEventSubscriber::http(HubHttpClient::new(hub.url()), &path);
+        assert_eq!(subscriber.process_events(&mut handler).await.unwrap(), 0);
+        assert_eq!(seen, [2, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn follows_the_grpc_stream_and_stops_on_handler_errors() {
+        let channel = MockHubService::new()
+            .server_streaming("Subscribe", |request: SubscribeRequest| {
+                assert_eq!(request.get_shard_index(), 1);
+                assert_eq!(request.get_event_types().len(), 1);
+                let from = request.get_from_id();
+                Ok((from..10)
+                    .map(|id| event(id, HubEventType::HUB_EVENT_TYPE_PRUNE_MESSAGE))
+                    .collect())
+            })
+            .serve()
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut subscriber =
+            HubEventSubscriber::grpc(HubServiceClient::new(channel), dir.path().join("cursor"))
+                .event_types([HubEventType::HUB_EVENT_TYPE_PRUNE_MESSAGE])
+                .shard_index(1);
+        subscriber.cursor().save(6).unwrap();
+
+        let mut handler = |event: &HubEvent| match event.get_id() {
+            8 => Err("boom"),
+            _ => Ok(()),
+        };
+        let err = subscriber.process_events(&mut handler).await.unwrap_err();
+        assert!(matches!(err, SubscriberError::Handler(_)));
+        assert_eq!(subscriber.cursor().load().unwrap(), Some(7));
+    }
+
+    #[tokio::test]
+    async fn reports_retried_hub_errors() {
+        let hub = MockHub::start(|_| (500, r#"{"errCode":"unavailable"}"#.to_string())).await;
+        let dir = tempfile::tempdir().unwrap();
+        let errors = Arc::new(AtomicUsize::new(0));
+        let reported = errors.clone();
+        let mut subscriber =
+            HubEventSubscriber::http(HubHttpClient::new(hub.url()), dir.path().join("cursor"))
+                .retry_delay(Duration::from_millis(10))
+                .on_error(move |err| {
+                    assert!(err.is_source_error());
+                    reported.fetch_add(1, Ordering::SeqCst);
+                });
+
+        let mut handler = |_: &HubEvent| -> Result<(), BoxError> { Ok(()) };
+        let run = subscriber.run(&mut handler);
+        assert!(tokio::time::timeout(Duration::from_millis(200), run)
+            .await
+            .is_err());
+        assert!(errors.load(Ordering::SeqCst) >= 2);
+    }
+}
@@ -0,0 +1,325 @@
+//! Fetches the messages a hub has and the local [`Store`] lacks.
+//!
+//! A sync compares snapshots to find the first level where the tries diverge, then walks down the
+//! hub's trie through the children whose hashes differ. Once a node holds few enough messages,
+//! its sync ids are listed and only the ones missing locally are fetched, validated and merged.
+//! Fetched messages that are not merged (invalid, conflicting or prunable) are remembered so later
+//! syncs don't fetch them again, though the walk still reaches the nodes that hold them.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use tonic::Status;
+
+use super::source::SyncSource;
+use super::trie::{same_hash, timestamp_prefix, MerkleTrie, SyncId};
+use crate::farcaster_time::{system_time_to_farcaster_time, Clock, SystemClock, TimeError};
+use crate::message::Message;
+use crate::store::{MergeResult, Store, StoreError};
+use crate::validations::ValidationError;
+
+/// Messages from the last ten minutes are left to gossip, as hubs do.
+pub const SYNC_THRESHOLD_SECONDS: u32 = 10 * 60;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SyncError {
+    #[error("sync rpc failed: {0}")]
+    Rpc(Box<Status>),
+    #[error(transparent)]
+    Invalid(#[from] ValidationError),
+    #[error(transparent)]
+    Time(#[from] TimeError),
+}
+
+impl From<Status> for SyncError {
+    fn from(status: Status) -> Self {
+        Self::Rpc(Box::new(status))
+    }
+}
+
+pub type SyncResult<T = ()> = Result<T, SyncError>;
+
+/// Counts of what a sync has done so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncProgress {
+    /// Trie nodes of the hub that were compared.
+    pub prefixes: usize,
+    /// Sync ids the hub had and the local trie did not.
+    pub missing: usize,
+    /// Fetched messages that were merged.
+    pub merged: usize,
+    /// Fetched messages that were duplicates, lost a conflict or are not kept by the store.
+    pub skipped: usize,
+    /// Fetched messages that failed validation.
+    pub invalid: usize,
+}
+
+type ProgressHandler = Box<dyn FnMut(&SyncProgress) + Send>;
+
+/// Keeps a [`Store`] and its [`MerkleTrie`] in sync with a hub.
+pub struct SyncEngine<S> {
+    source: S,
+    store: Store,
+    trie: MerkleTrie,
+    /// Sync ids that were fetched and not merged.
+    rejected: HashSet<SyncId>,
+    max_sync_ids: usize,
+    batch_size: usize,
+    clock: Arc<dyn Clock>,
+    on_progress: Option<ProgressHandler>,
+}
+
+impl<S: SyncSource> SyncEngine<S> {
+    /// Syncs `store` with the hub behind `source`, building the trie from its messages.
+    pub fn new(source: S, store: Store) -> SyncResult<Self> {
+        let mut trie = MerkleTrie::new();
+        for message in store.messages() {
+            trie.insert(message)?;
+        }
+        Ok(Self {
+            source,
+            store,
+            trie,
+            rejected: HashSet::new(),
+            max_sync_ids: 256,
+            batch_size: 100,
+            clock: Arc::new(SystemClock),
+            on_progress: None,
+        })
+    }
+
+    /// Lists the sync ids of hub trie nodes holding at most this many messages instead of
+    /// descending further. Defaults to 256.
+    pub fn max_sync_ids(mut self, max_sync_ids: usize) -> Self {
+        self.max_sync_ids = max_sync_ids;
+        self
+    }
+
+    /// Number of messages fetched per `GetAllMessagesBySyncIds` call. Defaults to 100.
+    pub fn batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Called with the running totals after every batch of fetched messages.
+    pub fn on_progress(mut self, on_progress: impl FnMut(&SyncProgress) + Send + 'static) -> Self {
+        self.on_progress = Some(Box::new(on_progress));
+        self
+    }
+
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    pub fn trie(&self) -> &MerkleTrie {
+        &self.trie
+    }
+
+    pub fn into_store(self) -> Store {
+        self.store
+    }
+
+    /// Merges a message into the store, keeping the trie in step with what was merged, deleted
+    /// and pruned.
+    pub fn merge(&mut self, message: Message) -> Result<MergeResult, StoreError> {
+        let sync_id = match SyncId::from_message(&message) {
+            Err(ValidationError::NoSyncId(message_type)) => {
+                return Err(StoreError::UnsupportedType(message_type))
+            }
+            sync_id => sync_id?,
+        };
+        let result = self.store.merge(message)?;
+        if let MergeResult::Merged { deleted, pruned } = &result {
+            self.trie.insert_sync_id(&sync_id);
+            let pruned = pruned
+                .iter()
+                .map(|event| event.get_prune_message_body().get_message());
+            for message in deleted.iter().chain(pruned) {
+                self.trie.delete(message)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Syncs every message older than [`SYNC_THRESHOLD_SECONDS`].
+    pub async fn sync(&mut self) -> SyncResult<SyncProgress> {
+        let now = system_time_to_farcaster_time(self.clock.now())?;
+        let prefix = timestamp_prefix(now.saturating_sub(SYNC_THRESHOLD_SECONDS));
+        let remote = self.source.snapshot(&prefix).await?;
+        let local = self.trie.snapshot(&remote.prefix);
+        let differs = |i: usize| match (local.excluded_hashes.get(i), remote.excluded_hashes.get(i))
+        {
+            (Some(local), Some(remote)) => !same_hash(local, remote),
+            (local, remote) => local.is_some() != remote.is_some(),
+        };
+        let len = local
+            .excluded_hashes
+            .len()
+            .max(remote.excluded_hashes.len());
+        if !(0..len).any(differs) {
+            return Ok(SyncProgress::default());
+        }
+
+        let divergence = (0..remote.prefix.len())
+            .find(|i| differs(*i))
+            .unwrap_or(remote.prefix.len());
+        self.sync_prefix(&remote.prefix[..divergence]).await
+    }
+
+    /// Walks the hub's trie below `prefix` and fetches what is missing locally.
+    pub async fn sync_prefix(&mut self, prefix: &[u8]) -> SyncResult<SyncProgress> {
+        let mut progress = SyncProgress::default();
+        let mut pending = vec![prefix.to_vec()];
+        while let Some(prefix) = pending.pop() {
+            let remote = match self.source.trie_metadata(&prefix).await {
+                Ok(remote) => remote,
+                // The hub has nothing under this prefix.
+                Err(status) if status.code() == tonic::Code::NotFound => continue,
+                Err(status) => return Err(status.into()),
+            };
+            progress.prefixes += 1;
+            if remote.get_num_messages() as usize <= self.max_sync_ids
+                || remote.get_children().is_empty()
+            {
+                self.fetch_missing(&prefix, &mut progress).await?;
+                continue;
+            }
+            let remote_children: Vec<&[u8]> = remote
+                .get_children()
+                .iter()
+                .map(|child| child.get_prefix())
+                .collect();
+            pending.extend(
+                self.trie
+                    .diff(&remote)
+                    .into_iter()
+                    .filter(|prefix| remote_children.contains(&prefix.as_slice()))
+                    .rev(),
+            );
+        }
+        Ok(progress)
+    }
+
+    async fn fetch_missing(&mut self, prefix: &[u8], progress: &mut SyncProgress) -> SyncResult {
+        let missing: Vec<SyncId> = self
+            .source
+            .sync_ids_by_prefix(prefix)
+            .await?
+            .into_iter()
+            .filter(|sync_id| !self.trie.exists(sync_id) && !self.rejected.contains(sync_id))
+            .collect();
+        progress.missing += missing.len();
+
+        for batch in missing.chunks(self.batch_size) {
+            for message in self.source.messages_by_sync_ids(batch).await? {
+                match self.merge(message) {
+                    Ok(MergeResult::Merged { .. }) => progress.merged += 1,
+                    Ok(_) | Err(StoreError::UnsupportedType(_)) => progress.skipped += 1,
+                    Err(StoreError::Invalid(_)) => progress.invalid += 1,
+                }
+            }
+            // Whatever was not merged, or not returned by the hub, is not asked for again
+            self.rejected.extend(
+                batch
+                    .iter()
+                    .filter(|sync_id| !self.trie.exists(sync_id))
+                    .cloned(),
+            );
+            if let Some(on_progress) = &mut self.on_progress {
+                on_progress(progress);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bodies::{CastAddBuilder, UserDataBuilder};
+    use crate::farcaster_time::{get_farcaster_time, FixedClock};
+    use crate::message::UserDataType;
+    use crate::sync::mock::TrieSource;
+    use crate::test_utils::message;
+    use std::sync::Mutex;
+
+    #[tokio::test]
+    async fn fetches_only_missing_messages_from_a_hub() {
+        let now = get_farcaster_time().unwrap();
+        let mut messages = Vec::new();
+        for i in 0..6 {
+            let text = format!("cast {}", i);
+            messages.push(message(CastAddBuilder::new(text), now - 100_000 + i * 997).await);
+        }
+        let bio = UserDataBuilder::new(UserDataType::USER_DATA_TYPE_BIO, "hi");
+        messages.push(message(bio, now - 50_000).await);
+
+        let mut store = Store::new();
+        for message in &messages[..3] {
+            store.merge(message.clone()).unwrap();
+        }
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let mut engine = SyncEngine::new(TrieSource::new(&messages), store)
+            .unwrap()
+            .max_sync_ids(1)
+            .batch_size(2)
+            .clock(Arc::new(FixedClock::at_farcaster_time(now)))
+            .on_progress({
+                let updates = updates.clone();
+                move |progress| updates.lock().unwrap().push(progress.merged)
+            });
+
+        let progress = engine.sync().await.unwrap();
+        assert_eq!(progress.missing, 4);
+        assert_eq!(progress.merged, 4);
+        assert_eq!(progress.invalid, 0);
+        assert!(progress.prefixes > 1);
+        assert_eq!(*updates.lock().unwrap().last().unwrap(), 4);
+        assert_eq!(engine.store().len(), 7);
+
+        let mut remote = MerkleTrie::new();
+        for message in &messages {
+            remote.insert(message).unwrap();
+        }
+        assert_eq!(engine.trie().root_hash(), remote.root_hash());
+
+        // Nothing is left to fetch.
+        let progress = engine.sync().await.unwrap();
+        assert_eq!(progress.missing, 0);
+    }
+
+    #[tokio::test]
+    async fn compares_snapshot_hashes_regardless_of_format() {
+        let now = get_farcaster_time().unwrap();
+        let cast = message(CastAddBuilder::new("hi"), now - 50_000).await;
+        let mut store = Store::new();
+        store.merge(cast.clone()).unwrap();
+        let mut source = TrieSource::new(&[cast]);
+        source.prefixed_hashes = true;
+
+        let mut engine = SyncEngine::new(source, store)
+            .unwrap()
+            .clock(Arc::new(FixedClock::at_farcaster_time(now)));
+        assert_eq!(engine.sync().await.unwrap(), SyncProgress::default());
+    }
+
+    #[tokio::test]
+    async fn does_not_fetch_rejected_messages_again() {
+        let now = get_farcaster_time().unwrap();
+        let mut invalid = message(CastAddBuilder::new("hi"), now - 50_000).await;
+        invalid.set_signature(vec![0; 64]);
+
+        let mut engine = SyncEngine::new(TrieSource::new(&[invalid]), Store::new())
+            .unwrap()
+            .clock(Arc::new(FixedClock::at_farcaster_time(now)));
+        let progress = engine.sync().await.unwrap();
+        assert_eq!((progress.missing, progress.invalid), (1, 1));
+        let progress = engine.sync().await.unwrap();
+        assert_eq!((progress.missing, progress.invalid), (0, 0));
+    }
+}
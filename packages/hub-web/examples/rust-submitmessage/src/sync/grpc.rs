@@ -0,0 +1,193 @@
+//! [`SyncSource`] over the gRPC API of legacy Hubble hubs, behind the `legacy-sync` feature.
+
+use async_trait::async_trait;
+use protobuf::{Message as _, ProtobufError};
+use tonic::client::Grpc;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::transport::Channel;
+use tonic::Status;
+
+use super::source::SyncSource;
+use super::trie::{SyncId, TrieSnapshot};
+use super::wire;
+use crate::grpc::{BytesCodec, HubServiceClient};
+use crate::message::Message;
+use crate::request_response::{
+    MessagesResponse, TrieNodeMetadataRequest, TrieNodeMetadataResponse,
+};
+
+/// Reads a legacy Hubble hub's trie over gRPC.
+///
+/// `GetSyncSnapshotByPrefix`, `GetAllSyncIdsByPrefix` and `GetAllMessagesBySyncIds` are not part
+/// of the current `HubService` schema, so their messages are encoded by hand. Hubs that serve only
+/// the current schema answer them with `Unimplemented`.
+#[derive(Debug, Clone)]
+pub struct GrpcSyncSource {
+    client: HubServiceClient<Channel>,
+    grpc: Grpc<Channel>,
+    shard_id: u32,
+}
+
+impl GrpcSyncSource {
+    pub fn new(channel: Channel) -> Self {
+        Self {
+            client: HubServiceClient::new(channel.clone()),
+            grpc: Grpc::new(channel),
+            shard_id: 0,
+        }
+    }
+
+    /// Sets the shard whose trie `trie_metadata` reads. Defaults to 0.
+    pub fn shard_id(mut self, shard_id: u32) -> Self {
+        self.shard_id = shard_id;
+        self
+    }
+
+    async fn call(&mut self, path: &'static str, request: Vec<u8>) -> Result<Vec<u8>, Status> {
+        self.grpc
+            .ready()
+            .await
+            .map_err(|err| Status::unknown(format!("Service was not ready: {}", err)))?;
+        let response = self
+            .grpc
+            .unary(
+                tonic::Request::new(request),
+                PathAndQuery::from_static(path),
+                BytesCodec,
+            )
+            .await?;
+        Ok(response.into_inner())
+    }
+}
+
+fn decode_error(err: ProtobufError) -> Status {
+    Status::internal(err.to_string())
+}
+
+#[async_trait]
+impl SyncSource for GrpcSyncSource {
+    async fn snapshot(&mut self, prefix: &[u8]) -> Result<TrieSnapshot, Status> {
+        let request = wire::encode_prefix(prefix).map_err(decode_error)?;
+        let response = self
+            .call("/HubService/GetSyncSnapshotByPrefix", request)
+            .await?;
+        wire::decode_snapshot(&response).map_err(decode_error)
+    }
+
+    async fn trie_metadata(&mut self, prefix: &[u8]) -> Result<TrieNodeMetadataResponse, Status> {
+        let mut request = TrieNodeMetadataRequest::new();
+        request.set_shard_id(self.shard_id);
+        request.set_prefix(prefix.to_vec());
+        let response = self.client.get_trie_metadata_by_prefix(request).await?;
+        Ok(response.into_inner())
+    }
+
+    async fn sync_ids_by_prefix(&mut self, prefix: &[u8]) -> Result<Vec<SyncId>, Status> {
+        let request = wire::encode_prefix(prefix).map_err(decode_error)?;
+        let response = self
+            .call("/HubService/GetAllSyncIdsByPrefix", request)
+            .await?;
+        wire::decode_sync_ids(&response).map_err(decode_error)
+    }
+
+    async fn messages_by_sync_ids(&mut self, sync_ids: &[SyncId]) -> Result<Vec<Message>, Status> {
+        let request = wire::encode_sync_ids(sync_ids).map_err(decode_error)?;
+        let response = self
+            .call("/HubService/GetAllMessagesBySyncIds", request)
+            .await?;
+        let response = MessagesResponse::parse_from_bytes(&response).map_err(decode_error)?;
+        Ok(response.get_messages().to_vec())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)] // handlers return tonic::Status like generated servers
+mod tests {
+    use super::*;
+    use crate::bodies::CastAddBuilder;
+    use crate::farcaster_time::FixedClock;
+    use crate::grpc::mock::MockHubService;
+    use crate::store::Store;
+    use crate::sync::mock::TrieSource;
+    use crate::sync::{MerkleTrie, SyncEngine};
+    use crate::test_utils::message;
+    use std::sync::Arc;
+
+    fn status(err: protobuf::ProtobufError) -> Status {
+        Status::internal(err.to_string())
+    }
+
+    /// Serves the legacy sync RPCs from a trie of fixture messages.
+    async fn mock_hub(messages: &[Message]) -> GrpcSyncSource {
+        let TrieSource {
+            trie,
+            messages: by_id,
+            ..
+        } = TrieSource::new(messages);
+        let trie = Arc::new(trie);
+        let mock = MockHubService::new()
+            .raw_unary("GetSyncSnapshotByPrefix", {
+                let trie = trie.clone();
+                move |request| {
+                    let prefix = wire::decode_prefix(&request).map_err(status)?;
+                    wire::encode_snapshot(&trie.snapshot(&prefix)).map_err(status)
+                }
+            })
+            .unary("GetTrieMetadataByPrefix", {
+                let trie = trie.clone();
+                move |request: TrieNodeMetadataRequest| {
+                    trie.metadata(request.get_prefix())
+                        .ok_or_else(|| Status::not_found("no trie node"))
+                }
+            })
+            .raw_unary("GetAllSyncIdsByPrefix", move |request| {
+                let prefix = wire::decode_prefix(&request).map_err(status)?;
+                wire::encode_sync_ids(&trie.sync_ids_by_prefix(&prefix)).map_err(status)
+            })
+            .raw_unary("GetAllMessagesBySyncIds", move |request| {
+                let mut response = MessagesResponse::new();
+                for sync_id in wire::decode_sync_ids(&request).map_err(status)? {
+                    response.mut_messages().push(by_id[&sync_id].clone());
+                }
+                response.write_to_bytes().map_err(status)
+            });
+        GrpcSyncSource::new(mock.serve().await)
+    }
+
+    #[tokio::test]
+    async fn calls_the_legacy_sync_rpcs() {
+        let mut messages = Vec::new();
+        for i in 0..3 {
+            let cast = CastAddBuilder::new(format!("cast {}", i));
+            messages.push(message(cast, 100_000_000 + i * 997).await);
+        }
+        let mut trie = MerkleTrie::new();
+        for message in &messages {
+            trie.insert(message).unwrap();
+        }
+
+        let mut source = mock_hub(&messages).await;
+        let snapshot = source.snapshot(b"01000").await.unwrap();
+        assert_eq!(snapshot, trie.snapshot(b"01000"));
+        let metadata = source.trie_metadata(b"").await.unwrap();
+        assert_eq!(metadata.get_num_messages(), 3);
+        let sync_ids = source.sync_ids_by_prefix(b"").await.unwrap();
+        assert_eq!(sync_ids, trie.sync_ids_by_prefix(b""));
+        assert_eq!(
+            source
+                .messages_by_sync_ids(&sync_ids[..2])
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+        // A whole sync against the mock hub, from an empty store.
+        let mut engine = SyncEngine::new(mock_hub(&messages).await, Store::new())
+            .unwrap()
+            .clock(Arc::new(FixedClock::at_farcaster_time(100_100_000)));
+        let progress = engine.sync().await.unwrap();
+        assert_eq!(progress.merged, 3);
+        assert_eq!(engine.trie().root_hash(), trie.root_hash());
+        assert_eq!(engine.sync().await.unwrap().missing, 0);
+    }
+}
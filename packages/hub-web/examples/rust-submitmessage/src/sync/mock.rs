@@ -0,0 +1,65 @@
+//! An in-memory [`SyncSource`] standing in for a hub's sync RPCs in tests.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use tonic::Status;
+
+use super::source::SyncSource;
+use super::trie::{MerkleTrie, SyncId, TrieSnapshot};
+use crate::message::Message;
+use crate::request_response::TrieNodeMetadataResponse;
+
+/// Serves the sync RPCs from a trie of fixture messages.
+pub(crate) struct TrieSource {
+    pub trie: MerkleTrie,
+    pub messages: HashMap<SyncId, Message>,
+    /// Sends snapshot hashes `0x` prefixed and upper case, as some hubs do.
+    pub prefixed_hashes: bool,
+}
+
+impl TrieSource {
+    pub fn new(messages: &[Message]) -> Self {
+        let mut trie = MerkleTrie::new();
+        let mut by_id = HashMap::new();
+        for message in messages {
+            trie.insert(message).unwrap();
+            by_id.insert(SyncId::from_message(message).unwrap(), message.clone());
+        }
+        Self {
+            trie,
+            messages: by_id,
+            prefixed_hashes: false,
+        }
+    }
+}
+
+#[async_trait]
+impl SyncSource for TrieSource {
+    async fn snapshot(&mut self, prefix: &[u8]) -> Result<TrieSnapshot, Status> {
+        let mut snapshot = self.trie.snapshot(prefix);
+        if self.prefixed_hashes {
+            for hash in &mut snapshot.excluded_hashes {
+                *hash = format!("0x{}", hash.to_uppercase());
+            }
+        }
+        Ok(snapshot)
+    }
+
+    async fn trie_metadata(&mut self, prefix: &[u8]) -> Result<TrieNodeMetadataResponse, Status> {
+        self.trie
+            .metadata(prefix)
+            .ok_or_else(|| Status::not_found("no trie node"))
+    }
+
+    async fn sync_ids_by_prefix(&mut self, prefix: &[u8]) -> Result<Vec<SyncId>, Status> {
+        Ok(self.trie.sync_ids_by_prefix(prefix))
+    }
+
+    async fn messages_by_sync_ids(&mut self, sync_ids: &[SyncId]) -> Result<Vec<Message>, Status> {
+        Ok(sync_ids
+            .iter()
+            .map(|id| self.messages[id].clone())
+            .collect())
+    }
+}
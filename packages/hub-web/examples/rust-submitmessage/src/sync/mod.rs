@@ -0,0 +1,26 @@
+//! Reconciling local messages with a hub.
+//!
+//! [`MerkleTrie`] indexes messages by [`SyncId`] the way hubs do, so comparing node hashes with a
+//! hub's `GetTrieMetadataByPrefix` responses narrows down which time ranges differ.
+//! [`SyncEngine`] walks those differences through a [`SyncSource`] and fetches only the messages
+//! missing from a local [`Store`](crate::store::Store).
+//!
+//! The current `HubService` schema has no RPCs to list sync ids or fetch messages by them, so the
+//! only gRPC source, `GrpcSyncSource`, targets legacy Hubble hubs and is behind the `legacy-sync`
+//! feature.
+
+pub mod engine;
+#[cfg(feature = "legacy-sync")]
+pub mod grpc;
+#[cfg(test)]
+pub(crate) mod mock;
+pub mod source;
+pub mod trie;
+#[cfg(feature = "legacy-sync")]
+mod wire;
+
+pub use engine::{SyncEngine, SyncError, SyncProgress};
+#[cfg(feature = "legacy-sync")]
+pub use grpc::GrpcSyncSource;
+pub use source::SyncSource;
+pub use trie::{timestamp_prefix, MerkleTrie, SyncId, TrieSnapshot, TIMESTAMP_LENGTH};
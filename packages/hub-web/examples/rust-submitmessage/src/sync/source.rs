@@ -0,0 +1,26 @@
+//! Where a [`SyncEngine`](super::SyncEngine) reads the hub's trie and messages from.
+
+use async_trait::async_trait;
+use tonic::Status;
+
+use super::trie::{SyncId, TrieSnapshot};
+use crate::message::Message;
+use crate::request_response::TrieNodeMetadataResponse;
+
+/// The sync RPCs of a hub, named after Hubble's. Implement it over whatever can serve another
+/// node's trie and messages; [`GrpcSyncSource`](super::GrpcSyncSource) (`legacy-sync` feature)
+/// calls the RPCs of a legacy Hubble hub.
+#[async_trait]
+pub trait SyncSource: Send {
+    /// `GetSyncSnapshotByPrefix`: the hub's exclusion hashes along `prefix`.
+    async fn snapshot(&mut self, prefix: &[u8]) -> Result<TrieSnapshot, Status>;
+
+    /// `GetTrieMetadataByPrefix`: the hub's trie node at `prefix` and its children.
+    async fn trie_metadata(&mut self, prefix: &[u8]) -> Result<TrieNodeMetadataResponse, Status>;
+
+    /// `GetAllSyncIdsByPrefix`: every sync id the hub has under `prefix`.
+    async fn sync_ids_by_prefix(&mut self, prefix: &[u8]) -> Result<Vec<SyncId>, Status>;
+
+    /// `GetAllMessagesBySyncIds`: the messages identified by `sync_ids`.
+    async fn messages_by_sync_ids(&mut self, sync_ids: &[SyncId]) -> Result<Vec<Message>, Status>;
+}
@@ -0,0 +1,571 @@
+//! The Merkle trie hubs use to compare the messages they hold.
+//!
+//! Every message is keyed by its [`SyncId`], in the layout hubs use. The first
+//! [`TIMESTAMP_LENGTH`] levels of the trie branch on the digits of the timestamp, so a prefix
+//! selects a time range. Below that a node holding a single key is kept as a leaf instead of a
+//! chain of single children. A leaf's hash is the truncated blake3 hash of its key; any other
+//! node's hash is the truncated blake3 hash of its children's hashes, in order of their bytes.
+
+use std::collections::BTreeMap;
+
+use crate::builder::{hash_data, HASH_LENGTH};
+use crate::message::{Message, MessageType};
+use crate::request_response::TrieNodeMetadataResponse;
+use crate::validations::{message_data, ValidationError, ValidationResult};
+
+/// Number of decimal digits the timestamp of a sync id is padded to.
+pub const TIMESTAMP_LENGTH: usize = 10;
+
+/// Hub database prefix of per-user records, which message sync ids are keyed under.
+const ROOT_PREFIX_USER: u8 = 1;
+
+const FID_LENGTH: usize = 4;
+
+/// Length of a message sync id up to its hash.
+const SYNC_ID_PREFIX_LENGTH: usize = TIMESTAMP_LENGTH + 1 + FID_LENGTH + 1;
+
+/// The hub's database postfix for the set a message type is stored in.
+fn set_postfix(message_type: MessageType) -> Option<u8> {
+    match message_type {
+        MessageType::MESSAGE_TYPE_CAST_ADD | MessageType::MESSAGE_TYPE_CAST_REMOVE => Some(1),
+        MessageType::MESSAGE_TYPE_LINK_ADD | MessageType::MESSAGE_TYPE_LINK_REMOVE => Some(2),
+        MessageType::MESSAGE_TYPE_REACTION_ADD | MessageType::MESSAGE_TYPE_REACTION_REMOVE => {
+            Some(3)
+        }
+        MessageType::MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS
+        | MessageType::MESSAGE_TYPE_VERIFICATION_REMOVE => Some(4),
+        MessageType::MESSAGE_TYPE_USER_DATA_ADD => Some(6),
+        MessageType::MESSAGE_TYPE_USERNAME_PROOF => Some(7),
+        _ => None,
+    }
+}
+
+/// Identifies a message in the trie, laid out as hubs key it: the zero-padded decimal timestamp,
+/// then the message's primary key in the hub database (the user root prefix, the big-endian
+/// 4-byte fid and the postfix of the message's set) and finally the message hash.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SyncId(Vec<u8>);
+
+impl SyncId {
+    pub fn new(
+        timestamp: u32,
+        fid: u64,
+        message_type: MessageType,
+        hash: &[u8],
+    ) -> ValidationResult<Self> {
+        let fid = u32::try_from(fid).map_err(|_| ValidationError::SyncIdFidTooLarge)?;
+        let postfix = set_postfix(message_type).ok_or(ValidationError::NoSyncId(message_type))?;
+        let mut bytes = Vec::with_capacity(SYNC_ID_PREFIX_LENGTH + hash.len());
+        bytes.extend_from_slice(&timestamp_prefix(timestamp));
+        bytes.push(ROOT_PREFIX_USER);
+        bytes.extend_from_slice(&fid.to_be_bytes());
+        bytes.push(postfix);
+        bytes.extend_from_slice(hash);
+        Ok(SyncId(bytes))
+    }
+
+    pub fn from_message(message: &Message) -> ValidationResult<Self> {
+        let data = message_data(message)?;
+        SyncId::new(
+            data.get_timestamp(),
+            data.get_fid(),
+            data.get_field_type(),
+            message.get_hash(),
+        )
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> Self {
+        SyncId(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// The farcaster timestamp, if the id starts with one.
+    pub fn timestamp(&self) -> Option<u32> {
+        std::str::from_utf8(self.0.get(..TIMESTAMP_LENGTH)?)
+            .ok()?
+            .parse()
+            .ok()
+    }
+
+    /// The fid, if this is a message sync id.
+    pub fn fid(&self) -> Option<u64> {
+        if self.0.get(TIMESTAMP_LENGTH) != Some(&ROOT_PREFIX_USER) {
+            return None;
+        }
+        let fid = self
+            .0
+            .get(TIMESTAMP_LENGTH + 1..TIMESTAMP_LENGTH + 1 + FID_LENGTH)?;
+        Some(u32::from_be_bytes(fid.try_into().ok()?).into())
+    }
+
+    pub fn hash(&self) -> &[u8] {
+        self.0.get(SYNC_ID_PREFIX_LENGTH..).unwrap_or_default()
+    }
+}
+
+/// The first [`TIMESTAMP_LENGTH`] bytes of the sync ids of messages sent at `timestamp`.
+pub fn timestamp_prefix(timestamp: u32) -> [u8; TIMESTAMP_LENGTH] {
+    let mut prefix = [0; TIMESTAMP_LENGTH];
+    prefix.copy_from_slice(format!("{:010}", timestamp).as_bytes());
+    prefix
+}
+
+/// Hashes and item counts summarising a trie up to a prefix, for finding where two tries diverge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrieSnapshot {
+    pub prefix: Vec<u8>,
+    /// For each level of the prefix, the hash of every sibling off the path; then the hash of the
+    /// node at the prefix itself.
+    pub excluded_hashes: Vec<String>,
+    pub num_messages: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    hash: Vec<u8>,
+    items: usize,
+    children: BTreeMap<u8, TrieNode>,
+    key: Option<Vec<u8>>,
+}
+
+impl TrieNode {
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty() && self.key.is_some()
+    }
+
+    fn insert(&mut self, key: &[u8], depth: usize) -> bool {
+        if self.children.is_empty() && depth >= TIMESTAMP_LENGTH {
+            match &self.key {
+                None => {
+                    self.key = Some(key.to_vec());
+                    self.items = 1;
+                    self.update_hash();
+                    return true;
+                }
+                Some(existing) if existing == key => return false,
+                Some(_) => self.split_leaf(depth),
+            }
+        }
+        let Some(&char) = key.get(depth) else {
+            return false;
+        };
+        let inserted = self
+            .children
+            .entry(char)
+            .or_default()
+            .insert(key, depth + 1);
+        if inserted {
+            self.items += 1;
+            self.update_hash();
+        }
+        inserted
+    }
+
+    fn delete(&mut self, key: &[u8], depth: usize) -> bool {
+        if self.is_leaf() {
+            if self.key.as_deref() != Some(key) {
+                return false;
+            }
+            self.key = None;
+            self.items = 0;
+            self.update_hash();
+            return true;
+        }
+        let Some(char) = key.get(depth) else {
+            return false;
+        };
+        let Some(child) = self.children.get_mut(char) else {
+            return false;
+        };
+        if !child.delete(key, depth + 1) {
+            return false;
+        }
+        if child.items == 0 {
+            self.children.remove(char);
+        }
+        self.items -= 1;
+        // Collapse a chain below the timestamp back into a leaf.
+        if self.items == 1 && depth >= TIMESTAMP_LENGTH {
+            if let Some((_, child)) = self.children.first_key_value() {
+                if child.is_leaf() {
+                    self.key = child.key.clone();
+                    self.children.clear();
+                }
+            }
+        }
+        self.update_hash();
+        true
+    }
+
+    fn split_leaf(&mut self, depth: usize) {
+        if let Some(key) = self.key.take() {
+            self.children
+                .entry(key[depth])
+                .or_default()
+                .insert(&key, depth + 1);
+        }
+    }
+
+    fn update_hash(&mut self) {
+        self.hash = match &self.key {
+            Some(key) if self.children.is_empty() => hash_data(key),
+            _ => self.children_hash(|_| true),
+        };
+    }
+
+    fn children_hash(&self, include: impl Fn(u8) -> bool) -> Vec<u8> {
+        let mut hasher = blake3::Hasher::new();
+        for (char, child) in &self.children {
+            if include(*char) {
+                hasher.update(&child.hash);
+            }
+        }
+        hasher.finalize().as_bytes()[..HASH_LENGTH].to_vec()
+    }
+
+    fn node(&self, prefix: &[u8]) -> Option<&TrieNode> {
+        prefix
+            .iter()
+            .try_fold(self, |node, char| node.children.get(char))
+    }
+
+    fn collect_keys(&self, keys: &mut Vec<SyncId>) {
+        if let Some(key) = &self.key {
+            keys.push(SyncId(key.clone()));
+        }
+        for child in self.children.values() {
+            child.collect_keys(keys);
+        }
+    }
+}
+
+/// A Merkle trie of sync ids, built the same way as a hub's.
+#[derive(Debug, Clone)]
+pub struct MerkleTrie {
+    root: TrieNode,
+}
+
+impl Default for MerkleTrie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MerkleTrie {
+    pub fn new() -> Self {
+        let mut root = TrieNode::default();
+        root.update_hash();
+        Self { root }
+    }
+
+    /// Inserts the sync id of `message`; returns false if it was already present.
+    pub fn insert(&mut self, message: &Message) -> ValidationResult<bool> {
+        Ok(self.insert_sync_id(&SyncId::from_message(message)?))
+    }
+
+    /// Deletes the sync id of `message`; returns false if it was not present.
+    pub fn delete(&mut self, message: &Message) -> ValidationResult<bool> {
+        Ok(self.delete_sync_id(&SyncId::from_message(message)?))
+    }
+
+    pub fn insert_sync_id(&mut self, sync_id: &SyncId) -> bool {
+        self.root.insert(sync_id.as_bytes(), 0)
+    }
+
+    pub fn delete_sync_id(&mut self, sync_id: &SyncId) -> bool {
+        self.root.delete(sync_id.as_bytes(), 0)
+    }
+
+    pub fn exists(&self, sync_id: &SyncId) -> bool {
+        let mut node = &self.root;
+        for char in sync_id.as_bytes() {
+            if node.is_leaf() {
+                break;
+            }
+            match node.children.get(char) {
+                Some(child) => node = child,
+                None => return false,
+            }
+        }
+        node.key.as_deref() == Some(sync_id.as_bytes())
+    }
+
+    pub fn items(&self) -> usize {
+        self.root.items
+    }
+
+    pub fn root_hash(&self) -> &[u8] {
+        &self.root.hash
+    }
+
+    /// The node at `prefix` and its direct children, in the shape hubs return from
+    /// `GetTrieMetadataByPrefix`.
+    pub fn metadata(&self, prefix: &[u8]) -> Option<TrieNodeMetadataResponse> {
+        let node = self.root.node(prefix)?;
+        let mut metadata = node_metadata(prefix.to_vec(), node);
+        for (char, child) in &node.children {
+            let mut child_prefix = prefix.to_vec();
+            child_prefix.push(*char);
+            metadata
+                .mut_children()
+                .push(node_metadata(child_prefix, child));
+        }
+        Some(metadata)
+    }
+
+    /// Summarises the trie along `prefix`, stopping early where the path ends.
+    pub fn snapshot(&self, prefix: &[u8]) -> TrieSnapshot {
+        let mut excluded_hashes = Vec::new();
+        let mut num_messages = 0;
+        let mut node = &self.root;
+        for (depth, char) in prefix.iter().enumerate() {
+            excluded_hashes.push(hex::encode(node.children_hash(|c| c != *char)));
+            num_messages += node
+                .children
+                .iter()
+                .filter(|(c, _)| *c != char)
+                .map(|(_, child)| child.items)
+                .sum::<usize>();
+            match node.children.get(char) {
+                Some(child) => node = child,
+                None => {
+                    return TrieSnapshot {
+                        prefix: prefix[..depth].to_vec(),
+                        excluded_hashes,
+                        num_messages,
+                    }
+                }
+            }
+        }
+        excluded_hashes.push(hex::encode(&node.hash));
+        TrieSnapshot {
+            prefix: prefix.to_vec(),
+            excluded_hashes,
+            num_messages: num_messages + node.items,
+        }
+    }
+
+    /// Every sync id under `prefix`, in order.
+    pub fn sync_ids_by_prefix(&self, prefix: &[u8]) -> Vec<SyncId> {
+        let mut keys = Vec::new();
+        if let Some(node) = self.root.node(prefix) {
+            node.collect_keys(&mut keys);
+        } else if let Some(leaf) = self.leaf_under(prefix) {
+            keys.push(SyncId(leaf.to_vec()));
+        }
+        keys
+    }
+
+    /// A compacted leaf above `prefix` whose key starts with it.
+    fn leaf_under(&self, prefix: &[u8]) -> Option<&[u8]> {
+        let mut node = &self.root;
+        for char in prefix {
+            if node.is_leaf() {
+                break;
+            }
+            node = node.children.get(char)?;
+        }
+        node.key.as_deref().filter(|key| key.starts_with(prefix))
+    }
+
+    /// Compares the node at `remote.prefix` with a hub's metadata for it, returning the prefixes
+    /// below it that differ: children whose hashes don't match, or that only one side has. When
+    /// the hub sent no children, the prefix itself is returned if the node hashes differ.
+    pub fn diff(&self, remote: &TrieNodeMetadataResponse) -> Vec<Vec<u8>> {
+        let prefix = remote.get_prefix();
+        let local = self.metadata(prefix);
+        let local_hash = local.as_ref().map(|node| node.get_hash());
+        if local_hash.is_some_and(|hash| same_hash(hash, remote.get_hash())) {
+            return Vec::new();
+        }
+        if remote.get_children().is_empty() {
+            return vec![prefix.to_vec()];
+        }
+
+        let local_children: BTreeMap<&[u8], &str> = local
+            .as_ref()
+            .map(|node| {
+                node.get_children()
+                    .iter()
+                    .map(|child| (child.get_prefix(), child.get_hash()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut differing: Vec<Vec<u8>> = remote
+            .get_children()
+            .iter()
+            .filter(|child| {
+                !local_children
+                    .get(child.get_prefix())
+                    .is_some_and(|hash| same_hash(hash, child.get_hash()))
+            })
+            .map(|child| child.get_prefix().to_vec())
+            .collect();
+        for prefix in local_children.keys() {
+            if !remote
+                .get_children()
+                .iter()
+                .any(|child| child.get_prefix() == *prefix)
+            {
+                differing.push(prefix.to_vec());
+            }
+        }
+        differing.sort();
+        differing
+    }
+}
+
+fn node_metadata(prefix: Vec<u8>, node: &TrieNode) -> TrieNodeMetadataResponse {
+    let mut metadata = TrieNodeMetadataResponse::new();
+    metadata.set_prefix(prefix);
+    metadata.set_num_messages(node.items as u64);
+    metadata.set_hash(hex::encode(&node.hash));
+    metadata
+}
+
+/// Compares hex hashes, ignoring case and a `0x` prefix.
+pub(crate) fn same_hash(a: &str, b: &str) -> bool {
+    let strip = |hash: &str| hash.trim_start_matches("0x").to_ascii_lowercase();
+    strip(a) == strip(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bodies::LinkBuilder;
+    use crate::test_utils::message_from;
+
+    fn sync_id(timestamp: u32, seed: u8) -> SyncId {
+        SyncId::new(
+            timestamp,
+            2,
+            MessageType::MESSAGE_TYPE_CAST_ADD,
+            &hash_data(&[seed]),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn insert_and_delete_restore_the_root_hash() {
+        let mut trie = MerkleTrie::new();
+        let empty = trie.root_hash().to_vec();
+        let a = sync_id(100_000, 1);
+        let b = sync_id(100_000, 2);
+        let c = sync_id(100_050, 3);
+
+        assert!(trie.insert_sync_id(&a));
+        let with_a = trie.root_hash().to_vec();
+        assert!(!trie.insert_sync_id(&a));
+        assert!(trie.insert_sync_id(&b));
+        assert!(trie.insert_sync_id(&c));
+        assert_eq!(trie.items(), 3);
+        assert!(trie.exists(&b));
+        assert!(!trie.exists(&sync_id(100_000, 4)));
+
+        // Insertion order doesn't matter.
+        let mut other = MerkleTrie::new();
+        for id in [&c, &b, &a] {
+            other.insert_sync_id(id);
+        }
+        assert_eq!(other.root_hash(), trie.root_hash());
+
+        assert!(trie.delete_sync_id(&c));
+        assert!(trie.delete_sync_id(&b));
+        assert!(!trie.delete_sync_id(&b));
+        assert_eq!(trie.root_hash(), with_a);
+        assert!(trie.delete_sync_id(&a));
+        assert_eq!(trie.root_hash(), empty);
+        assert_eq!(trie.items(), 0);
+    }
+
+    #[test]
+    fn snapshots_and_sync_ids_follow_the_prefix() {
+        let mut trie = MerkleTrie::new();
+        let ids = [
+            sync_id(100_000, 1),
+            sync_id(100_001, 2),
+            sync_id(200_000, 3),
+        ];
+        for id in &ids {
+            trie.insert_sync_id(id);
+        }
+        let prefix = &timestamp_prefix(100_000)[..9];
+        assert_eq!(trie.sync_ids_by_prefix(prefix), ids[..2].to_vec());
+        assert_eq!(ids[0].timestamp(), Some(100_000));
+
+        let snapshot = trie.snapshot(prefix);
+        assert_eq!(snapshot.prefix, prefix);
+        assert_eq!(snapshot.excluded_hashes.len(), 10);
+        assert_eq!(snapshot.num_messages, 3);
+        let missing = trie.snapshot(&timestamp_prefix(300_000));
+        assert_eq!(missing.prefix, b"0000");
+    }
+
+    #[test]
+    fn diffs_against_hub_metadata() {
+        let mut local = MerkleTrie::new();
+        let mut remote = MerkleTrie::new();
+        for id in [sync_id(100_000, 1), sync_id(200_000, 2)] {
+            local.insert_sync_id(&id);
+            remote.insert_sync_id(&id);
+        }
+        remote.insert_sync_id(&sync_id(300_000, 3));
+
+        let root = remote.metadata(b"").unwrap();
+        assert_eq!(root.get_num_messages(), 3);
+        assert_eq!(local.diff(&root), vec![b"0".to_vec()]);
+
+        // Walk down the differing branch until it reaches the missing message.
+        let mut prefix = Vec::new();
+        while let [next] = local.diff(&remote.metadata(&prefix).unwrap()).as_slice() {
+            if *next == prefix {
+                break;
+            }
+            prefix = next.clone();
+        }
+        assert_eq!(prefix, timestamp_prefix(300_000));
+        assert_eq!(
+            remote.sync_ids_by_prefix(&prefix),
+            vec![sync_id(300_000, 3)]
+        );
+        assert!(remote.diff(&remote.metadata(b"").unwrap()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn lays_out_sync_ids_like_hubs() {
+        let hash = [0xab; HASH_LENGTH];
+        let id = SyncId::new(
+            100_000,
+            0x0102_0304,
+            MessageType::MESSAGE_TYPE_REACTION_REMOVE,
+            &hash,
+        )
+        .unwrap();
+        let mut expected = b"0000100000".to_vec();
+        expected.extend_from_slice(&[1, 0x01, 0x02, 0x03, 0x04, 3]);
+        expected.extend_from_slice(&hash);
+        assert_eq!(id.as_bytes(), expected);
+        assert_eq!(id.timestamp(), Some(100_000));
+        assert_eq!(id.fid(), Some(0x0102_0304));
+        assert_eq!(id.hash(), hash);
+
+        let message = message_from(9152, LinkBuilder::add("follow", 2), 100_000).await;
+        let id = SyncId::from_message(&message).unwrap();
+        assert_eq!(&id.as_bytes()[..16], b"0000100000\x01\x00\x00\x23\xc0\x02");
+        assert_eq!(id.hash(), message.get_hash());
+
+        assert!(matches!(
+            SyncId::new(0, 1 << 32, MessageType::MESSAGE_TYPE_CAST_ADD, &hash),
+            Err(ValidationError::SyncIdFidTooLarge)
+        ));
+        assert!(matches!(
+            SyncId::new(0, 2, MessageType::MESSAGE_TYPE_FRAME_ACTION, &hash),
+            Err(ValidationError::NoSyncId(
+                MessageType::MESSAGE_TYPE_FRAME_ACTION
+            ))
+        ));
+    }
+}
@@ -0,0 +1,112 @@
+//! Encodings of the sync RPC messages that the schemas no longer define:
+//!
+//! ```protobuf
+//! message TrieNodePrefix { bytes prefix = 1; }
+//! message SyncIds { repeated bytes sync_ids = 1; }
+//! message TrieNodeSnapshotResponse {
+//!   bytes prefix = 1;
+//!   repeated string excluded_hashes = 2;
+//!   uint64 num_messages = 3;
+//!   string root_hash = 4;
+//! }
+//! ```
+
+use protobuf::wire_format::WireType;
+use protobuf::{CodedInputStream, CodedOutputStream, ProtobufResult};
+
+use super::trie::{SyncId, TrieSnapshot};
+
+fn encode(
+    write: impl FnOnce(&mut CodedOutputStream) -> ProtobufResult<()>,
+) -> ProtobufResult<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut os = CodedOutputStream::vec(&mut bytes);
+    write(&mut os)?;
+    os.flush()?;
+    drop(os);
+    Ok(bytes)
+}
+
+fn decode(
+    bytes: &[u8],
+    mut read: impl FnMut(&mut CodedInputStream, u32, WireType) -> ProtobufResult<()>,
+) -> ProtobufResult<()> {
+    let mut is = CodedInputStream::from_bytes(bytes);
+    while !is.eof()? {
+        let (field, wire_type) = is.read_tag_unpack()?;
+        read(&mut is, field, wire_type)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn encode_prefix(prefix: &[u8]) -> ProtobufResult<Vec<u8>> {
+    encode(|os| os.write_bytes(1, prefix))
+}
+
+#[cfg(test)]
+pub(crate) fn decode_prefix(bytes: &[u8]) -> ProtobufResult<Vec<u8>> {
+    let mut prefix = Vec::new();
+    decode(bytes, |is, field, wire_type| match field {
+        1 => {
+            prefix = is.read_bytes()?;
+            Ok(())
+        }
+        _ => is.skip_field(wire_type),
+    })?;
+    Ok(prefix)
+}
+
+pub(crate) fn encode_sync_ids(sync_ids: &[SyncId]) -> ProtobufResult<Vec<u8>> {
+    encode(|os| {
+        sync_ids
+            .iter()
+            .try_for_each(|sync_id| os.write_bytes(1, sync_id.as_bytes()))
+    })
+}
+
+pub(crate) fn decode_sync_ids(bytes: &[u8]) -> ProtobufResult<Vec<SyncId>> {
+    let mut sync_ids = Vec::new();
+    decode(bytes, |is, field, wire_type| match field {
+        1 => {
+            sync_ids.push(SyncId::from_bytes(is.read_bytes()?));
+            Ok(())
+        }
+        _ => is.skip_field(wire_type),
+    })?;
+    Ok(sync_ids)
+}
+
+#[cfg(test)]
+pub(crate) fn encode_snapshot(snapshot: &TrieSnapshot) -> ProtobufResult<Vec<u8>> {
+    encode(|os| {
+        os.write_bytes(1, &snapshot.prefix)?;
+        for hash in &snapshot.excluded_hashes {
+            os.write_string(2, hash)?;
+        }
+        os.write_uint64(3, snapshot.num_messages as u64)
+    })
+}
+
+pub(crate) fn decode_snapshot(bytes: &[u8]) -> ProtobufResult<TrieSnapshot> {
+    let mut snapshot = TrieSnapshot {
+        prefix: Vec::new(),
+        excluded_hashes: Vec::new(),
+        num_messages: 0,
+    };
+    decode(bytes, |is, field, wire_type| match field {
+        1 => {
+            snapshot.prefix = is.read_bytes()?;
+            Ok(())
+        }
+        2 => {
+            snapshot.excluded_hashes.push(is.read_string()?);
+            Ok(())
+        }
+        3 => {
+            snapshot.num_messages = is.read_uint64()? as usize;
+            Ok(())
+        }
+        _ => is.skip_field(wire_type),
+    })?;
+    Ok(snapshot)
+}
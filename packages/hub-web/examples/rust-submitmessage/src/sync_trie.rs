@@ -0,0 +1,471 @@
+// This file is generated by rust-protobuf 2.28.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `sync_trie.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_28_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DbTrieNode {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub child_chars: ::std::vec::Vec<u32>,
+    pub items: u32,
+    pub child_hashes: ::std::collections::HashMap<u32, ::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DbTrieNode {
+    fn default() -> &'a DbTrieNode {
+        <DbTrieNode as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DbTrieNode {
+    pub fn new() -> DbTrieNode {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // repeated uint32 child_chars = 2;
+
+
+    pub fn get_child_chars(&self) -> &[u32] {
+        &self.child_chars
+    }
+    pub fn clear_child_chars(&mut self) {
+        self.child_chars.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_child_chars(&mut self, v: ::std::vec::Vec<u32>) {
+        self.child_chars = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_child_chars(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.child_chars
+    }
+
+    // Take field
+    pub fn take_child_chars(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.child_chars, ::std::vec::Vec::new())
+    }
+
+    // uint32 items = 3;
+
+
+    pub fn get_items(&self) -> u32 {
+        self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: u32) {
+        self.items = v;
+    }
+
+    // repeated .DbTrieNode.child_hashes_MapEntry child_hashes = 4;
+
+
+    pub fn get_child_hashes(&self) -> &::std::collections::HashMap<u32, ::std::vec::Vec<u8>> {
+        &self.child_hashes
+    }
+    pub fn clear_child_hashes(&mut self) {
+        self.child_hashes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_child_hashes(&mut self, v: ::std::collections::HashMap<u32, ::std::vec::Vec<u8>>) {
+        self.child_hashes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_child_hashes(&mut self) -> &mut ::std::collections::HashMap<u32, ::std::vec::Vec<u8>> {
+        &mut self.child_hashes
+    }
+
+    // Take field
+    pub fn take_child_hashes(&mut self) -> ::std::collections::HashMap<u32, ::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.child_hashes, ::std::collections::HashMap::new())
+    }
+}
+
+impl ::protobuf::Message for DbTrieNode {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.child_chars)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.items = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeBytes>(wire_type, is, &mut self.child_hashes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        for value in &self.child_chars {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if self.items != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.items, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeBytes>(4, &self.child_hashes);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        for v in &self.child_chars {
+            os.write_uint32(2, *v)?;
+        };
+        if self.items != 0 {
+            os.write_uint32(3, self.items)?;
+        }
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeBytes>(4, &self.child_hashes, os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DbTrieNode {
+        DbTrieNode::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "key",
+                |m: &DbTrieNode| { &m.key },
+                |m: &mut DbTrieNode| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "child_chars",
+                |m: &DbTrieNode| { &m.child_chars },
+                |m: &mut DbTrieNode| { &mut m.child_chars },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "items",
+                |m: &DbTrieNode| { &m.items },
+                |m: &mut DbTrieNode| { &mut m.items },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeUint32, ::protobuf::types::ProtobufTypeBytes>(
+                "child_hashes",
+                |m: &DbTrieNode| { &m.child_hashes },
+                |m: &mut DbTrieNode| { &mut m.child_hashes },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DbTrieNode>(
+                "DbTrieNode",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DbTrieNode {
+        static instance: ::protobuf::rt::LazyV2<DbTrieNode> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DbTrieNode::new)
+    }
+}
+
+impl ::protobuf::Clear for DbTrieNode {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.child_chars.clear();
+        self.items = 0;
+        self.child_hashes.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DbTrieNode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DbTrieNode {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DbMerkleTrieMetadata {
+    // message fields
+    pub outdated_hash: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DbMerkleTrieMetadata {
+    fn default() -> &'a DbMerkleTrieMetadata {
+        <DbMerkleTrieMetadata as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DbMerkleTrieMetadata {
+    pub fn new() -> DbMerkleTrieMetadata {
+        ::std::default::Default::default()
+    }
+
+    // bool outdated_hash = 1;
+
+
+    pub fn get_outdated_hash(&self) -> bool {
+        self.outdated_hash
+    }
+    pub fn clear_outdated_hash(&mut self) {
+        self.outdated_hash = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_outdated_hash(&mut self, v: bool) {
+        self.outdated_hash = v;
+    }
+}
+
+impl ::protobuf::Message for DbMerkleTrieMetadata {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.outdated_hash = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.outdated_hash != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.outdated_hash != false {
+            os.write_bool(1, self.outdated_hash)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DbMerkleTrieMetadata {
+        DbMerkleTrieMetadata::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "outdated_hash",
+                |m: &DbMerkleTrieMetadata| { &m.outdated_hash },
+                |m: &mut DbMerkleTrieMetadata| { &mut m.outdated_hash },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DbMerkleTrieMetadata>(
+                "DbMerkleTrieMetadata",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DbMerkleTrieMetadata {
+        static instance: ::protobuf::rt::LazyV2<DbMerkleTrieMetadata> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DbMerkleTrieMetadata::new)
+    }
+}
+
+impl ::protobuf::Clear for DbMerkleTrieMetadata {
+    fn clear(&mut self) {
+        self.outdated_hash = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DbMerkleTrieMetadata {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DbMerkleTrieMetadata {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0fsync_trie.proto\"\xe6\x01\n\nDbTrieNode\x12\x12\n\x03key\x18\x01\
+    \x20\x01(\x0cR\x03keyB\0\x12!\n\x0bchild_chars\x18\x02\x20\x03(\rR\nchil\
+    dCharsB\0\x12\x16\n\x05items\x18\x03\x20\x01(\rR\x05itemsB\0\x12F\n\x0cc\
+    hild_hashes\x18\x04\x20\x03(\x0b2!.DbTrieNode.child_hashes_MapEntryR\x0b\
+    childHashesB\0\x1a?\n\x15child_hashes_MapEntry\x12\x0e\n\x03key\x18\x01(\
+    \rR\x03key\x12\x12\n\x05value\x18\x02(\x0cR\x05value:\x028\x01:\0\"?\n\
+    \x14DbMerkleTrieMetadata\x12%\n\routdated_hash\x18\x01\x20\x01(\x08R\x0c\
+    outdatedHashB\0:\0B\0b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,31 @@
+//! Fixtures shared by the unit tests.
+
+use ed25519_dalek::SigningKey;
+
+use crate::bodies::MessageBody;
+use crate::message::{FarcasterNetwork, Message};
+use crate::signers::InMemorySigner;
+use crate::MessageBuilder;
+
+/// The ed25519 key test messages are signed with.
+pub(crate) fn test_signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32])
+}
+
+pub(crate) fn test_signer() -> InMemorySigner {
+    InMemorySigner::new(test_signing_key())
+}
+
+/// A mainnet message from `fid`, signed by [`test_signer`].
+pub(crate) async fn message_from(fid: u64, body: impl MessageBody, timestamp: u32) -> Message {
+    MessageBuilder::new(fid, FarcasterNetwork::FARCASTER_NETWORK_MAINNET, body)
+        .timestamp(timestamp)
+        .build(&test_signer())
+        .await
+        .unwrap()
+}
+
+/// A mainnet message from fid 2, signed by [`test_signer`].
+pub(crate) async fn message(body: impl MessageBody, timestamp: u32) -> Message {
+    message_from(2, body, timestamp).await
+}
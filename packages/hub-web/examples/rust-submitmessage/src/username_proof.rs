@@ -372,6 +372,7 @@ pub enum UserNameType {
     USERNAME_TYPE_NONE = 0,
     USERNAME_TYPE_FNAME = 1,
     USERNAME_TYPE_ENS_L1 = 2,
+    USERNAME_TYPE_BASENAME = 3,
 }
 
 impl ::protobuf::ProtobufEnum for UserNameType {
@@ -384,6 +385,7 @@ impl ::protobuf::ProtobufEnum for UserNameType {
             0 => ::std::option::Option::Some(UserNameType::USERNAME_TYPE_NONE),
             1 => ::std::option::Option::Some(UserNameType::USERNAME_TYPE_FNAME),
             2 => ::std::option::Option::Some(UserNameType::USERNAME_TYPE_ENS_L1),
+            3 => ::std::option::Option::Some(UserNameType::USERNAME_TYPE_BASENAME),
             _ => ::std::option::Option::None
         }
     }
@@ -393,6 +395,7 @@ impl ::protobuf::ProtobufEnum for UserNameType {
             UserNameType::USERNAME_TYPE_NONE,
             UserNameType::USERNAME_TYPE_FNAME,
             UserNameType::USERNAME_TYPE_ENS_L1,
+            UserNameType::USERNAME_TYPE_BASENAME,
         ];
         values
     }
@@ -426,9 +429,10 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     (\x0cR\x04nameB\0\x12\x16\n\x05owner\x18\x03\x20\x01(\x0cR\x05ownerB\0\
     \x12\x1e\n\tsignature\x18\x04\x20\x01(\x0cR\tsignatureB\0\x12\x12\n\x03f\
     id\x18\x05\x20\x01(\x04R\x03fidB\0\x12#\n\x04type\x18\x06\x20\x01(\x0e2\
-    \r.UserNameTypeR\x04typeB\0:\0*[\n\x0cUserNameType\x12\x16\n\x12USERNAME\
+    \r.UserNameTypeR\x04typeB\0:\0*w\n\x0cUserNameType\x12\x16\n\x12USERNAME\
     _TYPE_NONE\x10\0\x12\x17\n\x13USERNAME_TYPE_FNAME\x10\x01\x12\x18\n\x14U\
-    SERNAME_TYPE_ENS_L1\x10\x02\x1a\0B\0b\x06proto3\
+    SERNAME_TYPE_ENS_L1\x10\x02\x12\x1a\n\x16USERNAME_TYPE_BASENAME\x10\x03\
+    \x1a\0B\0b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
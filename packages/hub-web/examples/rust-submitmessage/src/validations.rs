@@ -0,0 +1,1013 @@
+//! Structural message validation, mirroring `packages/core/src/validations.ts`.
+//!
+//! These are the checks a hub runs before accepting a message, so senders can reject bad messages
+//! locally instead of discovering them after a round trip to `/v1/submitMessage`.
+
+use protobuf::wire_format::WireType;
+use protobuf::{CodedInputStream, Message as _};
+
+use crate::builder::HASH_LENGTH;
+use crate::farcaster_time::{get_farcaster_time, to_farcaster_time, TimeError};
+use crate::message::{
+    CastAddBody, CastId, CastRemoveBody, CastType, Embed, Embed_oneof_embed, FarcasterNetwork,
+    FrameActionBody, LendStorageBody, LinkBody, LinkCompactStateBody, Message, MessageData,
+    MessageData_oneof_body, MessageType, Protocol, ReactionBody, ReactionBody_oneof_target,
+    ReactionType, UserDataBody, UserDataType, VerificationAddAddressBody, VerificationRemoveBody,
+};
+use crate::username_proof::{UserNameProof, UserNameType};
+use crate::verify::{verify_message, VerifyError};
+
+/// Number of seconds (10 minutes) that is appropriate for clock skew
+pub const ALLOWED_CLOCK_SKEW_SECONDS: u32 = 10 * 60;
+
+/// Casts before this farcaster timestamp may still use `embeds_deprecated`.
+pub const EMBEDS_V1_CUTOFF: u32 = 73612800; // 5/3/23 00:00 UTC
+
+/// Largest `data_bytes` accepted by a hub.
+pub const MAX_DATA_BYTES: usize = 65_536;
+
+pub const MAX_CAST_TEXT_BYTES: usize = 320;
+pub const MAX_LONG_CAST_TEXT_BYTES: usize = 1024;
+pub const MAX_TEN_K_CAST_TEXT_BYTES: usize = 10_000;
+pub const MAX_EMBEDS: usize = 4;
+pub const MAX_DEPRECATED_EMBEDS: usize = 2;
+pub const MAX_MENTIONS: usize = 10;
+pub const MAX_URL_BYTES: usize = 256;
+pub const MAX_LINK_TYPE_BYTES: usize = 8;
+pub const MAX_LEND_STORAGE_UNITS: u64 = 5000;
+pub const MAX_FNAME_LENGTH: usize = 16;
+pub const USERNAME_MAX_LENGTH: usize = 25;
+pub const MAX_FRAME_BUTTON_INDEX: u32 = 5;
+pub const MAX_FRAME_URL_BYTES: usize = 1024;
+pub const MAX_FRAME_INPUT_TEXT_BYTES: usize = 256;
+pub const MAX_FRAME_STATE_BYTES: usize = 4096;
+pub const MAX_FRAME_TRANSACTION_ID_BYTES: usize = 256;
+pub const MAX_FRAME_ADDRESS_BYTES: usize = 64;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ValidationError {
+    #[error("data is missing")]
+    MissingData,
+    #[error("failed to decode data_bytes: {0}")]
+    InvalidDataBytes(#[from] protobuf::ProtobufError),
+    #[error("data_bytes > {MAX_DATA_BYTES} bytes")]
+    DataBytesTooLong,
+    #[error("hash is missing")]
+    MissingHash,
+    #[error("hash must be {HASH_LENGTH} bytes")]
+    InvalidHashLength,
+    #[error(transparent)]
+    Verify(#[from] VerifyError),
+    #[error("fid is missing")]
+    MissingFid,
+    #[error("invalid clock: {0}")]
+    InvalidClock(#[from] TimeError),
+    #[error("timestamp more than 10 mins in the future")]
+    TimestampInFuture,
+    #[error("timestamp more than 10 mins in the past")]
+    TimestampInPast,
+    #[error("invalid network")]
+    InvalidNetwork,
+    #[error("body does not match message type {0:?}")]
+    InvalidBodyType(MessageType),
+    #[error("text > {MAX_CAST_TEXT_BYTES} bytes")]
+    TextTooLong,
+    #[error("text > {MAX_LONG_CAST_TEXT_BYTES} bytes for long cast")]
+    LongCastTextTooLong,
+    #[error("text too short for long cast")]
+    LongCastTextTooShort,
+    #[error("text > 10,000 bytes for 10k cast")]
+    TenKCastTextTooLong,
+    #[error("text too short for 10k cast")]
+    TenKCastTextTooShort,
+    #[error("invalid cast type")]
+    InvalidCastType,
+    #[error("cannot use both parentUrl and parentCastId")]
+    MixedParents,
+    #[error("embeds > {MAX_EMBEDS}")]
+    TooManyEmbeds,
+    #[error("string embeds > {MAX_DEPRECATED_EMBEDS}")]
+    TooManyDeprecatedEmbeds,
+    #[error("string embeds have been deprecated")]
+    DeprecatedEmbeds,
+    #[error("cannot use both embeds and string embeds")]
+    MixedEmbeds,
+    #[error("embed must have either url or castId")]
+    MissingEmbed,
+    #[error("mentions > {MAX_MENTIONS}")]
+    TooManyMentions,
+    #[error("mentions and mentionsPositions must match")]
+    MentionsMismatch,
+    #[error("mentionsPositions must be a position in text")]
+    MentionPositionOutOfRange,
+    #[error("mentionsPositions must be sorted in ascending order")]
+    MentionPositionsUnsorted,
+    #[error("cast is empty")]
+    EmptyCast,
+    #[error("url must be between 1-{max} bytes")]
+    InvalidUrl { max: usize },
+    #[error("target is missing")]
+    MissingTarget,
+    #[error("invalid reaction type")]
+    InvalidReactionType,
+    #[error("link type must be between 1-{MAX_LINK_TYPE_BYTES} bytes")]
+    InvalidLinkType,
+    #[error("num storage units too large")]
+    TooManyStorageUnits,
+    #[error("address must be {expected} bytes")]
+    InvalidAddressLength { expected: usize },
+    #[error("blockHash must be 32 bytes")]
+    InvalidBlockHashLength,
+    #[error("invalid claimSignature length")]
+    InvalidClaimSignatureLength,
+    #[error("invalid user data type")]
+    InvalidUserDataType,
+    #[error("{0:?} value > {1} bytes")]
+    UserDataTooLong(UserDataType, usize),
+    #[error("fname {0:?} is invalid")]
+    InvalidFname(String),
+    #[error("ensName {0:?} is invalid")]
+    InvalidEnsName(String),
+    #[error("invalid location string {0:?}")]
+    InvalidLocation(String),
+    #[error("twitter username {0:?} is invalid")]
+    InvalidTwitterUsername(String),
+    #[error("github username {0:?} is invalid")]
+    InvalidGithubUsername(String),
+    #[error("profile token {0:?} is not a valid CAIP-19 token")]
+    InvalidProfileToken(String),
+    #[error("invalid username type")]
+    InvalidUsernameType,
+    #[error("fid in username proof does not match fid in message data")]
+    UsernameProofFidMismatch,
+    #[error("timestamp in username proof does not match timestamp in message data")]
+    UsernameProofTimestampMismatch,
+    #[error("invalid button index")]
+    InvalidButtonIndex,
+    #[error("invalid url")]
+    InvalidFrameUrl,
+    #[error("invalid input text")]
+    InvalidInputText,
+    #[error("invalid state")]
+    InvalidState,
+    #[error("invalid transaction id")]
+    InvalidTransactionId,
+    #[error("invalid address")]
+    InvalidFrameAddress,
+    #[error("{0:?} messages have no sync id")]
+    NoSyncId(MessageType),
+    #[error("fid > {} cannot be put in a sync id", u32::MAX)]
+    SyncIdFidTooLarge,
+}
+
+pub type ValidationResult<T = ()> = Result<T, ValidationError>;
+
+/// Decodes the `MessageData` carried by a message, preferring `data_bytes` over `data`.
+pub fn message_data(message: &Message) -> ValidationResult<MessageData> {
+    if !message.get_data_bytes().is_empty() {
+        Ok(MessageData::parse_from_bytes(message.get_data_bytes())?)
+    } else if message.has_data() {
+        Ok(message.get_data().clone())
+    } else {
+        Err(ValidationError::MissingData)
+    }
+}
+
+/// Validates the message data, then verifies the hash and signature of the envelope.
+pub fn validate_message(message: &Message) -> ValidationResult {
+    let data = message_data(message)?;
+    validate_message_data(&data)?;
+    if data.get_field_type() == MessageType::MESSAGE_TYPE_CAST_ADD
+        && has_both_parents(message.get_data_bytes())?
+    {
+        return Err(ValidationError::MixedParents);
+    }
+
+    if message.get_data_bytes().len() > MAX_DATA_BYTES {
+        return Err(ValidationError::DataBytesTooLong);
+    }
+    validate_message_hash(message.get_hash())?;
+    Ok(verify_message(message)?)
+}
+
+/// Whether encoded `MessageData` carries a cast add body with both `parent_cast_id` and
+/// `parent_url`. The generated `parent` oneof keeps only the last of the two, so this can only be
+/// seen on the wire.
+fn has_both_parents(data_bytes: &[u8]) -> ValidationResult<bool> {
+    // MessageData.cast_add_body = 5; CastAddBody.parent_cast_id = 3, CastAddBody.parent_url = 7
+    let mut data = CodedInputStream::from_bytes(data_bytes);
+    let mut cast_add_body = None;
+    while !data.eof()? {
+        match data.read_tag_unpack()? {
+            (5, WireType::WireTypeLengthDelimited) => cast_add_body = Some(data.read_bytes()?),
+            (_, wire_type) => data.skip_field(wire_type)?,
+        }
+    }
+    let Some(cast_add_body) = cast_add_body else {
+        return Ok(false);
+    };
+
+    let mut body = CodedInputStream::from_bytes(&cast_add_body);
+    let (mut parent_cast_id, mut parent_url) = (false, false);
+    while !body.eof()? {
+        let (field, wire_type) = body.read_tag_unpack()?;
+        parent_cast_id |= field == 3;
+        parent_url |= field == 7;
+        body.skip_field(wire_type)?;
+    }
+    Ok(parent_cast_id && parent_url)
+}
+
+/// Validates `data` against the current system time.
+pub fn validate_message_data(data: &MessageData) -> ValidationResult {
+    let now = get_farcaster_time()?;
+    validate_message_data_at(data, now)
+}
+
+/// Validates `data` as if the current farcaster time were `now`.
+pub fn validate_message_data_at(data: &MessageData, now: u32) -> ValidationResult {
+    validate_fid(data.get_fid())?;
+
+    if data.get_timestamp().saturating_sub(now) > ALLOWED_CLOCK_SKEW_SECONDS {
+        return Err(ValidationError::TimestampInFuture);
+    }
+
+    if data.get_network() == FarcasterNetwork::FARCASTER_NETWORK_NONE {
+        return Err(ValidationError::InvalidNetwork);
+    }
+
+    let message_type = data.get_field_type();
+    let invalid_body = || ValidationError::InvalidBodyType(message_type);
+    let body = data.body.as_ref().ok_or_else(invalid_body)?;
+
+    match (message_type, body) {
+        (MessageType::MESSAGE_TYPE_CAST_ADD, MessageData_oneof_body::cast_add_body(body)) => {
+            let allow_embeds_deprecated = data.get_timestamp() < EMBEDS_V1_CUTOFF;
+            validate_cast_add_body(body, allow_embeds_deprecated)
+        }
+        (MessageType::MESSAGE_TYPE_CAST_REMOVE, MessageData_oneof_body::cast_remove_body(body)) => {
+            validate_cast_remove_body(body)
+        }
+        (
+            MessageType::MESSAGE_TYPE_REACTION_ADD | MessageType::MESSAGE_TYPE_REACTION_REMOVE,
+            MessageData_oneof_body::reaction_body(body),
+        ) => validate_reaction_body(body),
+        (
+            MessageType::MESSAGE_TYPE_LINK_ADD | MessageType::MESSAGE_TYPE_LINK_REMOVE,
+            MessageData_oneof_body::link_body(body),
+        ) => validate_link_body(body),
+        (
+            MessageType::MESSAGE_TYPE_LINK_COMPACT_STATE,
+            MessageData_oneof_body::link_compact_state_body(body),
+        ) => validate_link_compact_state_body(body),
+        (MessageType::MESSAGE_TYPE_USER_DATA_ADD, MessageData_oneof_body::user_data_body(body)) => {
+            validate_user_data_add_body(body)
+        }
+        (
+            MessageType::MESSAGE_TYPE_VERIFICATION_ADD_ETH_ADDRESS,
+            MessageData_oneof_body::verification_add_address_body(body),
+        ) => validate_verification_add_address_body(body),
+        (
+            MessageType::MESSAGE_TYPE_VERIFICATION_REMOVE,
+            MessageData_oneof_body::verification_remove_body(body),
+        ) => validate_verification_remove_body(body),
+        (
+            MessageType::MESSAGE_TYPE_USERNAME_PROOF,
+            MessageData_oneof_body::username_proof_body(body),
+        ) => validate_username_proof_body(body, data),
+        (
+            MessageType::MESSAGE_TYPE_FRAME_ACTION,
+            MessageData_oneof_body::frame_action_body(body),
+        ) => validate_frame_action_body(body),
+        (
+            MessageType::MESSAGE_TYPE_LEND_STORAGE,
+            MessageData_oneof_body::lend_storage_body(body),
+        ) => {
+            if data.get_timestamp() < now.saturating_sub(ALLOWED_CLOCK_SKEW_SECONDS) {
+                return Err(ValidationError::TimestampInPast);
+            }
+            validate_lend_storage_body(body)
+        }
+        _ => Err(invalid_body()),
+    }
+}
+
+pub fn validate_message_hash(hash: &[u8]) -> ValidationResult {
+    if hash.is_empty() {
+        return Err(ValidationError::MissingHash);
+    }
+    if hash.len() != HASH_LENGTH {
+        return Err(ValidationError::InvalidHashLength);
+    }
+    Ok(())
+}
+
+pub fn validate_fid(fid: u64) -> ValidationResult {
+    if fid == 0 {
+        return Err(ValidationError::MissingFid);
+    }
+    Ok(())
+}
+
+pub fn validate_cast_id(cast_id: &CastId) -> ValidationResult {
+    validate_fid(cast_id.get_fid())?;
+    validate_message_hash(cast_id.get_hash())
+}
+
+pub fn validate_url(url: &str) -> ValidationResult {
+    if url.is_empty() || url.len() > MAX_URL_BYTES {
+        return Err(ValidationError::InvalidUrl { max: MAX_URL_BYTES });
+    }
+    Ok(())
+}
+
+pub fn validate_embed(embed: &Embed) -> ValidationResult {
+    match &embed.embed {
+        Some(Embed_oneof_embed::url(url)) => validate_url(url),
+        Some(Embed_oneof_embed::cast_id(cast_id)) => validate_cast_id(cast_id),
+        None => Err(ValidationError::MissingEmbed),
+    }
+}
+
+pub fn validate_cast_add_body(
+    body: &CastAddBody,
+    allow_embeds_deprecated: bool,
+) -> ValidationResult {
+    let text_bytes = body.get_text().len();
+    // A type this schema doesn't know is kept in the unknown fields, leaving the default CAST
+    if body.get_unknown_fields().get(8).is_some() {
+        return Err(ValidationError::InvalidCastType);
+    }
+    match body.get_field_type() {
+        CastType::CAST if text_bytes > MAX_CAST_TEXT_BYTES => {
+            return Err(ValidationError::TextTooLong)
+        }
+        CastType::LONG_CAST if text_bytes > MAX_LONG_CAST_TEXT_BYTES => {
+            return Err(ValidationError::LongCastTextTooLong)
+        }
+        CastType::LONG_CAST if text_bytes <= MAX_CAST_TEXT_BYTES => {
+            return Err(ValidationError::LongCastTextTooShort)
+        }
+        CastType::TEN_K_CAST if text_bytes > MAX_TEN_K_CAST_TEXT_BYTES => {
+            return Err(ValidationError::TenKCastTextTooLong)
+        }
+        CastType::TEN_K_CAST if text_bytes <= MAX_LONG_CAST_TEXT_BYTES => {
+            return Err(ValidationError::TenKCastTextTooShort)
+        }
+        _ => {}
+    }
+
+    let embeds = body.get_embeds();
+    let embeds_deprecated = body.get_embeds_deprecated();
+    if embeds.len() > MAX_EMBEDS {
+        return Err(ValidationError::TooManyEmbeds);
+    }
+    if allow_embeds_deprecated && embeds_deprecated.len() > MAX_DEPRECATED_EMBEDS {
+        return Err(ValidationError::TooManyDeprecatedEmbeds);
+    }
+    if !allow_embeds_deprecated && !embeds_deprecated.is_empty() {
+        return Err(ValidationError::DeprecatedEmbeds);
+    }
+
+    let mentions = body.get_mentions();
+    let positions = body.get_mentions_positions();
+    if mentions.len() > MAX_MENTIONS {
+        return Err(ValidationError::TooManyMentions);
+    }
+    if mentions.len() != positions.len() {
+        return Err(ValidationError::MentionsMismatch);
+    }
+
+    if !embeds.is_empty() && !embeds_deprecated.is_empty() {
+        return Err(ValidationError::MixedEmbeds);
+    }
+
+    if text_bytes == 0 && embeds.is_empty() && embeds_deprecated.is_empty() && mentions.is_empty() {
+        return Err(ValidationError::EmptyCast);
+    }
+
+    for embed in embeds {
+        validate_embed(embed)?;
+    }
+    for url in embeds_deprecated {
+        validate_url(url)?;
+    }
+
+    for (i, (&fid, &position)) in mentions.iter().zip(positions).enumerate() {
+        validate_fid(fid)?;
+        if position as usize > text_bytes {
+            return Err(ValidationError::MentionPositionOutOfRange);
+        }
+        if i > 0 && position < positions[i - 1] {
+            return Err(ValidationError::MentionPositionsUnsorted);
+        }
+    }
+
+    if body.has_parent_cast_id() {
+        validate_cast_id(body.get_parent_cast_id())?;
+    } else if body.has_parent_url() {
+        validate_url(body.get_parent_url())?;
+    }
+
+    Ok(())
+}
+
+pub fn validate_cast_remove_body(body: &CastRemoveBody) -> ValidationResult {
+    validate_message_hash(body.get_target_hash())
+}
+
+pub fn validate_reaction_body(body: &ReactionBody) -> ValidationResult {
+    if body.get_field_type() == ReactionType::REACTION_TYPE_NONE {
+        return Err(ValidationError::InvalidReactionType);
+    }
+    match &body.target {
+        Some(ReactionBody_oneof_target::target_cast_id(cast_id)) => validate_cast_id(cast_id),
+        Some(ReactionBody_oneof_target::target_url(url)) => validate_url(url),
+        None => Err(ValidationError::MissingTarget),
+    }
+}
+
+pub fn validate_link_type(link_type: &str) -> ValidationResult {
+    if link_type.is_empty() || link_type.len() > MAX_LINK_TYPE_BYTES {
+        return Err(ValidationError::InvalidLinkType);
+    }
+    Ok(())
+}
+
+pub fn validate_link_body(body: &LinkBody) -> ValidationResult {
+    validate_link_type(body.get_field_type())?;
+    if !body.has_target_fid() {
+        return Err(ValidationError::MissingTarget);
+    }
+    validate_fid(body.get_target_fid())
+}
+
+pub fn validate_link_compact_state_body(body: &LinkCompactStateBody) -> ValidationResult {
+    validate_link_type(body.get_field_type())?;
+    for &target_fid in body.get_target_fids() {
+        validate_fid(target_fid)?;
+    }
+    Ok(())
+}
+
+pub fn validate_lend_storage_body(body: &LendStorageBody) -> ValidationResult {
+    if body.get_num_units() > MAX_LEND_STORAGE_UNITS {
+        return Err(ValidationError::TooManyStorageUnits);
+    }
+    Ok(())
+}
+
+fn validate_address(address: &[u8], protocol: Protocol) -> ValidationResult {
+    let expected = match protocol {
+        Protocol::PROTOCOL_ETHEREUM => 20,
+        Protocol::PROTOCOL_SOLANA => 32,
+    };
+    if address.len() != expected {
+        return Err(ValidationError::InvalidAddressLength { expected });
+    }
+    Ok(())
+}
+
+/// Validates the shape of a verification. The claim signature itself is not checked here.
+pub fn validate_verification_add_address_body(
+    body: &VerificationAddAddressBody,
+) -> ValidationResult {
+    let protocol = body.get_protocol();
+    validate_address(body.get_address(), protocol)?;
+    if body.get_block_hash().len() != 32 {
+        return Err(ValidationError::InvalidBlockHashLength);
+    }
+    let claim_signature = body.get_claim_signature();
+    let valid_signature_length = match protocol {
+        Protocol::PROTOCOL_ETHEREUM => !claim_signature.is_empty() && claim_signature.len() <= 2048,
+        Protocol::PROTOCOL_SOLANA => claim_signature.len() == 64,
+    };
+    if !valid_signature_length {
+        return Err(ValidationError::InvalidClaimSignatureLength);
+    }
+    Ok(())
+}
+
+pub fn validate_verification_remove_body(body: &VerificationRemoveBody) -> ValidationResult {
+    validate_address(body.get_address(), body.get_protocol())
+}
+
+pub fn validate_user_data_add_body(body: &UserDataBody) -> ValidationResult {
+    let user_data_type = body.get_field_type();
+    let value = body.get_value();
+    let max = match user_data_type {
+        UserDataType::USER_DATA_TYPE_NONE => return Err(ValidationError::InvalidUserDataType),
+        UserDataType::USER_DATA_TYPE_PFP => 256,
+        UserDataType::USER_DATA_TYPE_DISPLAY => 32,
+        UserDataType::USER_DATA_TYPE_BIO => 256,
+        UserDataType::USER_DATA_TYPE_URL => 256,
+        UserDataType::USER_DATA_TYPE_BANNER => 256,
+        UserDataType::USER_DATA_PRIMARY_ADDRESS_ETHEREUM => 42,
+        UserDataType::USER_DATA_PRIMARY_ADDRESS_SOLANA => 44,
+        UserDataType::USER_DATA_TYPE_PROFILE_TOKEN => {
+            if !value.is_empty() {
+                validate_profile_token(value)?;
+            }
+            256
+        }
+        UserDataType::USER_DATA_TYPE_USERNAME => {
+            // Users are allowed to set fname = '' to remove their fname
+            if !value.is_empty() && validate_fname(value).is_err() {
+                validate_ens_name(value)
+                    .map_err(|_| ValidationError::InvalidFname(value.into()))?;
+            }
+            return Ok(());
+        }
+        // Users are allowed to clear their location and usernames
+        UserDataType::USER_DATA_TYPE_LOCATION => return validate_user_location(value),
+        UserDataType::USER_DATA_TYPE_TWITTER if value.is_empty() => return Ok(()),
+        UserDataType::USER_DATA_TYPE_TWITTER => return validate_twitter_username(value),
+        UserDataType::USER_DATA_TYPE_GITHUB if value.is_empty() => return Ok(()),
+        UserDataType::USER_DATA_TYPE_GITHUB => return validate_github_username(value),
+    };
+    if value.len() > max {
+        return Err(ValidationError::UserDataTooLong(user_data_type, max));
+    }
+    Ok(())
+}
+
+/// Parses a `-?\d{1,int_digits}\.\d{2}` coordinate.
+fn parse_coordinate(value: &str, int_digits: usize) -> Option<f64> {
+    let (int, frac) = value.strip_prefix('-').unwrap_or(value).split_once('.')?;
+    let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if int.is_empty() || int.len() > int_digits || frac.len() != 2 || !digits(int) || !digits(frac)
+    {
+        return None;
+    }
+    value.parse().ok()
+}
+
+/// Checks that `location` is empty or `geo:<lat>,<long>` with two decimal places.
+pub fn validate_user_location(location: &str) -> ValidationResult {
+    if location.is_empty() {
+        return Ok(());
+    }
+    let invalid = || ValidationError::InvalidLocation(location.into());
+    let (latitude, longitude) = location
+        .strip_prefix("geo:")
+        .and_then(|coordinates| coordinates.split_once(','))
+        .ok_or_else(invalid)?;
+    let latitude = parse_coordinate(latitude, 2).ok_or_else(invalid)?;
+    let longitude = parse_coordinate(longitude, 3).ok_or_else(invalid)?;
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+/// Checks that `username` is 1-15 characters of `[a-z0-9_]`.
+pub fn validate_twitter_username(username: &str) -> ValidationResult {
+    let valid = !username.is_empty()
+        && username.len() <= 15
+        && username
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_');
+    if !valid {
+        return Err(ValidationError::InvalidTwitterUsername(username.into()));
+    }
+    Ok(())
+}
+
+/// Checks that `username` is 1-38 alphanumerics or single hyphens, not starting with a hyphen.
+pub fn validate_github_username(username: &str) -> ValidationResult {
+    let valid = !username.is_empty()
+        && username.len() <= 38
+        && !username.starts_with('-')
+        && !username.contains("--")
+        && username
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-');
+    if !valid {
+        return Err(ValidationError::InvalidGithubUsername(username.into()));
+    }
+    Ok(())
+}
+
+/// Checks that `token` is a CAIP-19 asset id, e.g. `eip155:1/erc20:0x6b17...`.
+pub fn validate_profile_token(token: &str) -> ValidationResult {
+    let invalid = || ValidationError::InvalidProfileToken(token.into());
+    let is = |s: &str, f: fn(u8) -> bool| !s.is_empty() && s.bytes().all(f);
+    let lower_alnum = |b: u8| b.is_ascii_lowercase() || b.is_ascii_digit();
+
+    let (chain_id, asset) = token.split_once('/').ok_or_else(invalid)?;
+    let (namespace, reference) = chain_id.split_once(':').ok_or_else(invalid)?;
+    let (asset_namespace, asset_reference) = asset.split_once(':').ok_or_else(invalid)?;
+    let (asset_reference, token_id) = match asset_reference.split_once('/') {
+        Some((asset_reference, token_id)) => (asset_reference, Some(token_id)),
+        None => (asset_reference, None),
+    };
+
+    let valid = is(namespace, lower_alnum)
+        && is(reference, |b| b.is_ascii_alphanumeric())
+        && (3..=8).contains(&asset_namespace.len())
+        && is(asset_namespace, |b| {
+            b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-'
+        })
+        && asset_reference.len() <= 128
+        && is(asset_reference, |b| {
+            b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'%')
+        })
+        && token_id.is_none_or(|id| is(id, |b| b.is_ascii_alphanumeric()));
+    if !valid {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+fn is_valid_fname_label(label: &str) -> bool {
+    let mut chars = label.chars();
+    let valid_first = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit());
+    valid_first
+        && label.len() <= MAX_FNAME_LENGTH
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Checks that `fname` is 1-16 characters of `[a-z0-9-]`, not starting with a hyphen.
+pub fn validate_fname(fname: &str) -> ValidationResult {
+    if !is_valid_fname_label(fname) {
+        return Err(ValidationError::InvalidFname(fname.into()));
+    }
+    Ok(())
+}
+
+/// Checks that `name` is a `.eth` or `.base.eth` name whose label is a valid fname.
+pub fn validate_ens_name(name: &str) -> ValidationResult {
+    let invalid = || ValidationError::InvalidEnsName(name.into());
+    if name.len() > USERNAME_MAX_LENGTH {
+        return Err(invalid());
+    }
+    let parts: Vec<&str> = name.split('.').collect();
+    let valid_suffix = matches!(parts.as_slice(), [_, "eth"] | [_, "base", "eth"]);
+    if !valid_suffix || !is_valid_fname_label(parts[0]) {
+        return Err(invalid());
+    }
+    Ok(())
+}
+
+pub fn validate_username_proof_body(proof: &UserNameProof, data: &MessageData) -> ValidationResult {
+    // Gossiped username proofs must only have an ENS type
+    if proof.get_field_type() != UserNameType::USERNAME_TYPE_ENS_L1 {
+        return Err(ValidationError::InvalidUsernameType);
+    }
+    let name = std::str::from_utf8(proof.get_name()).map_err(|_| {
+        ValidationError::InvalidEnsName(String::from_utf8_lossy(proof.get_name()).into())
+    })?;
+    validate_ens_name(name)?;
+    if proof.get_fid() != data.get_fid() {
+        return Err(ValidationError::UsernameProofFidMismatch);
+    }
+
+    // Proof time is in Unix seconds
+    let proof_farcaster_time = to_farcaster_time(proof.get_timestamp().saturating_mul(1000));
+    if proof_farcaster_time != Ok(data.get_timestamp()) {
+        return Err(ValidationError::UsernameProofTimestampMismatch);
+    }
+    Ok(())
+}
+
+pub fn validate_frame_action_body(body: &FrameActionBody) -> ValidationResult {
+    if body.get_button_index() > MAX_FRAME_BUTTON_INDEX {
+        return Err(ValidationError::InvalidButtonIndex);
+    }
+    let url = body.get_url();
+    if url.is_empty() || url.len() > MAX_FRAME_URL_BYTES {
+        return Err(ValidationError::InvalidFrameUrl);
+    }
+    if body.get_input_text().len() > MAX_FRAME_INPUT_TEXT_BYTES {
+        return Err(ValidationError::InvalidInputText);
+    }
+    if body.get_state().len() > MAX_FRAME_STATE_BYTES {
+        return Err(ValidationError::InvalidState);
+    }
+    if body.get_transaction_id().len() > MAX_FRAME_TRANSACTION_ID_BYTES {
+        return Err(ValidationError::InvalidTransactionId);
+    }
+    if body.get_address().len() > MAX_FRAME_ADDRESS_BYTES {
+        return Err(ValidationError::InvalidFrameAddress);
+    }
+    if body.has_cast_id() {
+        validate_cast_id(body.get_cast_id())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bodies::{cast_id, CastAddBuilder, LinkBuilder, MessageBody, UserDataBuilder};
+    use crate::builder::hash_data;
+    use crate::signers::MessageSigner;
+    use crate::test_utils::test_signer;
+    use crate::MessageBuilder;
+
+    const NOW: u32 = 100_000_000;
+
+    fn data_for(body: impl MessageBody) -> MessageData {
+        let mut data = MessageData::new();
+        data.set_fid(1);
+        data.set_timestamp(NOW);
+        data.set_network(FarcasterNetwork::FARCASTER_NETWORK_MAINNET);
+        data.set_field_type(body.message_type());
+        data.body = Some(body.into_body());
+        data
+    }
+
+    #[test]
+    fn accepts_valid_cast() {
+        let body = CastAddBuilder::new("hello @ and @")
+            .mention(2, 6)
+            .mention(3, 12);
+        assert!(validate_message_data_at(&data_for(body), NOW).is_ok());
+    }
+
+    #[test]
+    fn rejects_long_cast_text() {
+        let body = CastAddBuilder::new("a".repeat(MAX_CAST_TEXT_BYTES + 1));
+        assert!(matches!(
+            validate_message_data_at(&data_for(body), NOW),
+            Err(ValidationError::TextTooLong)
+        ));
+    }
+
+    #[test]
+    fn validates_text_length_by_cast_type() {
+        let cast = |len: usize, cast_type| {
+            let body = CastAddBuilder::new("a".repeat(len)).cast_type(cast_type);
+            validate_message_data_at(&data_for(body), NOW)
+        };
+        assert!(cast(MAX_CAST_TEXT_BYTES, CastType::CAST).is_ok());
+        assert!(cast(MAX_CAST_TEXT_BYTES + 1, CastType::LONG_CAST).is_ok());
+        assert!(cast(MAX_LONG_CAST_TEXT_BYTES, CastType::LONG_CAST).is_ok());
+        assert!(cast(MAX_LONG_CAST_TEXT_BYTES + 1, CastType::TEN_K_CAST).is_ok());
+        assert!(cast(MAX_TEN_K_CAST_TEXT_BYTES, CastType::TEN_K_CAST).is_ok());
+        assert!(matches!(
+            cast(MAX_CAST_TEXT_BYTES, CastType::LONG_CAST),
+            Err(ValidationError::LongCastTextTooShort)
+        ));
+        assert!(matches!(
+            cast(MAX_LONG_CAST_TEXT_BYTES + 1, CastType::LONG_CAST),
+            Err(ValidationError::LongCastTextTooLong)
+        ));
+        assert!(matches!(
+            cast(MAX_LONG_CAST_TEXT_BYTES, CastType::TEN_K_CAST),
+            Err(ValidationError::TenKCastTextTooShort)
+        ));
+        assert!(matches!(
+            cast(MAX_TEN_K_CAST_TEXT_BYTES + 1, CastType::TEN_K_CAST),
+            Err(ValidationError::TenKCastTextTooLong)
+        ));
+
+        // CastAddBody.type = 8, set to a value the schema doesn't define
+        let mut bytes = CastAddBuilder::new("hi")
+            .into_inner()
+            .write_to_bytes()
+            .unwrap();
+        bytes.extend_from_slice(&[8 << 3, 9]);
+        let body = CastAddBody::parse_from_bytes(&bytes).unwrap();
+        assert!(matches!(
+            validate_cast_add_body(&body, false),
+            Err(ValidationError::InvalidCastType)
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_casts_with_both_parents() {
+        let signer = test_signer();
+        let body = CastAddBuilder::new("hi").parent_url("https://example.com/channel");
+        let message = MessageBuilder::new(2, FarcasterNetwork::FARCASTER_NETWORK_MAINNET, body)
+            .build(&signer)
+            .await
+            .unwrap();
+        validate_message(&message).unwrap();
+
+        // Append a parent_cast_id (3) to the encoded cast add body (5) and re-sign the message
+        let mut parent = cast_id(3, vec![1; HASH_LENGTH]).write_to_bytes().unwrap();
+        parent.insert(0, parent.len() as u8);
+        parent.insert(0, 3 << 3 | 2);
+        let mut body = message_data(&message)
+            .unwrap()
+            .get_cast_add_body()
+            .write_to_bytes()
+            .unwrap();
+        body.extend_from_slice(&parent);
+        let mut data = message_data(&message).unwrap();
+        data.body = None;
+        let mut data_bytes = data.write_to_bytes().unwrap();
+        data_bytes.extend_from_slice(&[5 << 3 | 2, body.len() as u8]);
+        data_bytes.extend_from_slice(&body);
+
+        let mut message = message;
+        message.set_hash(hash_data(&data_bytes));
+        let signature = signer.sign(message.get_hash()).await.unwrap();
+        message.set_signature(signature.to_bytes().to_vec());
+        message.set_data_bytes(data_bytes);
+        assert!(matches!(
+            validate_message(&message),
+            Err(ValidationError::MixedParents)
+        ));
+    }
+
+    #[test]
+    fn rejects_mentions_without_positions() {
+        let mut body = CastAddBuilder::new("hi").into_inner();
+        body.mentions.push(2);
+        assert!(matches!(
+            validate_cast_add_body(&body, false),
+            Err(ValidationError::MentionsMismatch)
+        ));
+    }
+
+    #[test]
+    fn rejects_unsorted_or_out_of_range_mentions() {
+        let unsorted = CastAddBuilder::new("hi  ").mention(2, 3).mention(3, 1);
+        assert!(matches!(
+            validate_cast_add_body(&unsorted.into_inner(), false),
+            Err(ValidationError::MentionPositionsUnsorted)
+        ));
+        let out_of_range = CastAddBuilder::new("hi").mention(2, 3);
+        assert!(matches!(
+            validate_cast_add_body(&out_of_range.into_inner(), false),
+            Err(ValidationError::MentionPositionOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn rejects_too_many_embeds() {
+        let mut body = CastAddBuilder::new("");
+        for _ in 0..=MAX_EMBEDS {
+            body = body.embed_cast(cast_id(2, vec![1; HASH_LENGTH]));
+        }
+        assert!(matches!(
+            validate_cast_add_body(&body.into_inner(), false),
+            Err(ValidationError::TooManyEmbeds)
+        ));
+    }
+
+    #[test]
+    fn rejects_timestamp_in_future() {
+        let mut data = data_for(CastAddBuilder::new("hi"));
+        data.set_timestamp(NOW + ALLOWED_CLOCK_SKEW_SECONDS + 1);
+        assert!(matches!(
+            validate_message_data_at(&data, NOW),
+            Err(ValidationError::TimestampInFuture)
+        ));
+    }
+
+    #[test]
+    fn rejects_body_for_other_type() {
+        let mut data = data_for(CastAddBuilder::new("hi"));
+        data.set_field_type(MessageType::MESSAGE_TYPE_CAST_REMOVE);
+        assert!(matches!(
+            validate_message_data_at(&data, NOW),
+            Err(ValidationError::InvalidBodyType(_))
+        ));
+    }
+
+    #[test]
+    fn validates_link_type() {
+        assert!(validate_message_data_at(&data_for(LinkBuilder::add("follow", 2)), NOW).is_ok());
+        assert!(matches!(
+            validate_message_data_at(&data_for(LinkBuilder::add("", 2)), NOW),
+            Err(ValidationError::InvalidLinkType)
+        ));
+        assert!(matches!(
+            validate_message_data_at(&data_for(LinkBuilder::add("123456789", 2)), NOW),
+            Err(ValidationError::InvalidLinkType)
+        ));
+    }
+
+    #[test]
+    fn validates_link_compact_state() {
+        let compact_state = |link_type: &str, target_fids: Vec<u64>| {
+            let mut body = LinkCompactStateBody::new();
+            body.set_field_type(link_type.to_string());
+            body.set_target_fids(target_fids);
+            let mut data = data_for(LinkBuilder::add("follow", 2));
+            data.set_field_type(MessageType::MESSAGE_TYPE_LINK_COMPACT_STATE);
+            data.body = Some(MessageData_oneof_body::link_compact_state_body(body));
+            validate_message_data_at(&data, NOW)
+        };
+        assert!(compact_state("follow", vec![2, 3]).is_ok());
+        assert!(compact_state("follow", vec![]).is_ok());
+        assert!(matches!(
+            compact_state("", vec![2]),
+            Err(ValidationError::InvalidLinkType)
+        ));
+        assert!(matches!(
+            compact_state("follow", vec![2, 0]),
+            Err(ValidationError::MissingFid)
+        ));
+    }
+
+    #[test]
+    fn validates_lend_storage() {
+        let lend = |num_units: u64, timestamp: u32| {
+            let mut body = LendStorageBody::new();
+            body.set_to_fid(3);
+            body.set_num_units(num_units);
+            let mut data = data_for(LinkBuilder::add("follow", 2));
+            data.set_field_type(MessageType::MESSAGE_TYPE_LEND_STORAGE);
+            data.set_timestamp(timestamp);
+            data.body = Some(MessageData_oneof_body::lend_storage_body(body));
+            validate_message_data_at(&data, NOW)
+        };
+        assert!(lend(MAX_LEND_STORAGE_UNITS, NOW).is_ok());
+        assert!(lend(1, NOW - ALLOWED_CLOCK_SKEW_SECONDS).is_ok());
+        assert!(matches!(
+            lend(MAX_LEND_STORAGE_UNITS + 1, NOW),
+            Err(ValidationError::TooManyStorageUnits)
+        ));
+        assert!(matches!(
+            lend(1, NOW - ALLOWED_CLOCK_SKEW_SECONDS - 1),
+            Err(ValidationError::TimestampInPast)
+        ));
+    }
+
+    #[test]
+    fn validates_user_data_lengths() {
+        let display = UserDataBuilder::new(UserDataType::USER_DATA_TYPE_DISPLAY, "a".repeat(33));
+        assert!(matches!(
+            validate_message_data_at(&data_for(display), NOW),
+            Err(ValidationError::UserDataTooLong(
+                UserDataType::USER_DATA_TYPE_DISPLAY,
+                32
+            ))
+        ));
+        let username = UserDataBuilder::new(UserDataType::USER_DATA_TYPE_USERNAME, "alice.eth");
+        assert!(validate_message_data_at(&data_for(username), NOW).is_ok());
+        let username = UserDataBuilder::new(UserDataType::USER_DATA_TYPE_USERNAME, "-alice");
+        assert!(matches!(
+            validate_message_data_at(&data_for(username), NOW),
+            Err(ValidationError::InvalidFname(_))
+        ));
+    }
+
+    #[test]
+    fn validates_profile_user_data() {
+        assert!(validate_user_location("geo:37.77,-122.42").is_ok());
+        assert!(validate_user_location("").is_ok());
+        assert!(validate_user_location("geo:91.00,0.00").is_err());
+        assert!(validate_user_location("geo:37.7,-122.42").is_err());
+
+        assert!(validate_twitter_username("farcaster_xyz").is_ok());
+        assert!(validate_twitter_username("Farcaster").is_err());
+        assert!(validate_github_username("farcaster-xyz").is_ok());
+        assert!(validate_github_username("farcaster--xyz").is_err());
+
+        assert!(validate_profile_token(
+            "eip155:1/erc20:0x6b175474e89094c44da98b954eedeac495271d0f"
+        )
+        .is_ok());
+        assert!(validate_profile_token("eip155:1/erc721:0xabc/1").is_ok());
+        assert!(validate_profile_token("eip155:1").is_err());
+
+        let twitter = UserDataBuilder::new(UserDataType::USER_DATA_TYPE_TWITTER, "");
+        assert!(validate_message_data_at(&data_for(twitter), NOW).is_ok());
+    }
+
+    #[test]
+    fn validates_names() {
+        assert!(validate_fname("alice").is_ok());
+        assert!(validate_fname("al1ce-2").is_ok());
+        assert!(validate_fname("Alice").is_err());
+        assert!(validate_fname("").is_err());
+        assert!(validate_fname("abcdefghijklmnopq").is_err());
+
+        assert!(validate_ens_name("alice.eth").is_ok());
+        assert!(validate_ens_name("alice.base.eth").is_ok());
+        assert!(validate_ens_name("alice.xyz").is_err());
+        assert!(validate_ens_name("a.b.eth").is_err());
+    }
+
+    #[tokio::test]
+    async fn validates_built_message() {
+        let signer = test_signer();
+        let message = MessageBuilder::new(
+            1,
+            FarcasterNetwork::FARCASTER_NETWORK_MAINNET,
+            CastAddBuilder::new("hi"),
+        )
+        .build(&signer)
+        .await
+        .unwrap();
+        assert!(validate_message(&message).is_ok());
+
+        let mut tampered = message.clone();
+        tampered.set_signature(vec![0; 64]);
+        assert!(matches!(
+            validate_message(&tampered),
+            Err(ValidationError::Verify(VerifyError::InvalidSignature))
+        ));
+        assert!(matches!(
+            validate_message(&Message::new()),
+            Err(ValidationError::MissingData)
+        ));
+    }
+}
@@ -0,0 +1,554 @@
+//! Address verification claims, mirroring packages/core/src/verifications.ts.
+//!
+//! Verifying an Ethereum address means signing a `VerificationClaim` (fid, address, blockHash,
+//! network) as EIP-712 typed data with that address's key and sending the signature in a
+//! `VerificationAddAddressBody`. Solana addresses sign a plain text claim with their ed25519 key
+//! instead.
+
+use async_trait::async_trait;
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use protobuf::ProtobufEnum;
+
+use crate::bodies::VerificationAddAddressBuilder;
+use crate::eip712::{encode_address, encode_uint, hash_struct, hash_typed_data, Eip712Domain};
+use crate::eth::{recover_address, Address, EthResult, EthWallet};
+use crate::message::{
+    FarcasterNetwork, Message, MessageData_oneof_body, Protocol, VerificationAddAddressBody,
+};
+use crate::validations::{
+    message_data, validate_message, validate_verification_add_address_body, ValidationError,
+};
+
+pub const EIP_712_FARCASTER_VERIFICATION_CLAIM: &str =
+    "VerificationClaim(uint256 fid,address address,bytes32 blockHash,uint8 network)";
+
+/// `verification_type` of claims signed by an externally owned account.
+pub const VERIFICATION_TYPE_EOA: u32 = 0;
+/// `verification_type` of claims signed by a contract wallet, checked with ERC-1271.
+pub const VERIFICATION_TYPE_CONTRACT: u32 = 1;
+
+/// Chain ids a claim may be bound to: none (0), mainnet, goerli, optimism and optimism goerli.
+pub const VERIFICATION_CLAIM_CHAIN_IDS: [u32; 5] = [0, 1, 5, 10, 420];
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerificationError {
+    #[error(transparent)]
+    Invalid(#[from] ValidationError),
+    #[error("invalid verification protocol")]
+    InvalidProtocol,
+    #[error("invalid chain id {0}")]
+    InvalidChainId(u32),
+    #[error("invalid verification type {0}")]
+    InvalidVerificationType(u32),
+    #[error("no ERC-1271 checker for contract verifications")]
+    MissingErc1271Checker,
+    #[error("ERC-1271 check failed: {0}")]
+    Erc1271(String),
+    #[error("invalid claimSignature")]
+    InvalidClaimSignature,
+    #[error("blockHash is not base58: {0}")]
+    InvalidBase58(#[from] bs58::decode::Error),
+}
+
+pub type VerificationResult<T = ()> = Result<T, VerificationError>;
+
+/// Asks a contract wallet whether it accepts a signature, through ERC-1271's
+/// `isValidSignature(bytes32 hash, bytes signature)`.
+#[async_trait]
+pub trait Erc1271Checker: Send + Sync {
+    /// Whether the contract at `address` on `chain_id` returns the `0x1626ba7e` magic value for
+    /// `signature` over `hash`.
+    async fn is_valid_signature(
+        &self,
+        chain_id: u32,
+        address: &Address,
+        hash: &[u8; 32],
+        signature: &[u8],
+    ) -> VerificationResult<bool>;
+}
+
+/// The typed data an Ethereum address signs to verify it belongs to an fid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationClaim {
+    pub fid: u64,
+    pub address: Address,
+    pub block_hash: [u8; 32],
+    pub network: FarcasterNetwork,
+}
+
+impl VerificationClaim {
+    pub fn new(
+        fid: u64,
+        address: Address,
+        block_hash: [u8; 32],
+        network: FarcasterNetwork,
+    ) -> Self {
+        Self {
+            fid,
+            address,
+            block_hash,
+            network,
+        }
+    }
+
+    pub fn struct_hash(&self) -> [u8; 32] {
+        hash_struct(
+            EIP_712_FARCASTER_VERIFICATION_CLAIM,
+            &[
+                encode_uint(self.fid),
+                encode_address(&self.address),
+                self.block_hash,
+                encode_uint(self.network.value() as u64),
+            ],
+        )
+    }
+
+    /// The digest to sign. EOA claims use chain id 0, which leaves it out of the domain.
+    pub fn digest(&self, chain_id: u32) -> [u8; 32] {
+        let chain_id = (chain_id != 0).then_some(chain_id as u64);
+        hash_typed_data(
+            &Eip712Domain::farcaster_verify(chain_id),
+            &self.struct_hash(),
+        )
+    }
+}
+
+impl VerificationAddAddressBuilder {
+    /// Signs a claim that `wallet`'s address belongs to `fid` and fills the body with it, as an
+    /// EOA verification on Ethereum.
+    pub fn sign_ethereum(
+        wallet: &EthWallet,
+        fid: u64,
+        network: FarcasterNetwork,
+        block_hash: [u8; 32],
+    ) -> EthResult<Self> {
+        let claim = VerificationClaim::new(fid, wallet.address(), block_hash, network);
+        let signature = wallet.sign_hash(&claim.digest(0))?;
+        Ok(Self::new(
+            wallet.address().to_vec(),
+            signature.to_vec(),
+            block_hash.to_vec(),
+        )
+        .protocol(Protocol::PROTOCOL_ETHEREUM)
+        .verification_type(VERIFICATION_TYPE_EOA)
+        .chain_id(0))
+    }
+}
+
+/// The plain text claim a Solana address signs. It is simpler than the offchain message signing
+/// spec, which wallet libraries support unevenly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolanaVerificationClaim {
+    pub fid: u64,
+    pub address: [u8; 32],
+    pub block_hash: [u8; 32],
+    pub network: FarcasterNetwork,
+}
+
+impl SolanaVerificationClaim {
+    pub fn new(
+        fid: u64,
+        address: [u8; 32],
+        block_hash: [u8; 32],
+        network: FarcasterNetwork,
+    ) -> Self {
+        Self {
+            fid,
+            address,
+            block_hash,
+            network,
+        }
+    }
+
+    /// Rebuilds the claim a Solana verification body was signed over.
+    pub fn from_body(
+        body: &VerificationAddAddressBody,
+        fid: u64,
+        network: FarcasterNetwork,
+    ) -> VerificationResult<Self> {
+        if body.get_protocol() != Protocol::PROTOCOL_SOLANA {
+            return Err(VerificationError::InvalidProtocol);
+        }
+        validate_verification_add_address_body(body)?;
+        let address = <[u8; 32]>::try_from(body.get_address())
+            .map_err(|_| ValidationError::InvalidAddressLength { expected: 32 })?;
+        let block_hash = <[u8; 32]>::try_from(body.get_block_hash())
+            .map_err(|_| ValidationError::InvalidBlockHashLength)?;
+        Ok(Self::new(fid, address, block_hash, network))
+    }
+
+    /// The signed text, with the address and block hash in base58 and the enums as numbers.
+    pub fn message(&self) -> String {
+        format!(
+            "fid: {} address: {} network: {} blockHash: {} protocol: {}",
+            self.fid,
+            bs58::encode(self.address).into_string(),
+            self.network.value(),
+            bs58::encode(self.block_hash).into_string(),
+            Protocol::PROTOCOL_SOLANA.value(),
+        )
+    }
+}
+
+impl VerificationAddAddressBuilder {
+    /// Signs a claim that `wallet`'s Solana address belongs to `fid` and fills the body with it.
+    /// `block_hash` is the base58 hash of a recent block, as Solana RPCs return it.
+    pub fn sign_solana(
+        wallet: &SigningKey,
+        fid: u64,
+        network: FarcasterNetwork,
+        block_hash: &str,
+    ) -> VerificationResult<Self> {
+        let block_hash = <[u8; 32]>::try_from(bs58::decode(block_hash).into_vec()?)
+            .map_err(|_| ValidationError::InvalidBlockHashLength)?;
+        let address = wallet.verifying_key().to_bytes();
+        let claim = SolanaVerificationClaim::new(fid, address, block_hash, network);
+        let signature = wallet.sign(claim.message().as_bytes());
+        Ok(Self::new(
+            address.to_vec(),
+            signature.to_bytes().to_vec(),
+            block_hash.to_vec(),
+        )
+        .protocol(Protocol::PROTOCOL_SOLANA))
+    }
+}
+
+impl TryFrom<(&VerificationAddAddressBody, u64, FarcasterNetwork)> for VerificationClaim {
+    type Error = VerificationError;
+
+    /// Rebuilds the claim an Ethereum verification body was signed over.
+    fn try_from(
+        (body, fid, network): (&VerificationAddAddressBody, u64, FarcasterNetwork),
+    ) -> VerificationResult<Self> {
+        if body.get_protocol() != Protocol::PROTOCOL_ETHEREUM {
+            return Err(VerificationError::InvalidProtocol);
+        }
+        validate_verification_add_address_body(body)?;
+        let address = Address::try_from(body.get_address())
+            .map_err(|_| ValidationError::InvalidAddressLength { expected: 20 })?;
+        let block_hash = <[u8; 32]>::try_from(body.get_block_hash())
+            .map_err(|_| ValidationError::InvalidBlockHashLength)?;
+        Ok(Self::new(fid, address, block_hash, network))
+    }
+}
+
+/// Checks the claim signature of an EOA verification offline, by recovering the signer from the
+/// EIP-712 digest and comparing it with `address`.
+pub fn verify_eth_claim_signature_eoa(
+    body: &VerificationAddAddressBody,
+    fid: u64,
+    network: FarcasterNetwork,
+) -> VerificationResult {
+    let claim = VerificationClaim::try_from((body, fid, network))?;
+    if body.get_verification_type() != VERIFICATION_TYPE_EOA {
+        return Err(VerificationError::InvalidVerificationType(
+            body.get_verification_type(),
+        ));
+    }
+    if body.get_chain_id() != 0 {
+        return Err(VerificationError::InvalidChainId(body.get_chain_id()));
+    }
+    match recover_address(&claim.digest(0), body.get_claim_signature()) {
+        Ok(signer) if signer == claim.address => Ok(()),
+        _ => Err(VerificationError::InvalidClaimSignature),
+    }
+}
+
+/// Checks the claim signature of an Ethereum verification, mirroring
+/// `validateVerificationAddEthAddressSignature`. Contract wallet claims are sent to `checker`
+/// and fail without one.
+pub async fn verify_eth_claim_signature(
+    body: &VerificationAddAddressBody,
+    fid: u64,
+    network: FarcasterNetwork,
+    checker: Option<&dyn Erc1271Checker>,
+) -> VerificationResult {
+    let chain_id = body.get_chain_id();
+    if !VERIFICATION_CLAIM_CHAIN_IDS.contains(&chain_id) {
+        return Err(VerificationError::InvalidChainId(chain_id));
+    }
+    match body.get_verification_type() {
+        VERIFICATION_TYPE_EOA => verify_eth_claim_signature_eoa(body, fid, network),
+        VERIFICATION_TYPE_CONTRACT => {
+            let claim = VerificationClaim::try_from((body, fid, network))?;
+            if chain_id == 0 {
+                return Err(VerificationError::InvalidChainId(chain_id));
+            }
+            let checker = checker.ok_or(VerificationError::MissingErc1271Checker)?;
+            let digest = claim.digest(chain_id);
+            let valid = checker
+                .is_valid_signature(
+                    chain_id,
+                    &claim.address,
+                    &digest,
+                    body.get_claim_signature(),
+                )
+                .await?;
+            if !valid {
+                return Err(VerificationError::InvalidClaimSignature);
+            }
+            Ok(())
+        }
+        verification_type => Err(VerificationError::InvalidVerificationType(
+            verification_type,
+        )),
+    }
+}
+
+/// Checks the ed25519 claim signature of a Solana verification against its address.
+pub fn verify_sol_claim_signature(
+    body: &VerificationAddAddressBody,
+    fid: u64,
+    network: FarcasterNetwork,
+) -> VerificationResult {
+    let claim = SolanaVerificationClaim::from_body(body, fid, network)?;
+    let signature = Signature::from_slice(body.get_claim_signature())
+        .map_err(|_| ValidationError::InvalidClaimSignatureLength)?;
+    VerifyingKey::from_bytes(&claim.address)
+        .and_then(|key| key.verify_strict(claim.message().as_bytes(), &signature))
+        .map_err(|_| VerificationError::InvalidClaimSignature)
+}
+
+/// Validates a Solana verification add or remove message received from a hub, including the
+/// claim signature of adds.
+pub fn verify_solana_verification(message: &Message) -> VerificationResult {
+    validate_message(message)?;
+    let data = message_data(message)?;
+    match &data.body {
+        Some(MessageData_oneof_body::verification_add_address_body(body)) => {
+            verify_sol_claim_signature(body, data.get_fid(), data.get_network())
+        }
+        Some(MessageData_oneof_body::verification_remove_body(body))
+            if body.get_protocol() == Protocol::PROTOCOL_SOLANA =>
+        {
+            Ok(())
+        }
+        Some(MessageData_oneof_body::verification_remove_body(_)) => {
+            Err(VerificationError::InvalidProtocol)
+        }
+        _ => Err(ValidationError::InvalidBodyType(data.get_field_type()).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bodies::{MessageBody, VerificationRemoveBuilder};
+    use crate::eth::wallet::TEST_KEY;
+    use crate::test_utils::test_signer;
+    use crate::MessageBuilder;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn signs_eoa_verification_claims() {
+        let wallet = EthWallet::from_bytes(&[9u8; 32]).unwrap();
+        let network = FarcasterNetwork::FARCASTER_NETWORK_MAINNET;
+        let body =
+            VerificationAddAddressBuilder::sign_ethereum(&wallet, 2, network, [3u8; 32]).unwrap();
+        let message = MessageBuilder::new(2, network, body)
+            .build(&test_signer())
+            .await
+            .unwrap();
+        validate_message(&message).unwrap();
+
+        let data = message_data(&message).unwrap();
+        let body = data.get_verification_add_address_body();
+        assert_eq!(body.get_address(), wallet.address());
+        assert_eq!(body.get_protocol(), Protocol::PROTOCOL_ETHEREUM);
+        assert_eq!(body.get_verification_type(), VERIFICATION_TYPE_EOA);
+        assert_eq!(body.get_claim_signature().len(), 65);
+
+        let claim = VerificationClaim::new(2, wallet.address(), [3u8; 32], network);
+        let digest = claim.digest(0);
+        assert_eq!(
+            recover_address(&digest, body.get_claim_signature()).unwrap(),
+            wallet.address()
+        );
+        assert_ne!(digest, claim.digest(10));
+        assert_ne!(
+            digest,
+            VerificationClaim::new(3, wallet.address(), [3u8; 32], network).digest(0)
+        );
+    }
+
+    fn body(builder: VerificationAddAddressBuilder) -> VerificationAddAddressBody {
+        match builder.into_body() {
+            MessageData_oneof_body::verification_add_address_body(body) => body,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Stands in for contract wallets deployed on a local chain, each accepting signatures from
+    /// a single owner key.
+    struct OwnedWallets(HashMap<(u32, Address), Address>);
+
+    #[async_trait]
+    impl Erc1271Checker for OwnedWallets {
+        async fn is_valid_signature(
+            &self,
+            chain_id: u32,
+            address: &Address,
+            hash: &[u8; 32],
+            signature: &[u8],
+        ) -> VerificationResult<bool> {
+            let owner = self
+                .0
+                .get(&(chain_id, *address))
+                .ok_or_else(|| VerificationError::Erc1271("no contract at address".to_string()))?;
+            Ok(recover_address(hash, signature).ok().as_ref() == Some(owner))
+        }
+    }
+
+    const VECTOR_BLOCK_HASH: &str =
+        "1d3b0456c920eb503450c7efdcf9b5cf1f5184bf04e5d8ecbcead188a0d02018";
+
+    #[test]
+    fn matches_verification_claim_vectors() {
+        let wallet = EthWallet::from_hex(TEST_KEY).unwrap();
+        let block_hash: [u8; 32] = hex::decode(VECTOR_BLOCK_HASH).unwrap().try_into().unwrap();
+        let network = FarcasterNetwork::FARCASTER_NETWORK_MAINNET;
+        let claim = VerificationClaim::new(1, wallet.address(), block_hash, network);
+
+        let eoa = claim.digest(0);
+        assert_eq!(
+            hex::encode(eoa),
+            "7f55102b94377ea4a02b144b71ffe46c73a8b3ef5e4b3604cac913495f344582"
+        );
+        let body = body(
+            VerificationAddAddressBuilder::sign_ethereum(&wallet, 1, network, block_hash).unwrap(),
+        );
+        assert_eq!(
+            hex::encode(body.get_claim_signature()),
+            "9ae80703431524741764834c7f9d8a8d509d5dac5fbae0234f9828b2edd9a791\
+             2be8c2764033d4595b1345ca75fae487a81580d5a55ef2678298f8e773fdab511b"
+        );
+
+        let optimism = claim.digest(10);
+        assert_eq!(
+            hex::encode(optimism),
+            "6464ff060199bbcf65e9fb0a02700f86cb341cb2aa6d00ac8a100aa4a0957044"
+        );
+        assert_eq!(
+            hex::encode(wallet.sign_hash(&optimism).unwrap()),
+            "42d304d7dc1eb2f4173db75fbb8822c4029b140a078bd09ca8ae54eeb0b83aa0\
+             23ec37ba5e27c271d289cac9a28607a8291002bfca1a1a5e6d9c4d97a32c430c1b"
+        );
+    }
+
+    #[tokio::test]
+    async fn checks_eoa_claim_signatures() {
+        let wallet = EthWallet::from_bytes(&[9u8; 32]).unwrap();
+        let network = FarcasterNetwork::FARCASTER_NETWORK_MAINNET;
+        let body = body(
+            VerificationAddAddressBuilder::sign_ethereum(&wallet, 2, network, [3u8; 32]).unwrap(),
+        );
+        verify_eth_claim_signature_eoa(&body, 2, network).unwrap();
+        verify_eth_claim_signature(&body, 2, network, None)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            verify_eth_claim_signature_eoa(&body, 3, network),
+            Err(VerificationError::InvalidClaimSignature)
+        ));
+        let mut other = body.clone();
+        other.set_address(
+            EthWallet::from_bytes(&[10u8; 32])
+                .unwrap()
+                .address()
+                .to_vec(),
+        );
+        assert!(matches!(
+            verify_eth_claim_signature_eoa(&other, 2, network),
+            Err(VerificationError::InvalidClaimSignature)
+        ));
+        let mut other = body.clone();
+        other.set_chain_id(7);
+        assert!(matches!(
+            verify_eth_claim_signature(&other, 2, network, None).await,
+            Err(VerificationError::InvalidChainId(7))
+        ));
+    }
+
+    #[tokio::test]
+    async fn checks_contract_claim_signatures_with_erc1271() {
+        let owner = EthWallet::from_bytes(&[9u8; 32]).unwrap();
+        let contract = [0x5a; 20];
+        let network = FarcasterNetwork::FARCASTER_NETWORK_MAINNET;
+        let claim = VerificationClaim::new(2, contract, [3u8; 32], network);
+        let signature = owner.sign_hash(&claim.digest(10)).unwrap();
+        let body = body(
+            VerificationAddAddressBuilder::new(contract.to_vec(), signature.to_vec(), vec![3; 32])
+                .verification_type(VERIFICATION_TYPE_CONTRACT)
+                .chain_id(10),
+        );
+
+        let wallets = OwnedWallets(HashMap::from([((10, contract), owner.address())]));
+        verify_eth_claim_signature(&body, 2, network, Some(&wallets))
+            .await
+            .unwrap();
+        assert!(matches!(
+            verify_eth_claim_signature(&body, 2, network, None).await,
+            Err(VerificationError::MissingErc1271Checker)
+        ));
+        assert!(matches!(
+            verify_eth_claim_signature(&body, 3, network, Some(&wallets)).await,
+            Err(VerificationError::InvalidClaimSignature)
+        ));
+
+        let mut other = body.clone();
+        other.set_chain_id(1);
+        assert!(matches!(
+            verify_eth_claim_signature(&other, 2, network, Some(&wallets)).await,
+            Err(VerificationError::Erc1271(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn signs_and_verifies_solana_verifications() {
+        let wallet = SigningKey::from_bytes(&[11u8; 32]);
+        let signer = test_signer();
+        let network = FarcasterNetwork::FARCASTER_NETWORK_MAINNET;
+        let block_hash = bs58::encode([4u8; 32]).into_string();
+        let builder =
+            VerificationAddAddressBuilder::sign_solana(&wallet, 2, network, &block_hash).unwrap();
+
+        let claim = SolanaVerificationClaim::from_body(&body(builder.clone()), 2, network).unwrap();
+        assert_eq!(
+            claim.message(),
+            format!(
+                "fid: 2 address: {} network: 1 blockHash: {} protocol: 1",
+                bs58::encode(wallet.verifying_key().to_bytes()).into_string(),
+                block_hash
+            )
+        );
+
+        let message = MessageBuilder::new(2, network, builder)
+            .build(&signer)
+            .await
+            .unwrap();
+        verify_solana_verification(&message).unwrap();
+        let signed_for_fid_2 =
+            VerificationAddAddressBuilder::sign_solana(&wallet, 2, network, &block_hash).unwrap();
+        let forged = MessageBuilder::new(3, network, signed_for_fid_2)
+            .build(&signer)
+            .await
+            .unwrap();
+        assert!(matches!(
+            verify_solana_verification(&forged),
+            Err(VerificationError::InvalidClaimSignature)
+        ));
+        assert!(matches!(
+            VerificationAddAddressBuilder::sign_solana(&wallet, 2, network, "0OIl"),
+            Err(VerificationError::InvalidBase58(_))
+        ));
+
+        let remove =
+            VerificationRemoveBuilder::new(claim.address.to_vec(), Protocol::PROTOCOL_SOLANA);
+        let message = MessageBuilder::new(2, network, remove)
+            .build(&signer)
+            .await
+            .unwrap();
+        verify_solana_verification(&message).unwrap();
+    }
+}
@@ -0,0 +1,217 @@
+//! Hash and signature verification for messages received from hubs.
+
+use ed25519_dalek::{Signature, VerifyingKey};
+use protobuf::Message as _;
+
+use crate::builder::hash_data;
+use crate::message::{HashScheme, Message, MessageData, SignatureScheme};
+use crate::signer_registry::{SignerRegistry, SignerStatus};
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyError {
+    #[error("data is missing")]
+    MissingData,
+    #[error("failed to encode data: {0}")]
+    Encode(#[from] protobuf::ProtobufError),
+    #[error("unsupported hash scheme {0:?}")]
+    UnsupportedHashScheme(HashScheme),
+    #[error("invalid hash. expected={}, computed={}", hex::encode(.expected), hex::encode(.computed))]
+    HashMismatch {
+        expected: Vec<u8>,
+        computed: Vec<u8>,
+    },
+    #[error("unsupported signature scheme {0:?}")]
+    UnsupportedSignatureScheme(SignatureScheme),
+    #[error("signer is not a valid ed25519 public key")]
+    InvalidSigner,
+    #[error("signature must be 64 bytes")]
+    MalformedSignature,
+    #[error("invalid signature")]
+    InvalidSignature,
+    #[error("failed to decode data: {0}")]
+    Decode(protobuf::ProtobufError),
+    #[error("signer is not a signer of fid {0}")]
+    UnknownSigner(u64),
+    #[error("signer of fid {0} has been revoked")]
+    RevokedSigner(u64),
+}
+
+/// Recomputes the message hash and checks the ed25519 signature over it.
+///
+/// The hash is computed over `data_bytes` when present, otherwise over the re-encoded `data`.
+pub fn verify_message(message: &Message) -> Result<(), VerifyError> {
+    let computed = if !message.get_data_bytes().is_empty() {
+        hash_data(message.get_data_bytes())
+    } else if message.has_data() {
+        hash_data(&message.get_data().write_to_bytes()?)
+    } else {
+        return Err(VerifyError::MissingData);
+    };
+
+    let hash_scheme = message.get_hash_scheme();
+    if hash_scheme != HashScheme::HASH_SCHEME_BLAKE3 {
+        return Err(VerifyError::UnsupportedHashScheme(hash_scheme));
+    }
+    if message.get_hash() != computed.as_slice() {
+        return Err(VerifyError::HashMismatch {
+            expected: message.get_hash().to_vec(),
+            computed,
+        });
+    }
+
+    let signature_scheme = message.get_signature_scheme();
+    if signature_scheme != SignatureScheme::SIGNATURE_SCHEME_ED25519 {
+        return Err(VerifyError::UnsupportedSignatureScheme(signature_scheme));
+    }
+    let signer = <[u8; 32]>::try_from(message.get_signer())
+        .ok()
+        .and_then(|bytes| VerifyingKey::from_bytes(&bytes).ok())
+        .ok_or(VerifyError::InvalidSigner)?;
+    let signature = Signature::from_slice(message.get_signature())
+        .map_err(|_| VerifyError::MalformedSignature)?;
+
+    signer
+        .verify_strict(message.get_hash(), &signature)
+        .map_err(|_| VerifyError::InvalidSignature)
+}
+
+/// Like [`verify_message`], then checks with `signers` that the signer was active for the
+/// message's fid at `block_number`.
+pub fn verify_message_with_signers(
+    message: &Message,
+    signers: &dyn SignerRegistry,
+    block_number: u32,
+) -> Result<(), VerifyError> {
+    verify_message(message)?;
+    let fid = if !message.get_data_bytes().is_empty() {
+        MessageData::parse_from_bytes(message.get_data_bytes())
+            .map_err(VerifyError::Decode)?
+            .get_fid()
+    } else {
+        message.get_data().get_fid()
+    };
+    match signers.signer_status(fid, message.get_signer(), block_number) {
+        SignerStatus::Active => Ok(()),
+        SignerStatus::Revoked => Err(VerifyError::RevokedSigner(fid)),
+        SignerStatus::Unknown => Err(VerifyError::UnknownSigner(fid)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bodies::CastAddBuilder;
+    use crate::message::FarcasterNetwork;
+    use crate::onchain_event::{OnChainEvent, OnChainEventType, SignerEventBody, SignerEventType};
+    use crate::signer_registry::OnChainSignerRegistry;
+    use crate::test_utils::test_signer;
+    use crate::MessageBuilder;
+    use ed25519_dalek::SigningKey;
+
+    async fn signed_message() -> Message {
+        MessageBuilder::new(
+            1,
+            FarcasterNetwork::FARCASTER_NETWORK_MAINNET,
+            CastAddBuilder::new("hello"),
+        )
+        .timestamp(100)
+        .build(&test_signer())
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn verifies_built_message() {
+        assert!(verify_message(&signed_message().await).is_ok());
+    }
+
+    #[tokio::test]
+    async fn verifies_message_with_data_instead_of_data_bytes() {
+        let mut message = signed_message().await;
+        let data = MessageData::parse_from_bytes(&message.take_data_bytes()).unwrap();
+        message.set_data(data);
+        assert!(verify_message(&message).is_ok());
+    }
+
+    #[tokio::test]
+    async fn rejects_tampered_data() {
+        let mut message = signed_message().await;
+        message.mut_data_bytes()[2] ^= 1;
+        assert!(matches!(
+            verify_message(&message),
+            Err(VerifyError::HashMismatch { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_unsupported_schemes() {
+        let mut message = signed_message().await;
+        message.set_hash_scheme(HashScheme::HASH_SCHEME_NONE);
+        assert!(matches!(
+            verify_message(&message),
+            Err(VerifyError::UnsupportedHashScheme(_))
+        ));
+
+        let mut message = signed_message().await;
+        message.set_signature_scheme(SignatureScheme::SIGNATURE_SCHEME_EIP712);
+        assert!(matches!(
+            verify_message(&message),
+            Err(VerifyError::UnsupportedSignatureScheme(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_signer_and_signature() {
+        let mut message = signed_message().await;
+        message.set_signer(vec![1; 31]);
+        assert!(matches!(
+            verify_message(&message),
+            Err(VerifyError::InvalidSigner)
+        ));
+
+        let mut message = signed_message().await;
+        message.set_signature(vec![0; 63]);
+        assert!(matches!(
+            verify_message(&message),
+            Err(VerifyError::MalformedSignature)
+        ));
+
+        let mut message = signed_message().await;
+        let other = SigningKey::from_bytes(&[8u8; 32]);
+        message.set_signer(other.verifying_key().to_bytes().to_vec());
+        assert!(matches!(
+            verify_message(&message),
+            Err(VerifyError::InvalidSignature)
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_revoked_and_unknown_signers() {
+        let message = signed_message().await;
+        let mut registry = OnChainSignerRegistry::new();
+        assert!(matches!(
+            verify_message_with_signers(&message, &registry, 100),
+            Err(VerifyError::UnknownSigner(1))
+        ));
+
+        for (event_type, block_number) in [
+            (SignerEventType::SIGNER_EVENT_TYPE_ADD, 100),
+            (SignerEventType::SIGNER_EVENT_TYPE_REMOVE, 200),
+        ] {
+            let mut body = SignerEventBody::new();
+            body.set_key(message.get_signer().to_vec());
+            body.set_event_type(event_type);
+            let mut event = OnChainEvent::new();
+            event.set_field_type(OnChainEventType::EVENT_TYPE_SIGNER);
+            event.set_fid(1);
+            event.set_block_number(block_number);
+            event.set_signer_event_body(body);
+            registry.merge_event(&event);
+        }
+        assert!(verify_message_with_signers(&message, &registry, 150).is_ok());
+        assert!(matches!(
+            verify_message_with_signers(&message, &registry, 200),
+            Err(VerifyError::RevokedSigner(1))
+        ));
+    }
+}